@@ -6,28 +6,144 @@
 //------------------
 
 use std::env;
+use std::fs;
 use std::process;
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
 
-use significance::Significance;
+use significance::{Significance, PropagationOrder, Number, Interval, Decimal};
+use significance::significance::{format_source, parse_lint_names, run_test, bless, debug, Debugger, Graph, Diagnostic, Severity, SourceMap, Lint, LintLevel, Expectations};
 
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    
-    match args.len() {
-        1 => run_repl(),
-        2 => run_file(&args[1]),
-        _ => {
-            eprintln!("Usage: {} [filename]", args[0]);
-            process::exit(1);
+
+    if args.get(1).map(|arg| arg.as_str()) == Some("fmt") {
+        run_fmt(&args[0], &args[2..]);
+    }
+
+    let test = args.get(1).map(|arg| arg.as_str()) == Some("test");
+    let debugging = args.get(1).map(|arg| arg.as_str()) == Some("debug");
+    let graphing = args.get(1).map(|arg| arg.as_str()) == Some("graph");
+    let (flags, positional): (Vec<&String>, Vec<&String>) = args[if test || debugging || graphing { 2 } else { 1 }..].iter()
+        .partition(|arg| arg.starts_with("--"));
+
+    let mut order = PropagationOrder::First;
+    let mut backend = Backend::Real;
+    let mut ast = false;
+    let mut lint_levels = Vec::new();
+    let mut update = false;
+    let mut fold = true;
+    let (mut values, mut shares) = (false, false);
+
+    for flag in flags {
+        match flag.as_str() {
+            "--ast" => ast = true,
+            "--bless" if test => update = true,
+            "--values" if graphing => values = true,
+            "--shares" if graphing => shares = true,
+            "--second-order" => order = PropagationOrder::Second,
+            "--interval" => backend = Backend::Interval,
+            "--decimal" => backend = Backend::Decimal,
+            "--no-fold" => fold = false,
+            _ => match parse_lint_flag(flag) {
+                Some(Ok(levels)) => lint_levels.extend(levels),
+                Some(Err(message)) => {
+                    eprintln!("error: {}", message);
+                    process::exit(1);
+                },
+                None => usage(&args[0]),
+            },
+        }
+    }
+
+    if test {
+        let paths: Vec<&str> = positional.iter().map(|path| path.as_str()).collect();
+        match backend {
+            Backend::Real => run_tests(Significance::new, order, fold, &lint_levels, update, &paths),
+            Backend::Interval => run_tests(Significance::<Interval>::with_backend, order, fold, &lint_levels, update, &paths),
+            Backend::Decimal => run_tests(Significance::<Decimal>::with_backend, order, fold, &lint_levels, update, &paths),
+        }
+    }
+
+    if debugging {
+        let [filename] = positional.as_slice() else { usage(&args[0]) };
+        match backend {
+            Backend::Real => run_debugger(Significance::new(), order, fold, &lint_levels, filename),
+            Backend::Interval => run_debugger(Significance::<Interval>::with_backend(), order, fold, &lint_levels, filename),
+            Backend::Decimal => run_debugger(Significance::<Decimal>::with_backend(), order, fold, &lint_levels, filename),
+        }
+    }
+
+    if graphing {
+        let [filename] = positional.as_slice() else { usage(&args[0]) };
+        let annotations = (values, shares);
+        match backend {
+            Backend::Real => run_graph(Significance::new(), order, fold, &lint_levels, annotations, filename),
+            Backend::Interval => run_graph(Significance::<Interval>::with_backend(), order, fold, &lint_levels, annotations, filename),
+            Backend::Decimal => run_graph(Significance::<Decimal>::with_backend(), order, fold, &lint_levels, annotations, filename),
         }
     }
+
+    if positional.len() > 1 {
+        usage(&args[0]);
+    }
+    let filename = positional.first().map(|name| name.as_str());
+
+    match backend {
+        Backend::Real => run(Significance::new(), order, fold, &lint_levels, ast, filename),
+        Backend::Interval => run(Significance::<Interval>::with_backend(), order, fold, &lint_levels, ast, filename),
+        Backend::Decimal => run(Significance::<Decimal>::with_backend(), order, fold, &lint_levels, ast, filename),
+    }
+}
+
+/// Numeric backend selected on the command line.
+enum Backend {
+    Real,
+    Interval,
+    Decimal,
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} [--second-order] [--interval | --decimal] [--no-fold] [--ast] [--allow=LINTS] [--warn=LINTS] [--deny=LINTS] [filename]", program);
+    eprintln!("       {} fmt [--check] [filename...]", program);
+    eprintln!("       {} debug [--second-order] [--interval | --decimal] [--no-fold] [--allow=LINTS] [--warn=LINTS] [--deny=LINTS] filename", program);
+    eprintln!("       {} graph [--values] [--shares] [--second-order] [--interval | --decimal] [--no-fold] [--allow=LINTS] [--warn=LINTS] [--deny=LINTS] filename", program);
+    eprintln!("       {} test [--bless] [--second-order] [--interval | --decimal] [--no-fold] [--allow=LINTS] [--warn=LINTS] [--deny=LINTS] [path...]", program);
+    process::exit(1);
 }
 
-fn run_repl() {
-    print!("Significance REPL\n");
-    let mut parser = Significance::new();
+/// Parses `--allow=a,b`, `--warn=...` or `--deny=...` (`all` names every lint).
+///
+/// Returns `None` if the flag is not a lint flag.
+fn parse_lint_flag(flag: &str) -> Option<Result<Vec<(Lint, LintLevel)>, String>> {
+    let (level, names) = flag.strip_prefix("--")?.split_once('=')?;
+    let level = LintLevel::from_name(level)?;
+    Some(parse_lint_names(names).map(|lints| lints.into_iter().map(|lint| (lint, level)).collect()))
+}
+
+/// Runs a file, or the REPL without one. With `--ast`, the file's AST is written to `ast.json`.
+fn run<N: Number>(mut parser: Significance<N>, order: PropagationOrder, fold: bool, lint_levels: &[(Lint, LintLevel)], ast: bool, filename: Option<&str>) {
+    configure(&mut parser, order, fold, lint_levels);
+    if ast {
+        parser.set_ast_output(Some(Path::new("ast.json")));
+    }
+    match filename {
+        None => run_repl(parser),
+        Some(filename) => run_file(parser, filename),
+    }
+}
+
+fn configure<N: Number>(parser: &mut Significance<N>, order: PropagationOrder, fold: bool, lint_levels: &[(Lint, LintLevel)]) {
+    parser.set_propagation_order(order);
+    parser.set_constant_folding(fold);
+    for (lint, level) in lint_levels {
+        parser.set_lint_level(*lint, *level);
+    }
+}
+
+fn run_repl<N: Number>(mut parser: Significance<N>) {
+    println!("Significance REPL");
     loop {
         let mut input = String::new();
         print!("> ");
@@ -41,7 +157,9 @@ fn run_repl() {
         if input.trim() == "exit()" { break; }
         if input.trim() == "" { continue; }
 
-        match parser.parse_repl(&input) {
+        let result = parser.parse_repl(&input);
+        print_warnings(&parser);
+        match result {
             Ok(errors) => {
                 let msgs = errors.iter().map(|e| e.to_string()).collect::<Vec<String>>();
                 println!("{}", msgs.join("\n"))
@@ -54,13 +172,227 @@ fn run_repl() {
     process::exit(0);
 }
 
-fn run_file(filename: &str) {
+fn run_file<N: Number>(mut parser: Significance<N>, filename: &str) {
     
-    match Significance::parse_file(filename) {
-        Ok(_) => println!("{}", "Program executed successfully".to_string()),
+    let result = parser.run_file(filename);
+    print_warnings(&parser);
+    match result {
+        Ok(_) => println!("Program executed successfully"),
         Err(e) => {
-            eprintln!("Error: {}", e);
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn print_warnings<N: Number>(parser: &Significance<N>) {
+    for warning in parser.warnings() {
+        eprintln!("{}", warning);
+    }
+}
+
+/// `debug`: steps through a program interactively (see `significance::debugger`).
+fn run_debugger<N: Number>(mut parser: Significance<N>, order: PropagationOrder, fold: bool, lint_levels: &[(Lint, LintLevel)], filename: &str) -> ! {
+    configure(&mut parser, order, fold, lint_levels);
+    let text = match fs::read_to_string(filename) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("error: Failed to read file '{}': {}", filename, e);
+            process::exit(1);
+        }
+    };
+
+    match Debugger::new(parser, filename, &text, Some(Path::new(filename))) {
+        Ok(debugger) => {
+            if let Err(e) = debug(debugger, io::stdin().lock(), io::stdout()) {
+                eprintln!("error: {}", e);
+                process::exit(1);
+            }
             process::exit(0);
+        },
+        Err(errors) => {
+            eprintln!("{}", errors.join("\n"));
+            process::exit(1);
         }
     }
-}
\ No newline at end of file
+}
+
+/// `graph`: prints a program's computation graph in Graphviz DOT (see `significance::graph`),
+/// with `--values` and `--shares` annotating it from a run of the program.
+fn run_graph<N: Number>(mut parser: Significance<N>, order: PropagationOrder, fold: bool, lint_levels: &[(Lint, LintLevel)], (values, shares): (bool, bool), filename: &str) -> ! {
+    configure(&mut parser, order, fold, lint_levels);
+    let text = match fs::read_to_string(filename) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("error: Failed to read file '{}': {}", filename, e);
+            process::exit(1);
+        }
+    };
+
+    let (program, _, diagnostics) = parser.check(filename, &text, Some(Path::new(filename)));
+    for diagnostic in &diagnostics {
+        eprintln!("{}", diagnostic.render(parser.sources()));
+    }
+    if diagnostics.iter().any(|diagnostic| diagnostic.severity == Severity::Error) {
+        process::exit(1);
+    }
+
+    let mut graph = Graph::from_program(&program);
+    if values {
+        graph.annotate_values(&mut parser);
+    }
+    if shares {
+        graph.annotate_shares(&parser);
+    }
+    print!("{}", graph.to_dot());
+    process::exit(0);
+}
+
+/// `fmt`: formats the files in place, or with `--check` lists the files that are not
+/// formatted and fails if there are any. Without files, formats stdin to stdout.
+fn run_fmt(program: &str, args: &[String]) -> ! {
+    let (flags, filenames): (Vec<&String>, Vec<&String>) = args.iter().partition(|arg| arg.starts_with("--"));
+    let mut check = false;
+    for flag in flags {
+        match flag.as_str() {
+            "--check" => check = true,
+            _ => usage(program),
+        }
+    }
+
+    if filenames.is_empty() {
+        let mut text = String::new();
+        if let Err(e) = io::stdin().read_to_string(&mut text) {
+            eprintln!("error: Failed to read stdin: {}", e);
+            process::exit(1);
+        }
+        match format_source(&text) {
+            Ok(formatted) if !check => print!("{}", formatted),
+            Ok(formatted) if formatted != text => {
+                println!("<stdin> is not formatted");
+                process::exit(1);
+            },
+            Ok(_) => {},
+            Err(errors) => {
+                report(&errors, "<stdin>", &text);
+                process::exit(1);
+            },
+        }
+        process::exit(0);
+    }
+
+    let mut failed = false;
+    for filename in filenames {
+        let text = match fs::read_to_string(filename) {
+            Ok(text) => text,
+            Err(e) => {
+                eprintln!("error: Failed to read file '{}': {}", filename, e);
+                failed = true;
+                continue;
+            },
+        };
+        let formatted = match format_source(&text) {
+            Ok(formatted) => formatted,
+            Err(errors) => {
+                report(&errors, filename, &text);
+                failed = true;
+                continue;
+            },
+        };
+        if formatted == text {
+            continue;
+        }
+        if check {
+            println!("{} is not formatted", filename);
+            failed = true;
+        } else if let Err(e) = fs::write(filename, formatted) {
+            eprintln!("error: Failed to write file '{}': {}", filename, e);
+            failed = true;
+        }
+    }
+    process::exit(if failed { 1 } else { 0 });
+}
+
+/// `test`: runs every program with expectations in the given files and directories
+/// (default: the working directory) and compares what it produces with them. With
+/// `--bless`, failing expectations are updated instead, and a file named on the command
+/// line without expectations gets a `.expected` file.
+fn run_tests<N: Number>(interpreter: fn() -> Significance<N>, order: PropagationOrder, fold: bool, lint_levels: &[(Lint, LintLevel)], update: bool, paths: &[&str]) -> ! {
+    let mut files = Vec::new();
+    for path in if paths.is_empty() { &["."][..] } else { paths } {
+        let path = Path::new(path);
+        match path.is_dir() {
+            true => find_programs(path, &mut files),
+            false => files.push((path.to_path_buf(), true)),
+        }
+    }
+
+    let (mut passed, mut failed, mut blessed) = (0, 0, 0);
+    for (path, named) in files {
+        let mut parser = interpreter();
+        configure(&mut parser, order, fold, lint_levels);
+        let case = match run_test(parser, &path) {
+            Ok(case) => case,
+            Err(message) => {
+                println!("test {} ... error: {}", path.display(), message);
+                failed += 1;
+                continue;
+            },
+        };
+        if case.expectations == Expectations::Missing && !named {
+            continue;
+        }
+
+        if case.passed() && case.expectations != Expectations::Missing {
+            println!("test {} ... ok", path.display());
+            passed += 1;
+        } else if update {
+            match bless(&case) {
+                Ok(()) => {
+                    println!("test {} ... blessed", path.display());
+                    blessed += 1;
+                },
+                Err(message) => {
+                    println!("test {} ... error: {}", path.display(), message);
+                    failed += 1;
+                },
+            }
+        } else if case.expectations == Expectations::Missing {
+            println!("test {} ... FAILED (no expectations; run with --bless to record them)", path.display());
+            failed += 1;
+        } else {
+            println!("test {} ... FAILED", path.display());
+            for mismatch in case.mismatches() {
+                println!("    {}", mismatch);
+            }
+            failed += 1;
+        }
+    }
+
+    let result = if failed == 0 { "ok" } else { "FAILED" };
+    println!("\ntest result: {}. {} passed; {} failed; {} blessed", result, passed, failed, blessed);
+    process::exit(if failed == 0 { 0 } else { 1 });
+}
+
+/// Collects the `.sig` files in a directory and its subdirectories, in name order.
+fn find_programs(directory: &Path, files: &mut Vec<(PathBuf, bool)>) {
+    let Ok(entries) = fs::read_dir(directory) else { return };
+    let mut paths: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+    paths.sort();
+    for path in paths {
+        let path = path.strip_prefix(".").map(Path::to_path_buf).unwrap_or(path);
+        if path.is_dir() {
+            find_programs(&path, files);
+        } else if path.extension().is_some_and(|extension| extension == "sig") {
+            files.push((path, false));
+        }
+    }
+}
+
+fn report(errors: &[Diagnostic], name: &str, text: &str) {
+    let mut sources = SourceMap::new();
+    sources.add(name, text);
+    for error in errors {
+        eprintln!("{}", error.render(&sources));
+    }
+}
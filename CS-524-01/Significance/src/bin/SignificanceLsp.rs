@@ -0,0 +1,23 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Language server for `.sig` files, speaking LSP over stdin/stdout.
+
+use std::io;
+use std::process;
+
+use significance::serve;
+
+fn main() {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    if let Err(e) = serve(stdin.lock(), stdout.lock()) {
+        eprintln!("significance-lsp: {}", e);
+        process::exit(1);
+    }
+}
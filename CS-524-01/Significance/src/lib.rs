@@ -8,4 +8,4 @@
 pub mod significance;
 
 // Re-export main types and traits for convenient access
-pub use significance::{Number, Real, Significance, Position};
+pub use significance::{Number, Real, Interval, Decimal, Significance, Position, propagate, PropagationOrder, serve};
@@ -10,7 +10,9 @@
 //! This module defines the AST node types that represent parsed Significance code and provides
 //! a recursive descent parser that transforms token streams into an AST. The parser implements
 //! proper operator precedence and handles uncertainty notation (`+/-`).
-use crate::significance::tokenizer::{Token, TokenWithPos, Position};
+use crate::significance::tokenizer::{Token, TokenWithPos, Position, RelativeUnit};
+use crate::significance::source_map::Span;
+use crate::significance::diagnostic::{Diagnostic, Stage};
 use serde::{Serialize, Deserialize};
 
 /// Root node of the Abstract Syntax Tree representing a complete Significance program.
@@ -31,18 +33,47 @@ pub enum Statement {
     ///
     /// Declares a new variable with the specified name and type. The variable is initialized
     /// to a default value (0.0 for Real types).
-    VarDeclaration { name: String, var_type: VarType, pos: Position },
+    VarDeclaration { name: String, var_type: VarType, pos: Position, #[serde(default)] span: Span },
     
     /// Variable assignment: `name := expression`
     ///
     /// Assigns the result of an expression to an existing variable. The variable must be
     /// declared before it can be assigned.
-    Assignment { name: String, value: Expression, pos: Position },
+    Assignment { name: String, value: Expression, pos: Position, #[serde(default)] span: Span },
     
     /// Standalone expression statement
     ///
     /// An expression evaluated for its side effects or printed result. Common in REPL mode.
     Expression(Expression),
+
+    /// Import of another source file: `import "path.sig"`, `import "path.sig" as name`
+    /// or `import "path.sig" (a, b)`
+    ///
+    /// The path is resolved relative to the importing file. The variables declared in
+    /// the imported file are made available under a namespace or by name.
+    Import { path: String, names: ImportNames, pos: Position, #[serde(default)] span: Span },
+}
+
+impl Statement {
+    /// Returns the source text the statement was parsed from.
+    pub fn span(&self) -> Span {
+        match self {
+            Statement::VarDeclaration { span, .. }
+            | Statement::Assignment { span, .. }
+            | Statement::Import { span, .. } => *span,
+            Statement::Expression(expression) => expression.span(),
+        }
+    }
+}
+
+/// Which names an `import` brings into scope.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ImportNames {
+    /// Every variable of the imported file, qualified by the namespace (`cal.offset`)
+    Namespace(String),
+
+    /// Only the listed variables, under their own names
+    Selected(Vec<String>),
 }
 
 /// Variable type annotations in the Significance language.
@@ -65,38 +96,123 @@ pub enum VarType {
 pub enum Expression {
     /// Numeric literal with optional uncertainty: `value` or `value +/- error`
     ///
-    /// Examples: `42`, `12.3 +/- 0.5`, `1.5e-3`
-    NumberWithUncertainty { value: f64, error: f64, pos: Position },
+    /// Examples: `42`, `12.3 +/- 0.5`, `1.5e-3`, `12.3 +/- 2%`
+    ///
+    /// `error` is always the absolute uncertainty. `notation` records how the
+    /// uncertainty was written when that differs from a plain absolute value, and
+    /// `literal` keeps the exact source text of `value` for arbitrary-precision backends.
+    NumberWithUncertainty {
+        value: f64,
+        error: f64,
+        pos: Position,
+        #[serde(default)]
+        span: Span,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        notation: Option<UncertaintyNotation>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        literal: Option<String>,
+    },
     
     /// Variable reference by name
     ///
     /// References a previously declared variable to retrieve its current value.
-    Variable(String),
+    Variable { name: String, pos: Position, #[serde(default)] span: Span },
     
     /// Binary operation: `left op right`
     ///
     /// Applies a binary operator to two sub-expressions. Operator precedence is handled
     /// during parsing (see parsing methods for precedence rules).
-    Binary { left: Box<Expression>, op: BinaryOp, right: Box<Expression>, pos: Position },
+    Binary { left: Box<Expression>, op: BinaryOp, right: Box<Expression>, pos: Position, #[serde(default)] span: Span },
     
     /// Unary operation: `op operand`
     ///
     /// Applies a unary operator (+ or -) as a prefix to an expression.
-    Unary { op: UnaryOp, operand: Box<Expression>, pos: Position },
+    Unary { op: UnaryOp, operand: Box<Expression>, pos: Position, #[serde(default)] span: Span },
     
     /// Function call: `name(arg1, arg2, ...)`
     ///
     /// Invokes a function with the specified arguments. Functions are resolved from
     /// the standard library or user-defined functions.
-    FunctionCall { name: String, args: Vec<Expression>, pos: Position},
+    FunctionCall { name: String, args: Vec<Expression>, pos: Position, #[serde(default)] span: Span },
+}
+
+impl Expression {
+    /// Returns the position the expression is reported at.
+    ///
+    /// This is the start of literals, variables and function calls, and the operator
+    /// of binary and unary operations.
+    pub fn position(&self) -> Position {
+        match self {
+            Expression::NumberWithUncertainty { pos, .. }
+            | Expression::Variable { pos, .. }
+            | Expression::Binary { pos, .. }
+            | Expression::Unary { pos, .. }
+            | Expression::FunctionCall { pos, .. } => *pos,
+        }
+    }
+
+    /// Returns the source text the expression was parsed from, including any
+    /// parentheses around it.
+    pub fn span(&self) -> Span {
+        match self {
+            Expression::NumberWithUncertainty { span, .. }
+            | Expression::Variable { span, .. }
+            | Expression::Binary { span, .. }
+            | Expression::Unary { span, .. }
+            | Expression::FunctionCall { span, .. } => *span,
+        }
+    }
+
+    /// Replaces the expression's span, e.g. to cover the parentheses around it.
+    fn with_span(mut self, new_span: Span) -> Self {
+        match &mut self {
+            Expression::NumberWithUncertainty { span, .. }
+            | Expression::Variable { span, .. }
+            | Expression::Binary { span, .. }
+            | Expression::Unary { span, .. }
+            | Expression::FunctionCall { span, .. } => *span = new_span,
+        }
+        self
+    }
+}
+
+/// The source form of a literal's uncertainty, kept so it can be reproduced.
+///
+/// Absolute uncertainties (`12.3 +/- 0.5`) need no notation; other forms are
+/// converted to an absolute error during parsing but remembered here.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum UncertaintyNotation {
+    /// Relative uncertainty: `value +/- amount unit`, e.g. `12.3 +/- 2%`
+    ///
+    /// The absolute error is `|value| * amount * unit.factor()`.
+    Relative { amount: f64, unit: RelativeUnit },
+
+    /// Asymmetric uncertainty: `value(+upper/-lower)`, e.g. `12.3(+0.4/-0.2)`
+    ///
+    /// The absolute error is the larger of the two; the executor uses both.
+    Asymmetric { upper: f64, lower: f64 },
+}
+
+impl UncertaintyNotation {
+    /// Returns the absolute error this notation gives a literal with the given value.
+    pub fn absolute_error(&self, value: f64) -> f64 {
+        match self {
+            UncertaintyNotation::Relative { amount, unit } => value.abs() * amount * unit.factor(),
+            UncertaintyNotation::Asymmetric { upper, lower } => upper.max(*lower),
+        }
+    }
 }
 
 /// Binary operators supported in the Significance language.
 ///
 /// These operators are parsed with proper precedence levels:
-/// - Level 1 (lowest): Add, Sub
-/// - Level 2: Mul, Div, Mod
-/// - Level 3 (highest): Power, Root
+/// - Level 1 (lowest): Or
+/// - Level 2: And
+/// - Level 3: Less, LessEqual, Greater, GreaterEqual, Equal, NotEqual (not chainable)
+/// - Level 4: Add, Sub
+/// - Level 5: Mul, Div, Mod
+/// - Level 6: Power, Root
+/// - Level 7 (highest): PlusMinus
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum BinaryOp {
     /// Addition: `+`
@@ -123,6 +239,104 @@ pub enum BinaryOp {
     ///
     /// Example: `8//3` evaluates to 2 (cube root of 8)
     Root,
+
+    /// Attach uncertainty: `+/-`
+    ///
+    /// The right operand's value is combined in quadrature with the left operand's
+    /// uncertainty. Example: `x +/- dx`, or `(12.3 +/- 0.5) +/- 0.2` giving `12.3 +/- 0.54`
+    PlusMinus,
+
+    /// Less than: `<`
+    ///
+    /// Compares the nominal values, giving an exact 1 or 0. Example: `x < 2`
+    Less,
+
+    /// Less than or equal: `<=`
+    LessEqual,
+
+    /// Greater than: `>`
+    Greater,
+
+    /// Greater than or equal: `>=`
+    GreaterEqual,
+
+    /// Equal: `==`
+    ///
+    /// Compares the nominal values only, so `1 +/- 0.1 == 1` is 1.
+    Equal,
+
+    /// Not equal: `!=`
+    NotEqual,
+
+    /// Logical and: `&&`
+    ///
+    /// 1 if both operands are non-zero, otherwise 0. The right operand is only
+    /// evaluated if the left one is non-zero.
+    And,
+
+    /// Logical or: `||`
+    ///
+    /// 1 if either operand is non-zero, otherwise 0. The right operand is only
+    /// evaluated if the left one is zero.
+    Or,
+}
+
+impl BinaryOp {
+    /// Returns the operator as it is written in source code.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Mod => "%",
+            BinaryOp::Power => "**",
+            BinaryOp::Root => "//",
+            BinaryOp::PlusMinus => "+/-",
+            BinaryOp::Less => "<",
+            BinaryOp::LessEqual => "<=",
+            BinaryOp::Greater => ">",
+            BinaryOp::GreaterEqual => ">=",
+            BinaryOp::Equal => "==",
+            BinaryOp::NotEqual => "!=",
+            BinaryOp::And => "&&",
+            BinaryOp::Or => "||",
+        }
+    }
+
+    /// Returns whether the operator is a comparison or logical operator, whose result
+    /// is an exact 1 (true) or 0 (false) whatever the uncertainty of its operands.
+    pub fn is_boolean(&self) -> bool {
+        matches!(self, BinaryOp::Less | BinaryOp::LessEqual | BinaryOp::Greater | BinaryOp::GreaterEqual
+            | BinaryOp::Equal | BinaryOp::NotEqual | BinaryOp::And | BinaryOp::Or)
+    }
+
+    /// Applies a comparison or logical operator to two nominal values.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - 1.0 or 0.0
+    /// * `None` - The operator is arithmetic
+    pub fn apply_boolean(&self, left: f64, right: f64) -> Option<f64> {
+        let result = match self {
+            BinaryOp::Less => left < right,
+            BinaryOp::LessEqual => left <= right,
+            BinaryOp::Greater => left > right,
+            BinaryOp::GreaterEqual => left >= right,
+            BinaryOp::Equal => left == right,
+            BinaryOp::NotEqual => left != right,
+            BinaryOp::And => is_true(left) && is_true(right),
+            BinaryOp::Or => is_true(left) || is_true(right),
+            _ => return None,
+        };
+        Some(if result { 1.0 } else { 0.0 })
+    }
+}
+
+/// Returns whether a nominal value counts as true for `&&`, `||`, `!` and `assert`:
+/// it is neither zero nor NaN.
+pub fn is_true(value: f64) -> bool {
+    value != 0.0 && !value.is_nan()
 }
 
 /// Unary operators that can be applied as prefixes to expressions.
@@ -137,6 +351,22 @@ pub enum UnaryOp {
     ///
     /// Negates the value (preserves uncertainty magnitude).
     Minus,
+
+    /// Logical not: `!expr`
+    ///
+    /// An exact 1 if the operand's value is zero (or NaN), otherwise 0.
+    Not,
+}
+
+impl UnaryOp {
+    /// Returns the operator as it is written in source code.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            UnaryOp::Plus => "+",
+            UnaryOp::Minus => "-",
+            UnaryOp::Not => "!",
+        }
+    }
 }
 
 /// Error type for parsing failures.
@@ -150,14 +380,15 @@ pub struct ParseError {
     
     /// Location in the source where the error occurred
     pub position: Position,
+
+    /// Offending token, including its file
+    pub span: Span,
 }
 
-impl From<String> for ParseError {
-    fn from(message: String) -> Self {
-        ParseError {
-            message,
-            position: Position { line: 0, column: 0 }
-        }
+impl ParseError {
+    /// Converts the error into a `Diagnostic` that can be rendered with its source line.
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        Diagnostic::new(Stage::Parser, self.message.clone(), self.span)
     }
 }
 
@@ -200,6 +431,12 @@ pub struct AstParser {
     current: usize,
 }
 
+impl Default for AstParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AstParser {
     /// Creates a new parser instance with an empty token stream.
     ///
@@ -221,7 +458,7 @@ impl AstParser {
     /// # Returns
     ///
     /// * `Ok(Program)` - Successfully parsed program
-    /// * `Err(ParseError)` - Parse error with location and message
+    /// * `Err(ParseError)` - The first parse error, with location and message
     ///
     /// # Errors
     ///
@@ -230,25 +467,57 @@ impl AstParser {
     /// - Any statement fails to parse
     /// - Unexpected tokens are encountered
     pub fn parse_program(&mut self, tokens: Vec<TokenWithPos>) -> Result<Program, ParseError> {
+        let (program, errors) = self.parse_program_recovering(tokens);
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(program),
+        }
+    }
+
+    /// Parses a complete program, recovering from syntax errors.
+    ///
+    /// When a statement fails to parse, the error is recorded and the parser skips to
+    /// the next newline before parsing the following statement, so every syntax error
+    /// in the program is reported in one pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - Complete token stream including EOF token
+    ///
+    /// # Returns
+    ///
+    /// * `Program` - Every statement that parsed (the whole program if there were no errors)
+    /// * `Vec<ParseError>` - Every syntax error, in source order
+    pub fn parse_program_recovering(&mut self, tokens: Vec<TokenWithPos>) -> (Program, Vec<ParseError>) {
+        self.current = 0;
         self.tokens = tokens;
         
         //#NOTE: ensure EOF is present
         if self.tokens.is_empty() || !matches!(self.tokens.last().unwrap().token, Token::EOF) {
-            return Err(ParseError { 
+            let error = ParseError { 
                 message: "Token stream must end with EOF".to_string(),
-                position: Position {line: 1, column: 1}
-            });
+                position: Position {line: 1, column: 1},
+                span: Span::default(),
+            };
+            return (Program { statements: Vec::new() }, vec![error]);
         }
         
         let mut statements = Vec::new();
+        let mut errors = Vec::new();
         self.skip_newlines_and_comments();
 
         while !self.is_at_end() {
-            statements.push(self.parse_statement()?);
+            match self.parse_statement() {
+                Ok(statement) => statements.push(statement),
+                Err(error) => {
+                    errors.push(error);
+                    self.synchronize();
+                }
+            }
             self.skip_newlines_and_comments();
         }
         
-        Ok(Program { statements })
+        (Program { statements }, errors)
     }
     
     /// Parses a single statement from the current token position.
@@ -256,6 +525,7 @@ impl AstParser {
     /// Determines the statement type based on the first token:
     /// - `{` → Variable declaration
     /// - Identifier followed by `:=` → Assignment
+    /// - `import` → Import
     /// - Anything else → Expression statement
     ///
     /// # Returns
@@ -268,6 +538,9 @@ impl AstParser {
             Token::LeftBrace => {
                 self.parse_var_declaration()
             },
+            Token::Import => {
+                self.parse_import()
+            },
             Token::Identifier(_) => {
                 self.parse_starting_identifier()
                 
@@ -313,6 +586,7 @@ impl AstParser {
     fn parse_var_declaration(&mut self) -> Result<Statement, ParseError> {
 
         let pos = self.current_position();
+        let start = self.current_span();
 
         self.expect_token(Token::LeftBrace)?;
 
@@ -324,10 +598,72 @@ impl AstParser {
 
         self.expect_token(Token::RightBrace)?;
 
-        Ok(Statement::VarDeclaration { name, var_type, pos })
+        Ok(Statement::VarDeclaration { name, var_type, pos, span: start.to(self.previous_span()) })
         
     }
 
+    /// Parses an import statement.
+    ///
+    /// Without `as` or a name list, the namespace is the file name without its
+    /// extension, which must then be a valid identifier.
+    ///
+    /// # Grammar
+    ///
+    /// ```text
+    /// import := 'import' string [ 'as' identifier | '(' identifier (',' identifier)* ')' ]
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Statement::Import)` - Successfully parsed import
+    /// * `Err(ParseError)` - Missing path, invalid name list or namespace
+    fn parse_import(&mut self) -> Result<Statement, ParseError> {
+
+        let pos = self.current_position();
+        let start = self.current_span();
+
+        self.expect_token(Token::Import)?;
+
+        let path = match self.current_token() {
+            Token::StringLiteral(path) => path.clone(),
+            _ => return Err(self.error("Expected file path in quotes")),
+        };
+        self.advance();
+
+        let names = match self.current_token() {
+            Token::As => {
+                self.advance();
+                ImportNames::Namespace(self.consume_identifier()?)
+            },
+            Token::LeftParen => {
+                self.advance();
+                let mut names = vec![self.consume_identifier()?];
+                while self.current_token_is(&Token::Comma) {
+                    self.advance();
+                    names.push(self.consume_identifier()?);
+                }
+                self.expect_token(Token::RightParen)?;
+                ImportNames::Selected(names)
+            },
+            _ => {
+                let stem = std::path::Path::new(&path).file_stem().and_then(|stem| stem.to_str()).unwrap_or("");
+                let mut chars = stem.chars();
+                let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+                if !valid {
+                    return Err(ParseError {
+                        message: format!("Cannot use '{}' as a namespace; name one with 'as'", stem),
+                        position: pos,
+                        span: self.previous_span(),
+                    });
+                }
+                ImportNames::Namespace(stem.to_string())
+            },
+        };
+
+        Ok(Statement::Import { path, names, pos, span: start.to(self.previous_span()) })
+    }
+
     /// Disambiguates statements starting with an identifier.
     ///
     /// Looks ahead to determine if this is an assignment (`:=` follows) or
@@ -365,6 +701,7 @@ impl AstParser {
     fn parse_assignment(&mut self) -> Result<Statement, ParseError> {
 
         let pos = self.current_position();
+        let start = self.current_span();
 
         let name = self.consume_identifier()?;
 
@@ -372,18 +709,19 @@ impl AstParser {
 
         let expression = self.parse_expression()?;
 
-        Ok(Statement::Assignment { name, value: expression, pos })
+        Ok(Statement::Assignment { name, value: expression, pos, span: start.to(self.previous_span()) })
     }
     
-    /// Parses an expression with term-level operators (+ and -).
+    /// Parses an expression, starting at the lowest precedence level, `||`.
     ///
-    /// This is the lowest precedence level for binary operators. Handles left-associative
-    /// chains of addition and subtraction operations.
+    /// `||` and `&&` are left associative and bind more loosely than the comparisons,
+    /// so `x > 0 && x < 1 || y == 0` groups as `((x > 0) && (x < 1)) || (y == 0)`.
     ///
     /// # Grammar
     ///
     /// ```text
-    /// expression := term (('+' | '-') term)*
+    /// expression  := conjunction ('||' conjunction)*
+    /// conjunction := comparison ('&&' comparison)*
     /// ```
     ///
     /// # Returns
@@ -393,6 +731,109 @@ impl AstParser {
     fn parse_expression(&mut self) -> Result<Expression, ParseError> {
 
         let pos = self.current_position();
+        let start = self.current_span();
+
+        let mut left = self.parse_conjunction()?;
+
+        while self.current_token_is(&Token::Or) {
+            self.advance();
+            let right = self.parse_conjunction()?;
+
+            left = Expression::Binary {
+                left: Box::new(left),
+                op: BinaryOp::Or,
+                right: Box::new(right),
+                pos,
+                span: start.to(self.previous_span()),
+            };
+        }
+
+        Ok(left)
+    }
+
+    /// Parses a chain of `&&` operators; see `parse_expression`.
+    fn parse_conjunction(&mut self) -> Result<Expression, ParseError> {
+
+        let pos = self.current_position();
+        let start = self.current_span();
+
+        let mut left = self.parse_comparison()?;
+
+        while self.current_token_is(&Token::And) {
+            self.advance();
+            let right = self.parse_comparison()?;
+
+            left = Expression::Binary {
+                left: Box::new(left),
+                op: BinaryOp::And,
+                right: Box::new(right),
+                pos,
+                span: start.to(self.previous_span()),
+            };
+        }
+
+        Ok(left)
+    }
+
+    /// Parses a comparison (`<`, `<=`, `>`, `>=`, `==` or `!=`) between two sums.
+    ///
+    /// Comparisons are not associative: `a < b < c` is a syntax error rather than
+    /// comparing the 1 or 0 of `a < b` with `c`.
+    ///
+    /// # Grammar
+    ///
+    /// ```text
+    /// comparison := sum (('<' | '<=' | '>' | '>=' | '==' | '!=') sum)?
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Expression)` - Successfully parsed comparison or sum
+    /// * `Err(ParseError)` - Invalid syntax in sub-expressions, or a chained comparison
+    fn parse_comparison(&mut self) -> Result<Expression, ParseError> {
+
+        let pos = self.current_position();
+        let start = self.current_span();
+
+        let left = self.parse_sum()?;
+
+        let Some(op) = self.try_consume_comparison_operator() else {
+            return Ok(left);
+        };
+        let right = self.parse_sum()?;
+
+        if Self::comparison_operator(self.current_token()).is_some() {
+            return Err(self.error("Comparisons cannot be chained; combine them with '&&'"));
+        }
+
+        Ok(Expression::Binary {
+            left: Box::new(left),
+            op,
+            right: Box::new(right),
+            pos,
+            span: start.to(self.previous_span()),
+        })
+    }
+
+    /// Parses a sum with term-level operators (+ and -).
+    ///
+    /// Handles left-associative chains of addition and subtraction operations, which
+    /// bind more tightly than the comparisons.
+    ///
+    /// # Grammar
+    ///
+    /// ```text
+    /// sum := term (('+' | '-') term)*
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Expression)` - Successfully parsed sum
+    /// * `Err(ParseError)` - Invalid syntax in sub-expressions
+    fn parse_sum(&mut self) -> Result<Expression, ParseError> {
+
+        let pos = self.current_position();
+        let start = self.current_span();
 
         let mut left = self.parse_term()?;
 
@@ -404,7 +845,8 @@ impl AstParser {
                 left: Box::new(left),
                 op,
                 right: Box::new(right),
-                pos 
+                pos,
+                span: start.to(self.previous_span()),
             };
         }
 
@@ -430,6 +872,7 @@ impl AstParser {
     fn parse_term(&mut self) -> Result<Expression, ParseError> {
 
         let pos = self.current_position();
+        let start = self.current_span();
 
         let mut left = self.parse_factor()?;
 
@@ -441,7 +884,8 @@ impl AstParser {
                 left: Box::new(left),
                 op,
                 right: Box::new(right) , 
-                pos
+                pos,
+                span: start.to(self.previous_span()),
             };
         }
 
@@ -466,6 +910,7 @@ impl AstParser {
     fn parse_factor(&mut self) -> Result<Expression, ParseError> {
 
         let pos = self.current_position();
+        let start = self.current_span();
 
         let left = self.parse_unary()?;
 
@@ -477,7 +922,8 @@ impl AstParser {
                 left: Box::new(left),
                 op,
                 right: Box::new(right),
-                pos
+                pos,
+                span: start.to(self.previous_span()),
             })
         }
         else{
@@ -486,15 +932,15 @@ impl AstParser {
 
     }
     
-    /// Parses unary expressions with prefix operators (+ and -).
+    /// Parses unary expressions with prefix operators (+, - and !).
     ///
-    /// Handles unary plus and minus operations. These operators bind more tightly
-    /// than binary operators and can be chained (e.g., `--5` is valid).
+    /// Handles unary plus, minus and logical not. These operators bind more tightly
+    /// than the binary operators and can be chained (e.g., `--5` and `!!x` are valid).
     ///
     /// # Grammar
     ///
     /// ```text
-    /// unary := ('+' | '-')* primary
+    /// unary := ('+' | '-' | '!')* uncertain
     /// ```
     ///
     /// # Returns
@@ -504,18 +950,109 @@ impl AstParser {
     fn parse_unary(&mut self) -> Result<Expression, ParseError> {
 
         let pos = self.current_position();
+        let start = self.current_span();
 
         match self.try_consume_unary_operator() {
-            Some(op) => Ok(Expression::Unary { op, operand: Box::new(self.parse_unary()?), pos }),
-            None => self.parse_primary(),
+            Some(op) => {
+                let operand = Box::new(self.parse_unary()?);
+                Ok(Expression::Unary { op, operand, pos, span: start.to(self.previous_span()) })
+            },
+            None => self.parse_uncertain(),
+        }
+
+    }
+
+    /// Parses the uncertainty operator `+/-`, which binds tighter than any other operator.
+    ///
+    /// The operator is left associative, so `x +/- a +/- b` adds `a` and then `b` in
+    /// quadrature. A plain literal followed by a plain literal (`12.3 +/- 0.5`), a
+    /// relative uncertainty (`12.3 +/- 2%`) or an asymmetric uncertainty
+    /// (`12.3(+0.4/-0.2)`) is folded into a single `NumberWithUncertainty`; anything
+    /// else becomes a `BinaryOp::PlusMinus` node positioned at the `+/-` token. A zero
+    /// uncertainty (`12.3 +/- 0`) is not folded, so that it can still be linted.
+    ///
+    /// # Grammar
+    ///
+    /// ```text
+    /// uncertain := primary asymmetric_error? ('+/-' (primary | relative_number))*
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Expression)` - Successfully parsed expression
+    /// * `Err(ParseError)` - Invalid uncertainty operand
+    fn parse_uncertain(&mut self) -> Result<Expression, ParseError> {
+
+        let start = self.current_span();
+        let mut left = self.parse_primary()?;
+
+        if let Token::AsymmetricError(upper, lower) = *self.current_token() {
+            left = self.parse_literal_uncertainty(left, UncertaintyNotation::Asymmetric { upper, lower })?;
         }
 
+        while self.current_token_is(&Token::PlusMinus) {
+            let pos = self.current_position();
+            self.advance();
+
+            if let Token::RelativeNumber(amount, unit) = *self.current_token() {
+                left = self.parse_literal_uncertainty(left, UncertaintyNotation::Relative { amount, unit })?;
+                continue;
+            }
+
+            let right = self.parse_primary()?;
+            left = match (left, right) {
+                (
+                    Expression::NumberWithUncertainty { value, error: 0.0, pos: value_pos, notation: None, literal, .. },
+                    Expression::NumberWithUncertainty { value: error, error: 0.0, notation: None, .. },
+                ) if error != 0.0 => Expression::NumberWithUncertainty { value, error, pos: value_pos, span: start.to(self.previous_span()), notation: None, literal },
+                (left, right) => Expression::Binary {
+                    left: Box::new(left),
+                    op: BinaryOp::PlusMinus,
+                    right: Box::new(right),
+                    pos,
+                    span: start.to(self.previous_span()),
+                },
+            };
+        }
+
+        Ok(left)
+    }
+
+    /// Applies an uncertainty written in a literal-only notation to the preceding value.
+    ///
+    /// Relative uncertainty (`12.3 +/- 2%`) and asymmetric uncertainty (`12.3(+0.4/-0.2)`)
+    /// are only defined for an exact numeric literal, since the absolute error is fixed
+    /// while parsing. Consumes the token holding the uncertainty.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The expression the uncertainty applies to
+    /// * `notation` - The uncertainty as written
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Expression::NumberWithUncertainty)` - Successfully parsed
+    /// * `Err(ParseError)` - The value is not an exact literal
+    fn parse_literal_uncertainty(&mut self, value: Expression, notation: UncertaintyNotation) -> Result<Expression, ParseError> {
+
+        match value {
+            Expression::NumberWithUncertainty { value, error: 0.0, pos, span, notation: None, literal } => {
+                self.advance();
+                let error = notation.absolute_error(value);
+                let span = span.to(self.previous_span());
+                Ok(Expression::NumberWithUncertainty { value, error, pos, span, notation: Some(notation), literal })
+            },
+            _ => match notation {
+                UncertaintyNotation::Relative { .. } => Err(self.error("Relative uncertainty requires an exact number before '+/-'")),
+                UncertaintyNotation::Asymmetric { .. } => Err(self.error("Asymmetric uncertainty requires an exact number before it")),
+            },
+        }
     }
     
     /// Parses primary expressions (the highest precedence constructs).
     ///
     /// Primary expressions include:
-    /// - Numeric literals (with optional uncertainty)
+    /// - Numeric literals
     /// - Variable references
     /// - Function calls
     /// - Parenthesized expressions
@@ -523,7 +1060,7 @@ impl AstParser {
     /// # Grammar
     ///
     /// ```text
-    /// primary := number ('+/-' number)?
+    /// primary := number
     ///         | identifier '(' argument_list ')'
     ///         | identifier
     ///         | '(' expression ')'
@@ -536,7 +1073,12 @@ impl AstParser {
     fn parse_primary(&mut self) -> Result<Expression, ParseError> {
 
         let pos = self.current_position();
+        let span = self.current_span();
 
+        // Leave anything else in place, so a newline here still ends the statement when recovering
+        if !matches!(self.current_token(), Token::LeftParen | Token::Identifier(_) | Token::Number(_)) {
+            return Err(ParseError { message: "Expected expression".to_string(), position: pos, span });
+        }
         let current = self.advance().clone();
         let next = self.peek_token();
 
@@ -544,47 +1086,17 @@ impl AstParser {
             (Token::LeftParen, _) => {
                 let expr = self.parse_expression()?;
                 self.expect_token(Token::RightParen)?;
-                Ok(expr)
+                Ok(expr.with_span(span.to(self.previous_span())))
             },
             (Token::Identifier(name), Token::LeftParen) => {
                 self.parse_function_call(name, pos)
             }
-            (Token::Identifier(name), _) => Ok(Expression::Variable(name.to_string())), 
-            (Token::Number(n), Token::PlusMinus) => { 
-                self.parse_number_with_uncertainty(n, pos) 
-            },
+            (Token::Identifier(name), _) => Ok(Expression::Variable { name, pos, span }), 
             (Token::Number(n), _) => { 
-                Ok(Expression::NumberWithUncertainty { value: n, error: 0.0, pos }) 
-            },
-            _ => Err(self.error("Expected expression")),
-        }
-
-    }
-
-    /// Parses a number with uncertainty notation: `value +/- error`.
-    ///
-    /// Called when a number is followed by the `+/-` token. Expects another
-    /// number token representing the uncertainty value.
-    ///
-    /// # Arguments
-    ///
-    /// * `number` - The base value
-    /// * `pos` - Source position of the number
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(Expression::NumberWithUncertainty)` - Successfully parsed
-    /// * `Err(ParseError)` - Missing or invalid uncertainty value
-    fn parse_number_with_uncertainty(&mut self, number: f64, pos: Position) -> Result<Expression, ParseError> {
-
-        self.expect_token(Token::PlusMinus)?;
-        match self.advance() {
-            Token::Number(error) => {
-                Ok(Expression::NumberWithUncertainty { value:number , error: *error, pos })
+                let literal = self.tokens[self.current - 1].literal.clone();
+                Ok(Expression::NumberWithUncertainty { value: n, error: 0.0, pos, span: self.previous_span(), notation: None, literal }) 
             },
-            _ => {
-                Err(self.error("Expected uncertainty number"))
-            }
+            _ => Err(ParseError { message: "Expected expression".to_string(), position: pos, span }),
         }
 
     }
@@ -604,10 +1116,11 @@ impl AstParser {
     /// * `Err(ParseError)` - Missing parentheses or invalid arguments
     fn parse_function_call(&mut self, name: String, pos: Position) -> Result<Expression, ParseError> {
 
+        let start = self.previous_span();
         self.expect_token(Token::LeftParen)?;
         let args = self.parse_argument_list()?;
         self.expect_token(Token::RightParen)?;
-        Ok(Expression::FunctionCall { name, args, pos })
+        Ok(Expression::FunctionCall { name, args, pos, span: start.to(self.previous_span()) })
     }
 
     /// Parses a comma-separated list of function arguments.
@@ -642,6 +1155,13 @@ impl AstParser {
         }
     }
 
+    /// Skips the rest of a statement that failed to parse, up to the next newline.
+    fn synchronize(&mut self) {
+        while !self.is_at_end() && !self.current_token_is(&Token::Newline) {
+            self.advance();
+        }
+    }
+
     /// Returns a reference to the current token without advancing.
     fn current_token(&self) -> &Token {
         &self.tokens[self.current].token
@@ -695,9 +1215,9 @@ impl AstParser {
     /// * `n` - Number of positions to look ahead
     fn peek_token_n(&self, n: usize) -> &Token {
         if n + self.current < self.tokens.len() {
-            return &self.tokens[n + self.current].token;
+            &self.tokens[n + self.current].token
         } else {
-            return &Token::EOF; 
+            &Token::EOF
         }
     }
 
@@ -719,7 +1239,7 @@ impl AstParser {
         }
     }
 
-    /// Attempts to consume a unary operator (+ or -).
+    /// Attempts to consume a unary operator (+, - or !).
     ///
     /// Similar to `try_consume_term_operator` but returns unary operators.
     ///
@@ -727,11 +1247,38 @@ impl AstParser {
     ///
     /// * `Some(UnaryOp::Plus)` - If `+` token consumed
     /// * `Some(UnaryOp::Minus)` - If `-` token consumed
+    /// * `Some(UnaryOp::Not)` - If `!` token consumed
     /// * `None` - If current token is not a unary operator
     fn try_consume_unary_operator(&mut self) -> Option<UnaryOp> {
         match self.current_token() {
             Token::Plus => { self.advance(); Some(UnaryOp::Plus) },
             Token::Minus => { self.advance(); Some(UnaryOp::Minus) },
+            Token::Not => { self.advance(); Some(UnaryOp::Not) },
+            _ => None,
+        }
+    }
+
+    /// Attempts to consume a comparison operator (<, <=, >, >=, == or !=).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(BinaryOp)` - The comparison whose token was consumed
+    /// * `None` - If current token is not a comparison operator
+    fn try_consume_comparison_operator(&mut self) -> Option<BinaryOp> {
+        let op = Self::comparison_operator(self.current_token())?;
+        self.advance();
+        Some(op)
+    }
+
+    /// Returns the comparison a token stands for, if any.
+    fn comparison_operator(token: &Token) -> Option<BinaryOp> {
+        match token {
+            Token::Less => Some(BinaryOp::Less),
+            Token::LessEqual => Some(BinaryOp::LessEqual),
+            Token::Greater => Some(BinaryOp::Greater),
+            Token::GreaterEqual => Some(BinaryOp::GreaterEqual),
+            Token::Equal => Some(BinaryOp::Equal),
+            Token::NotEqual => Some(BinaryOp::NotEqual),
             _ => None,
         }
     }
@@ -770,7 +1317,17 @@ impl AstParser {
     
     /// Returns the current source position for error reporting.
     fn current_position(&self) -> Position {
-        self.tokens[self.current].position.clone()        
+        self.tokens[self.current].position        
+    }
+
+    /// Returns the span of the current token.
+    fn current_span(&self) -> Span {
+        self.tokens[self.current].span
+    }
+
+    /// Returns the span of the most recently consumed token.
+    fn previous_span(&self) -> Span {
+        self.tokens[self.current.saturating_sub(1)].span
     }
 
     /// Consumes and validates a variable type token.
@@ -835,7 +1392,8 @@ impl AstParser {
     fn error(&self, message: &str) -> ParseError {
         ParseError { 
             message: message.to_string(), 
-            position: self.current_position() 
+            position: self.current_position(),
+            span: self.current_span(),
         }
     }
 }
\ No newline at end of file
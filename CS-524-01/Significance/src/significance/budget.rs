@@ -0,0 +1,299 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Error budgets (sensitivity reports) for expressions.
+//!
+//! An error budget breaks the uncertainty of a result down into the contribution of each
+//! source measurement. A source is an uncertain literal (`12.3 +/- 0.5`), an uncertainty
+//! attached to an expression (`x +/- dx`), or a variable holding an uncertain value with
+//! no recorded definition. Variables defined by an
+//! assignment are followed through to the sources in their definitions, and a variable
+//! assigned directly from an uncertain literal is reported under its own name.
+//!
+//! Each source's contribution is `(∂f/∂xᵢ · σᵢ)²`, with the sensitivity `∂f/∂xᵢ` estimated
+//! by the same finite differences used in the propagation engine. Because a source that
+//! is used several times (e.g. `x * x`) is differentiated as a single input, the budget's
+//! total accounts for that correlation and may differ from the uncertainty the executor
+//! reports, which treats every operand as independent. Asymmetric sources are counted
+//! with the larger of their two errors.
+//!
+//! The budget is always a statistical (`Real`) breakdown. Under another numeric backend,
+//! variables without a recorded definition contribute their `value()` and `error()`.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{Number, Real};
+use crate::significance::ast_parser::{is_true, Expression, BinaryOp, UnaryOp};
+use crate::significance::executor::VarRunTime;
+use crate::significance::propagation::partial_derivative;
+use crate::significance::standard_lib::{lookup_std_function, NOMINAL_FUNCTION};
+use crate::significance::tokenizer::Position;
+
+/// One source measurement's share of a result's uncertainty.
+#[derive(Debug, Clone)]
+pub struct BudgetEntry {
+    /// Variable name, or the literal's position (prefixed with the variable whose
+    /// definition contains it, if any)
+    pub source: String,
+
+    /// Standard uncertainty of the source
+    pub error: f64,
+
+    /// Partial derivative of the result with respect to the source
+    pub sensitivity: f64,
+
+    /// Contribution to the result's variance: `(sensitivity · error)²`
+    pub variance: f64,
+
+    /// Contribution as a percentage of the total variance
+    pub percent: f64,
+}
+
+/// Breakdown of a result's uncertainty into per-source contributions.
+#[derive(Debug, Clone)]
+pub struct ErrorBudget {
+    /// The result value with the uncertainty implied by the budget
+    pub result: Real,
+
+    /// Per-source contributions, largest first
+    pub entries: Vec<BudgetEntry>,
+}
+
+impl ErrorBudget {
+    /// Returns the total variance of the result (sum of all contributions).
+    pub fn total_variance(&self) -> f64 {
+        self.entries.iter().map(|e| e.variance).sum()
+    }
+}
+
+impl fmt::Display for ErrorBudget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Error budget for {}", self.result)?;
+        if self.entries.is_empty() {
+            return write!(f, "  (no uncertain inputs)");
+        }
+        write!(f, "  {:<24} {:>14} {:>14} {:>14} {:>8}", "source", "error", "sensitivity", "variance", "share")?;
+        for entry in &self.entries {
+            write!(f, "\n  {:<24} {:>14.6e} {:>14.6e} {:>14.6e} {:>7.2}%",
+                entry.source, entry.error, entry.sensitivity, entry.variance, entry.percent)?;
+        }
+        Ok(())
+    }
+}
+
+/// Identifies a source measurement.
+#[derive(Debug, Clone, PartialEq)]
+enum SourceKey {
+    /// A variable reported under its own name
+    Variable(String),
+
+    /// A literal, identified by the variable whose definition contains it ("" at top level)
+    /// and its position within that definition
+    Literal(String, usize, usize),
+
+    /// An uncertainty attached with `+/-`, identified like a literal by the operator's position
+    PlusMinus(String, usize, usize),
+}
+
+/// A source measurement discovered while walking an expression.
+struct Source {
+    key: SourceKey,
+    value: f64,
+    error: f64,
+}
+
+/// Computes the error budget of an expression.
+///
+/// # Arguments
+///
+/// * `expression` - The expression to analyze
+/// * `vars` - Runtime variables, used to follow variable definitions
+///
+/// # Returns
+///
+/// The budget, with entries sorted from the largest contribution to the smallest
+pub fn error_budget<N: Number>(expression: &Expression, vars: &HashMap<String, VarRunTime<N>>) -> ErrorBudget {
+    let mut sources = Vec::new();
+    collect_sources(expression, "", vars, &mut sources);
+
+    let point: Vec<f64> = sources.iter().map(|s| s.value).collect();
+    let f = |values: &[f64]| evaluate_f64(expression, "", vars, &sources, values);
+
+    let value = f(&point);
+    let mut entries: Vec<BudgetEntry> = sources.iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let sensitivity = partial_derivative(&f, &point, i);
+            BudgetEntry {
+                source: source_label(&source.key),
+                error: source.error,
+                sensitivity,
+                variance: (sensitivity * source.error).powi(2),
+                percent: 0.0,
+            }
+        })
+        .collect();
+
+    let total: f64 = entries.iter().map(|e| e.variance).sum();
+    for entry in &mut entries {
+        entry.percent = if total > 0.0 { 100.0 * entry.variance / total } else { 0.0 };
+    }
+    entries.sort_by(|a, b| b.variance.total_cmp(&a.variance));
+
+    ErrorBudget { result: Real::with_error(value, total.sqrt()), entries }
+}
+
+/// Returns the human-readable label for a source.
+fn source_label(key: &SourceKey) -> String {
+    match key {
+        SourceKey::Variable(name) => name.clone(),
+        SourceKey::Literal(owner, line, column) if owner.is_empty() => format!("literal at {}:{}", line, column),
+        SourceKey::Literal(owner, line, column) => format!("{}: literal at {}:{}", owner, line, column),
+        SourceKey::PlusMinus(owner, line, column) if owner.is_empty() => format!("+/- at {}:{}", line, column),
+        SourceKey::PlusMinus(owner, line, column) => format!("{}: +/- at {}:{}", owner, line, column),
+    }
+}
+
+/// Returns the name a budget entry for an expression would have: a variable's name, or
+/// the label of a literal or `+/-` inside the definition of `owner` ("" at top level).
+///
+/// Used to match budget entries with the nodes of a computation graph.
+pub(crate) fn source_name(expression: &Expression, owner: &str) -> Option<String> {
+    match expression {
+        Expression::Variable { name, .. } => Some(name.clone()),
+        Expression::NumberWithUncertainty { pos, .. } => Some(source_label(&literal_key(owner, pos))),
+        Expression::Binary { op: BinaryOp::PlusMinus, pos, .. } => Some(source_label(&plus_minus_key(owner, pos))),
+        _ => None,
+    }
+}
+
+/// Returns the key for a literal inside the definition of `owner`.
+fn literal_key(owner: &str, pos: &Position) -> SourceKey {
+    SourceKey::Literal(owner.to_string(), pos.line, pos.column)
+}
+
+/// Returns the key for an uncertainty attached by the `+/-` at `pos`, inside the definition of `owner`.
+fn plus_minus_key(owner: &str, pos: &Position) -> SourceKey {
+    SourceKey::PlusMinus(owner.to_string(), pos.line, pos.column)
+}
+
+/// Walks an expression (following variable definitions) and records every uncertain source once.
+///
+/// The uncertainty operand of `+/-` and the argument of `nominal` contribute no sources
+/// of their own, since their uncertainty is dropped by the executor.
+fn collect_sources<N: Number>(expression: &Expression, owner: &str, vars: &HashMap<String, VarRunTime<N>>, sources: &mut Vec<Source>) {
+    let mut add = |key: SourceKey, value: f64, error: f64| {
+        if !sources.iter().any(|s| s.key == key) {
+            sources.push(Source { key, value, error });
+        }
+    };
+
+    match expression {
+        Expression::NumberWithUncertainty { value, error, pos, .. } => {
+            if *error != 0.0 {
+                add(literal_key(owner, pos), *value, *error);
+            }
+        },
+        Expression::Variable { name, .. } => {
+            let Some(var) = vars.get(name) else { return };
+            match var.get_definition() {
+                Some(Expression::NumberWithUncertainty { value, error, .. }) => {
+                    if *error != 0.0 {
+                        add(SourceKey::Variable(name.clone()), *value, *error);
+                    }
+                },
+                Some(definition) => collect_sources(definition, name, vars, sources),
+                None => {
+                    let current = var.get_value();
+                    if current.error() != 0.0 {
+                        add(SourceKey::Variable(name.clone()), current.value(), current.error());
+                    }
+                },
+            }
+        },
+        Expression::Binary { left, op: BinaryOp::PlusMinus, right, pos, .. } => {
+            let error = evaluate_f64(right, owner, vars, &[], &[]).abs();
+            if error != 0.0 {
+                add(plus_minus_key(owner, pos), 0.0, error);
+            }
+            collect_sources(left, owner, vars, sources);
+        },
+        // Comparisons and logical operators give an exact result
+        Expression::Binary { op, .. } if op.is_boolean() => {},
+        Expression::Unary { op: UnaryOp::Not, .. } => {},
+        Expression::Binary { left, right, .. } => {
+            collect_sources(left, owner, vars, sources);
+            collect_sources(right, owner, vars, sources);
+        },
+        Expression::Unary { operand, .. } => collect_sources(operand, owner, vars, sources),
+        Expression::FunctionCall { name, .. } if name == NOMINAL_FUNCTION => {},
+        Expression::FunctionCall { args, .. } => {
+            for arg in args {
+                collect_sources(arg, owner, vars, sources);
+            }
+        },
+    }
+}
+
+/// Evaluates an expression on plain `f64` values, substituting `values` for the sources.
+fn evaluate_f64<N: Number>(expression: &Expression, owner: &str, vars: &HashMap<String, VarRunTime<N>>, sources: &[Source], values: &[f64]) -> f64 {
+    let lookup = |key: &SourceKey| sources.iter().position(|s| &s.key == key).map(|i| values[i]);
+
+    match expression {
+        Expression::NumberWithUncertainty { value, pos, .. } => {
+            lookup(&literal_key(owner, pos)).unwrap_or(*value)
+        },
+        Expression::Variable { name, .. } => {
+            if let Some(value) = lookup(&SourceKey::Variable(name.clone())) {
+                return value;
+            }
+            match vars.get(name) {
+                Some(var) => match var.get_definition() {
+                    Some(definition) => evaluate_f64(definition, name, vars, sources, values),
+                    None => var.get_value().value(),
+                },
+                None => f64::NAN,
+            }
+        },
+        Expression::Binary { left, op, right, pos, .. } => {
+            let l = evaluate_f64(left, owner, vars, sources, values);
+            let r = evaluate_f64(right, owner, vars, sources, values);
+            if let Some(result) = op.apply_boolean(l, r) {
+                return result;
+            }
+            match op {
+                BinaryOp::Add => l + r,
+                BinaryOp::Sub => l - r,
+                BinaryOp::Mul => l * r,
+                BinaryOp::Div => l / r,
+                BinaryOp::Mod => l % r,
+                BinaryOp::Power => l.powf(r),
+                BinaryOp::Root => l.powf(1.0 / r),
+                // The attached uncertainty is a zero-mean deviation added to the value
+                BinaryOp::PlusMinus => l + lookup(&plus_minus_key(owner, pos)).unwrap_or(0.0),
+                _ => unreachable!("comparison and logical operators are applied above"),
+            }
+        },
+        Expression::Unary { op, operand, .. } => {
+            let v = evaluate_f64(operand, owner, vars, sources, values);
+            match op {
+                UnaryOp::Plus => v,
+                UnaryOp::Minus => -v,
+                UnaryOp::Not => if is_true(v) { 0.0 } else { 1.0 },
+            }
+        },
+        Expression::FunctionCall { name, args, .. } => {
+            let vals: Vec<f64> = args.iter().map(|a| evaluate_f64(a, owner, vars, sources, values)).collect();
+            match lookup_std_function(name) {
+                Some(function) => (function.eval)(&vals),
+                // `budget(x)` evaluates to `x`
+                None => vals.first().copied().unwrap_or(f64::NAN),
+            }
+        },
+    }
+}
@@ -0,0 +1,296 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Bytecode for the stack VM (`vm.rs`).
+//!
+//! An analyzed `Program` is compiled to a flat list of `Instruction`s. Every variable
+//! is resolved to a numbered slot at compile time, and every literal is converted to
+//! the numeric backend once, into the chunk's constant pool, so running a chunk does
+//! no name lookups and walks no tree:
+//!
+//! ```text
+//! x := 12.3 +/- 0.5        Constant(0)  Store { slot: x }
+//! x * 2 + 1                Load(x)  Constant(1)  Binary(Mul)  Constant(2)  Binary(Add)  Print
+//! ```
+//!
+//! The instructions mirror the tree-walking `Executor` exactly, including the order
+//! in which operands are evaluated and errors are recorded, so both give identical
+//! results.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::significance::ast_parser::{BinaryOp, Expression, Program, Statement, UnaryOp};
+use crate::significance::executor::literal_value;
+use crate::significance::numbers::Number;
+use crate::significance::source_map::Span;
+use crate::significance::standard_lib::{is_assertion, resolve_builtin, Builtin};
+use crate::significance::tokenizer::Position;
+
+/// One operation of the stack VM.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    /// Pushes a value from the constant pool
+    Constant(usize),
+
+    /// Pushes the value of a variable; an undeclared variable is an error at this position
+    Load(usize, Position),
+
+    /// Declares a variable, initialized to zero
+    Declare(usize),
+
+    /// Pops a value into a variable, recording the expression it was assigned from
+    Store {
+        /// Slot of the variable
+        slot: usize,
+
+        /// Index of the assigned expression in the chunk
+        definition: usize,
+
+        /// Position of the assignment, where an undeclared variable is reported
+        pos: Position,
+    },
+
+    /// Pops the right then the left operand and pushes the result
+    Binary(BinaryOp, Position),
+
+    /// Negates the value on top of the stack
+    Negate,
+
+    /// Replaces the value on top of the stack with an exact 1 if it is zero, otherwise 0
+    Not,
+
+    /// Skips the right operand of `&&` or `||` when the left one, on top of the stack,
+    /// decides the result: replaces it with an exact 0 (`&&`) or 1 (`||`) and jumps to
+    /// `target`. Otherwise leaves it for the `Binary` after the right operand.
+    ShortCircuit {
+        /// `BinaryOp::And` or `BinaryOp::Or`
+        op: BinaryOp,
+
+        /// Index of the instruction after the `Binary`
+        target: usize,
+    },
+
+    /// Pops the arguments of a `STD_LIB` function and pushes its result
+    Call {
+        /// Index of the function in `STD_LIB`
+        function: usize,
+
+        /// Number of arguments
+        argc: usize,
+    },
+
+    /// Drops the uncertainty of the value on top of the stack (`nominal`)
+    Nominal,
+
+    /// Prints the error budget of an expression of the chunk (`budget`)
+    Budget(usize),
+
+    /// Prints the uncertainty formula of an expression of the chunk with the value on
+    /// top of the stack, which it leaves there (`formula`)
+    Formula(usize),
+
+    /// Pops the arguments of an assertion and pushes its first argument
+    Assert {
+        /// Name of the assertion
+        function: &'static str,
+
+        /// Number of arguments
+        argc: usize,
+
+        /// Span of the call, where a failure is reported
+        span: Span,
+    },
+
+    /// Pops a value and prints it
+    Print,
+
+    /// Pops a value
+    Pop,
+}
+
+/// A compiled program (or expression).
+#[derive(Debug, Clone)]
+pub struct Chunk<N> {
+    /// The instructions, run in order
+    pub code: Vec<Instruction>,
+
+    /// Values of the literals, in the numeric backend
+    pub constants: Vec<N>,
+
+    /// Expressions kept for error budgets: the definitions of assignments and the
+    /// arguments of `budget`. Assigned variables share their definition with the chunk.
+    pub expressions: Vec<Rc<Expression>>,
+}
+
+impl<N> Chunk<N> {
+    fn new() -> Self {
+        Self { code: Vec::new(), constants: Vec::new(), expressions: Vec::new() }
+    }
+}
+
+/// Compiles programs, resolving variable names to slots.
+///
+/// Slots are kept from one compilation to the next, so that chunks compiled one after
+/// another (as in the REPL) share their variables.
+#[derive(Debug, Clone, Default)]
+pub struct Compiler {
+    /// Slot of every name seen so far
+    slots: HashMap<String, usize>,
+
+    /// Name of every slot
+    names: Vec<String>,
+}
+
+impl Compiler {
+    /// Creates a compiler with no slots.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the slot of a name, allocating one the first time the name is seen.
+    pub fn slot(&mut self, name: &str) -> usize {
+        if let Some(slot) = self.slots.get(name) {
+            return *slot;
+        }
+        self.names.push(name.to_string());
+        self.slots.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    /// Returns the slot of a name, if it has one.
+    pub fn lookup(&self, name: &str) -> Option<usize> {
+        self.slots.get(name).copied()
+    }
+
+    /// Returns the name of a slot.
+    pub fn name(&self, slot: usize) -> &str {
+        &self.names[slot]
+    }
+
+    /// Returns the number of slots allocated.
+    pub fn slot_count(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Compiles a program.
+    ///
+    /// Expression statements print their result, except assertions, and imports
+    /// compile to nothing (imported files are run by the interpreter).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a function doesn't exist or is called with the wrong number of
+    /// arguments. Both are expected to be caught by semantic analysis.
+    pub fn compile_program<N: Number>(&mut self, program: &Program) -> Chunk<N> {
+        let mut chunk = Chunk::new();
+        for statement in &program.statements {
+            self.statement(statement, &mut chunk);
+        }
+        chunk
+    }
+
+    /// Compiles a single statement.
+    pub fn compile_statement<N: Number>(&mut self, statement: &Statement) -> Chunk<N> {
+        let mut chunk = Chunk::new();
+        self.statement(statement, &mut chunk);
+        chunk
+    }
+
+    /// Compiles an expression, leaving its value on the stack.
+    pub fn compile_expression<N: Number>(&mut self, expression: &Expression) -> Chunk<N> {
+        let mut chunk = Chunk::new();
+        self.expression(expression, &mut chunk);
+        chunk
+    }
+
+    fn statement<N: Number>(&mut self, statement: &Statement, chunk: &mut Chunk<N>) {
+        match statement {
+            Statement::VarDeclaration { name, .. } => {
+                let slot = self.slot(name);
+                chunk.code.push(Instruction::Declare(slot));
+            },
+            Statement::Assignment { name, value, pos, .. } => {
+                self.expression(value, chunk);
+                chunk.expressions.push(Rc::new(value.clone()));
+                let slot = self.slot(name);
+                chunk.code.push(Instruction::Store { slot, definition: chunk.expressions.len() - 1, pos: *pos });
+            },
+            Statement::Expression(expression) => {
+                self.expression(expression, chunk);
+                match expression {
+                    Expression::FunctionCall { name, .. } if is_assertion(name) => chunk.code.push(Instruction::Pop),
+                    _ => chunk.code.push(Instruction::Print),
+                }
+            },
+            Statement::Import { .. } => {},
+        }
+    }
+
+    fn expression<N: Number>(&mut self, expression: &Expression, chunk: &mut Chunk<N>) {
+        match expression {
+            Expression::NumberWithUncertainty { value, error, notation, literal, .. } => {
+                chunk.constants.push(literal_value(*value, *error, notation, literal.as_deref()));
+                chunk.code.push(Instruction::Constant(chunk.constants.len() - 1));
+            },
+            Expression::Variable { name, pos, .. } => {
+                let slot = self.slot(name);
+                chunk.code.push(Instruction::Load(slot, *pos));
+            },
+            Expression::Binary { left, op: op @ (BinaryOp::And | BinaryOp::Or), right, pos, .. } => {
+                self.expression(left, chunk);
+                let short_circuit = chunk.code.len();
+                chunk.code.push(Instruction::ShortCircuit { op: op.clone(), target: 0 });
+                self.expression(right, chunk);
+                chunk.code.push(Instruction::Binary(op.clone(), *pos));
+                chunk.code[short_circuit] = Instruction::ShortCircuit { op: op.clone(), target: chunk.code.len() };
+            },
+            Expression::Binary { left, op, right, pos, .. } => {
+                self.expression(left, chunk);
+                self.expression(right, chunk);
+                chunk.code.push(Instruction::Binary(op.clone(), *pos));
+            },
+            Expression::Unary { op, operand, .. } => {
+                self.expression(operand, chunk);
+                match op {
+                    UnaryOp::Plus => {},
+                    UnaryOp::Minus => chunk.code.push(Instruction::Negate),
+                    UnaryOp::Not => chunk.code.push(Instruction::Not),
+                }
+            },
+            Expression::FunctionCall { name, args, pos, span } => self.call(name, args, pos, span, chunk),
+        }
+    }
+
+    /// Compiles a call to a built-in, dispatched as `Executor::evaluate_function_call` does.
+    ///
+    /// # Panics
+    ///
+    /// Panics (through `resolve_builtin`) on an unknown function or a wrong number of
+    /// arguments, which semantic analysis catches first.
+    fn call<N: Number>(&mut self, name: &str, args: &[Expression], pos: &Position, span: &Span, chunk: &mut Chunk<N>) {
+        let builtin = resolve_builtin(name, args.len(), pos);
+        if builtin == Builtin::Budget {
+            chunk.expressions.push(Rc::new(args[0].clone()));
+            chunk.code.push(Instruction::Budget(chunk.expressions.len() - 1));
+        }
+
+        for arg in args {
+            self.expression(arg, chunk);
+        }
+        match builtin {
+            Builtin::Budget => {},
+            Builtin::Formula => {
+                chunk.expressions.push(Rc::new(args[0].clone()));
+                chunk.code.push(Instruction::Formula(chunk.expressions.len() - 1));
+            },
+            Builtin::Nominal => chunk.code.push(Instruction::Nominal),
+            Builtin::Assertion(function) => chunk.code.push(Instruction::Assert { function, argc: args.len(), span: *span }),
+            Builtin::Std(function) => chunk.code.push(Instruction::Call { function, argc: args.len() }),
+        }
+    }
+}
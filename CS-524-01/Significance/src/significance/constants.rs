@@ -0,0 +1,85 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Built-in mathematical and physical constants.
+//!
+//! Constants behave like variables that are declared and assigned before the program
+//! starts: they can be read anywhere and reassigning one is an error, but a program
+//! may declare its own variable of the same name, which shadows the constant.
+//! Mathematical constants are exact. Physical constants use the CODATA 2018 recommended
+//! values; those fixed by the 2019 SI definition are exact, and the rest carry their
+//! published standard uncertainty.
+
+use crate::Number;
+
+/// A named constant, with the decimal text of its value and its standard uncertainty.
+pub struct Constant {
+    /// Name the constant is referenced by in Significance source
+    pub name: &'static str,
+
+    /// Decimal text of the value, so arbitrary-precision backends get every digit
+    pub value: &'static str,
+
+    /// Standard uncertainty (`0.0` for exact constants)
+    pub error: f64,
+
+    /// Short human-readable description, including the unit
+    pub description: &'static str,
+}
+
+impl Constant {
+    /// Builds the constant's value in the numeric backend `N`.
+    pub fn to_number<N: Number>(&self) -> N {
+        let value = self.value.parse().expect("constant values are valid numbers");
+        N::from_literal(Some(self.value), value, self.error, self.error)
+    }
+}
+
+/// Every built-in constant.
+///
+/// Adding a constant here makes it visible to both the semantic analyzer and the executor.
+pub const CONSTANTS: &[Constant] = &[
+    // Mathematical constants
+    Constant { name: "pi",  value: "3.14159265358979323846264338327950288419716939937510", error: 0.0, description: "ratio of a circle's circumference to its diameter" },
+    Constant { name: "tau", value: "6.28318530717958647692528676655900576839433879875021", error: 0.0, description: "ratio of a circle's circumference to its radius" },
+    Constant { name: "e",   value: "2.71828182845904523536028747135266249775724709369995", error: 0.0, description: "base of the natural logarithm" },
+    Constant { name: "phi", value: "1.61803398874989484820458683436563811772030917980576", error: 0.0, description: "golden ratio" },
+
+    // Exact physical constants (2019 SI)
+    Constant { name: "c",     value: "299792458",      error: 0.0, description: "speed of light in vacuum (m/s)" },
+    Constant { name: "h",     value: "6.62607015e-34", error: 0.0, description: "Planck constant (J s)" },
+    Constant { name: "hbar",  value: "1.05457181764615639126242800330228074472282633002041e-34", error: 0.0, description: "reduced Planck constant (J s)" },
+    Constant { name: "q_e",   value: "1.602176634e-19", error: 0.0, description: "elementary charge (C)" },
+    Constant { name: "k_B",   value: "1.380649e-23",   error: 0.0, description: "Boltzmann constant (J/K)" },
+    Constant { name: "N_A",   value: "6.02214076e23",  error: 0.0, description: "Avogadro constant (1/mol)" },
+    Constant { name: "R",     value: "8.31446261815324", error: 0.0, description: "molar gas constant (J/(mol K))" },
+    Constant { name: "F",     value: "96485.3321233100184", error: 0.0, description: "Faraday constant (C/mol)" },
+    Constant { name: "sigma", value: "5.67037441918442945397099673188923087584012297029130e-8", error: 0.0, description: "Stefan-Boltzmann constant (W/(m^2 K^4))" },
+    Constant { name: "g_n",   value: "9.80665",        error: 0.0, description: "standard acceleration of gravity (m/s^2)" },
+
+    // Measured physical constants (CODATA 2018)
+    Constant { name: "G",         value: "6.67430e-11",      error: 0.00015e-11,    description: "Newtonian constant of gravitation (m^3/(kg s^2))" },
+    Constant { name: "m_e",       value: "9.1093837015e-31", error: 0.0000000028e-31, description: "electron mass (kg)" },
+    Constant { name: "m_p",       value: "1.67262192369e-27", error: 0.00000000051e-27, description: "proton mass (kg)" },
+    Constant { name: "m_n",       value: "1.67492749804e-27", error: 0.00000000095e-27, description: "neutron mass (kg)" },
+    Constant { name: "m_u",       value: "1.66053906660e-27", error: 0.00000000050e-27, description: "atomic mass constant (kg)" },
+    Constant { name: "alpha",     value: "7.2973525693e-3",  error: 0.0000000011e-3,  description: "fine-structure constant" },
+    Constant { name: "epsilon_0", value: "8.8541878128e-12", error: 0.0000000013e-12, description: "vacuum electric permittivity (F/m)" },
+    Constant { name: "mu_0",      value: "1.25663706212e-6", error: 0.00000000019e-6, description: "vacuum magnetic permeability (N/A^2)" },
+    Constant { name: "a_0",       value: "5.29177210903e-11", error: 0.00000000080e-11, description: "Bohr radius (m)" },
+    Constant { name: "R_inf",     value: "10973731.568160",  error: 0.000021,         description: "Rydberg constant (1/m)" },
+];
+
+/// Looks up a built-in constant by name.
+///
+/// # Returns
+///
+/// * `Some(&Constant)` - The constant definition
+/// * `None` - No constant has this name
+pub fn lookup_constant(name: &str) -> Option<&'static Constant> {
+    CONSTANTS.iter().find(|constant| constant.name == name)
+}
@@ -0,0 +1,258 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! An interactive step debugger for `.sig` programs (`significance debug`).
+//!
+//! The program is loaded and analyzed up front with `Significance::prepare`, then run
+//! one statement at a time with `Significance::execute`. It starts stopped before its
+//! first statement. The commands are:
+//!
+//! | Command | Effect |
+//! | --- | --- |
+//! | `break LINE`, `b` | Stop before the statement on a source line |
+//! | `delete LINE`, `d` | Remove a breakpoint |
+//! | `next`, `n` | Run the current statement and stop at the next (step over) |
+//! | `continue`, `c` | Run until the next breakpoint or the end of the program |
+//! | `print NAME`, `p` | Show a variable's value and the expression it was assigned from |
+//! | `vars` | Show every variable of the program |
+//! | `trace on`, `trace off` | Show every sub-expression the executor evaluates |
+//! | `where`, `w` | Show the current statement |
+//! | `help`, `h` | List the commands |
+//! | `quit`, `q` | Stop debugging |
+//!
+//! Like the language server, the debugger runs over any reader and writer, so the tests
+//! drive scripted sessions in memory.
+
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use crate::Real;
+use crate::significance::ast_parser::Program;
+use crate::significance::language_parser::Significance;
+use crate::significance::numbers::Number;
+use crate::significance::source_map::{FileId, Span};
+
+/// Prompt printed before each command.
+const PROMPT: &str = "(sig) ";
+
+const HELP: &[&str] = &[
+    "break LINE (b)     stop before the statement on LINE",
+    "delete LINE (d)    remove the breakpoint on LINE",
+    "next (n)           run the current statement",
+    "continue (c)       run until a breakpoint or the end",
+    "print NAME (p)     show a variable and its definition",
+    "vars               show every variable",
+    "trace on|off       show each evaluated sub-expression",
+    "where (w)          show the current statement",
+    "help (h)           list the commands",
+    "quit (q)           stop debugging",
+];
+
+/// A program being debugged, stopped before one of its statements.
+pub struct Debugger<N = Real> {
+    /// Interpreter the program was prepared with, which keeps its variables
+    interpreter: Significance<N>,
+
+    /// The statements to run
+    program: Program,
+
+    /// Id of the program's source text
+    file: FileId,
+
+    /// Index of the statement the program is stopped before
+    next: usize,
+
+    /// Source lines to stop at
+    breakpoints: BTreeSet<usize>,
+
+    /// Whether the executor's trace is shown after each statement
+    trace: bool,
+
+    /// Whether `quit` was entered
+    quit: bool,
+}
+
+impl<N: Number> Debugger<N> {
+    /// Loads and analyzes a program for debugging.
+    ///
+    /// # Arguments
+    ///
+    /// * `interpreter` - A fresh interpreter, configured as for running the program
+    /// * `name` - Name of the text shown in diagnostics, typically its path
+    /// * `text` - The complete source text
+    /// * `path` - File the text belongs to, which imports are resolved relative to
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Debugger)` - Stopped before the first statement
+    /// * `Err(Vec<String>)` - The rendered errors that keep the program from running,
+    ///   followed by the lint warnings
+    pub fn new(mut interpreter: Significance<N>, name: &str, text: &str, path: Option<&Path>) -> Result<Self, Vec<String>> {
+        match interpreter.prepare(name, text, path) {
+            Ok((program, file)) => {
+                interpreter.set_capture(true);
+                Ok(Self { interpreter, program, file, next: 0, breakpoints: BTreeSet::new(), trace: false, quit: false })
+            },
+            Err(diagnostics) => {
+                let mut errors: Vec<String> = diagnostics.iter().map(|d| d.render(interpreter.sources())).collect();
+                errors.extend(interpreter.warnings());
+                Err(errors)
+            },
+        }
+    }
+
+    /// Returns whether every statement has run.
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.program.statements.len()
+    }
+
+    /// Returns whether `quit` was entered.
+    pub fn has_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Describes where the program is stopped: `Stopped at line 3: z := x + y`.
+    pub fn location(&self) -> String {
+        match self.program.statements.get(self.next) {
+            Some(statement) => {
+                let line = statement.span().start.line;
+                let text = self.interpreter.sources().line(self.file, line).unwrap_or_default();
+                format!("Stopped at line {}: {}", line, text.trim())
+            },
+            None => "Program finished".to_string(),
+        }
+    }
+
+    /// Handles one command, returning the lines to show.
+    pub fn handle(&mut self, command: &str) -> Vec<String> {
+        let words: Vec<&str> = command.split_whitespace().collect();
+        match words.as_slice() {
+            [] => Vec::new(),
+            ["break" | "b", line] => self.set_breakpoint(line),
+            ["delete" | "d", line] => match line.parse::<usize>() {
+                Ok(line) if self.breakpoints.remove(&line) => vec![format!("Deleted breakpoint at line {}", line)],
+                _ => vec![format!("No breakpoint at line {}", line)],
+            },
+            ["next" | "n"] => self.resume(false),
+            ["continue" | "c"] => self.resume(true),
+            ["print" | "p", name] => vec![self.describe(name)],
+            ["vars"] => self.variables(),
+            ["trace", setting @ ("on" | "off")] => {
+                self.trace = *setting == "on";
+                self.interpreter.set_trace(self.trace);
+                vec![format!("Trace {}", setting)]
+            },
+            ["where" | "w"] => vec![self.location()],
+            ["help" | "h"] => HELP.iter().map(|line| line.to_string()).collect(),
+            ["quit" | "q"] => {
+                self.quit = true;
+                Vec::new()
+            },
+            _ => vec![format!("Unknown command '{}' (enter 'help' for the commands)", command.trim())],
+        }
+    }
+
+    fn set_breakpoint(&mut self, line: &str) -> Vec<String> {
+        let starts_statement = |line: usize| self.program.statements.iter().any(|s| s.span().start.line == line);
+        match line.parse::<usize>() {
+            Ok(line) if starts_statement(line) => {
+                self.breakpoints.insert(line);
+                vec![format!("Breakpoint at line {}", line)]
+            },
+            _ => vec![format!("No statement on line {}", line)],
+        }
+    }
+
+    /// Runs the current statement, then (if `to_breakpoint`) every statement up to the
+    /// next breakpoint, and reports where the program stopped.
+    fn resume(&mut self, to_breakpoint: bool) -> Vec<String> {
+        if self.is_finished() {
+            return vec!["The program has finished".to_string()];
+        }
+
+        let mut lines = self.step();
+        while to_breakpoint && !self.is_finished() && !self.breakpoints.contains(&self.current_line()) {
+            lines.extend(self.step());
+        }
+
+        if self.is_finished() {
+            lines.extend(self.interpreter.warnings());
+        }
+        lines.push(self.location());
+        lines
+    }
+
+    /// Runs the current statement, returning its trace, output and errors.
+    fn step(&mut self) -> Vec<String> {
+        let statement = &self.program.statements[self.next];
+        let errors = self.interpreter.execute(statement, self.file);
+        self.next += 1;
+
+        let mut lines: Vec<String> = self.interpreter.take_trace().iter().map(ToString::to_string).collect();
+        lines.extend(self.interpreter.take_output());
+        lines.extend(errors.iter().map(|error| error.render(self.interpreter.sources())));
+        lines
+    }
+
+    fn current_line(&self) -> usize {
+        self.program.statements[self.next].span().start.line
+    }
+
+    /// Describes a variable: `x = 12.3 +/- 0.5 (from x * 2)`.
+    fn describe(&self, name: &str) -> String {
+        let Some(var) = self.interpreter.get_var(name) else {
+            return format!("No variable '{}'", name);
+        };
+        let definition = var.get_definition().and_then(|definition| self.source(definition.span()));
+        match definition {
+            Some(definition) => format!("{} = {} (from {})", name, var.get_value(), definition),
+            None => format!("{} = {}", name, var.get_value()),
+        }
+    }
+
+    /// Lists the program's variables (not the built-in constants), by name.
+    fn variables(&self) -> Vec<String> {
+        let mut names: Vec<&str> = self.interpreter.variables()
+            .map(|(name, _)| name)
+            .filter(|name| !self.interpreter.is_constant(name))
+            .collect();
+        if names.is_empty() {
+            return vec!["No variables".to_string()];
+        }
+        names.sort();
+        names.iter().map(|name| self.describe(name)).collect()
+    }
+
+    /// Returns the source text of a span on a single line of the program.
+    fn source(&self, span: Span) -> Option<String> {
+        if span.file != self.file || span.start.line != span.end.line {
+            return None;
+        }
+        let line = self.interpreter.sources().line(self.file, span.start.line)?;
+        Some(line.chars().skip(span.start.column - 1).take(span.end.column - span.start.column).collect())
+    }
+}
+
+/// Runs a debugging session, reading commands from `reader` until `quit` or the end
+/// of the input, and writing what they show to `writer`.
+pub fn debug<N: Number>(mut debugger: Debugger<N>, mut reader: impl BufRead, mut writer: impl Write) -> io::Result<()> {
+    writeln!(writer, "{}", debugger.location())?;
+    while !debugger.has_quit() {
+        write!(writer, "{}", PROMPT)?;
+        writer.flush()?;
+
+        let mut command = String::new();
+        if reader.read_line(&mut command)? == 0 {
+            break;
+        }
+        for line in debugger.handle(&command) {
+            writeln!(writer, "{}", line)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,632 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Arbitrary-precision decimal backend.
+//!
+//! `Decimal` keeps the value of a number as an exact decimal (`BigDecimal`) instead of an
+//! `f64`, so literals such as `6.02214076e23` keep every digit they were written with and
+//! print without binary rounding artifacts. Uncertainties are still `f64`: they only need
+//! a few significant digits, and they are propagated with exactly the same rules as
+//! `Real` (evaluated on the `f64` approximations of the operands).
+//!
+//! # Precision
+//!
+//! Addition, subtraction, multiplication, modulus and non-negative integer powers are
+//! exact, unless the exact result would have more than `MAX_EXACT_DIGITS` digits (as for
+//! `1e100000000 + 1`): sums are then rounded to `PRECISION` significant digits, and such
+//! a modulus is evaluated in `f64`. Division, roots and negative integer powers are
+//! rounded to `PRECISION` significant digits. Other library functions and non-integer powers are evaluated in
+//! `f64`, and their results are exact decimals of that `f64` value. Results that are
+//! not finite (e.g. division by zero) are kept as `f64` only.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::{Number, Real};
+use crate::significance::propagation::PropagationOrder;
+use crate::significance::standard_lib::StdFunction;
+
+/// Significant digits kept by operations whose exact result may not be a finite decimal.
+pub const PRECISION: usize = 50;
+
+/// Sums and integer powers whose exact result would have more digits than this are
+/// rounded to `PRECISION` significant digits instead.
+const MAX_EXACT_DIGITS: usize = 1000;
+
+/// Exact decimal number: `(-1)^negative · coefficient · 10^exponent`.
+///
+/// Values are kept normalized (no leading or trailing zero digits in the coefficient,
+/// and zero is never negative), so equal numbers compare equal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigDecimal {
+    negative: bool,
+
+    /// Decimal digits of the coefficient, least significant first (empty for zero)
+    digits: Vec<u8>,
+
+    /// Power of ten the coefficient is scaled by
+    exponent: i64,
+}
+
+impl BigDecimal {
+    /// Returns zero.
+    pub fn zero() -> Self {
+        Self { negative: false, digits: Vec::new(), exponent: 0 }
+    }
+
+    /// Parses a decimal literal such as `12.5`, `-6.02214076e23` or `1E-3`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(BigDecimal)` - The exact value of the literal
+    /// * `None` - The text is not a decimal literal
+    pub fn parse(text: &str) -> Option<Self> {
+        let (negative, text) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (mantissa, exponent) = match text.find(['e', 'E']) {
+            Some(i) => (&text[..i], text[i + 1..].parse::<i64>().ok()?),
+            None => (text, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return None;
+        }
+
+        let mut digits = Vec::with_capacity(integer.len() + fraction.len());
+        for c in integer.chars().chain(fraction.chars()).rev() {
+            digits.push(c.to_digit(10)? as u8);
+        }
+        let exponent = exponent.checked_sub(fraction.len() as i64)?;
+        Some(Self::from_parts(negative, digits, exponent))
+    }
+
+    /// Converts an `f64` to the shortest decimal that rounds back to it.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the value is infinite or NaN
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        Self::parse(&format!("{:e}", value))
+    }
+
+    /// Returns the nearest `f64`.
+    pub fn to_f64(&self) -> f64 {
+        if self.is_zero() {
+            return 0.0;
+        }
+        let coefficient: String = self.digits.iter().rev().map(|d| char::from(b'0' + d)).collect();
+        let sign = if self.negative { "-" } else { "" };
+        format!("{}{}e{}", sign, coefficient, self.exponent).parse().unwrap_or(f64::NAN)
+    }
+
+    /// Returns whether the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Returns whether the value is negative.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns whether the value is an integer.
+    pub fn is_integer(&self) -> bool {
+        self.exponent >= 0
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(&self) -> Self {
+        Self { negative: false, ..self.clone() }
+    }
+
+    /// Returns the value as an `i64`, if it is an integer that fits.
+    fn to_i64(&self) -> Option<i64> {
+        if !self.is_integer() || self.digits.len() as i64 + self.exponent > 18 {
+            return None;
+        }
+        let magnitude = self.digits.iter().rev().fold(0i64, |acc, d| acc * 10 + *d as i64) * 10i64.pow(self.exponent as u32);
+        Some(if self.negative { -magnitude } else { magnitude })
+    }
+
+    /// Returns the power of ten of the leading digit (the exponent in scientific notation).
+    fn magnitude(&self) -> i64 {
+        self.exponent + self.digits.len() as i64 - 1
+    }
+
+    /// Builds a normalized value from possibly unnormalized parts.
+    fn from_parts(negative: bool, mut digits: Vec<u8>, mut exponent: i64) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        let zeros = digits.iter().take_while(|d| **d == 0).count();
+        digits.drain(..zeros);
+        exponent += zeros as i64;
+        if digits.is_empty() {
+            return Self::zero();
+        }
+        Self { negative, digits, exponent }
+    }
+
+    /// Returns the coefficient scaled to `exponent`, which must not exceed the value's own exponent.
+    fn coefficient_at(&self, exponent: i64) -> Vec<u8> {
+        let mut digits = vec![0; (self.exponent - exponent) as usize];
+        digits.extend_from_slice(&self.digits);
+        digits
+    }
+
+    /// Rounds to a multiple of `10^exponent` (half away from zero).
+    fn round_at(&self, exponent: i64) -> Self {
+        if self.exponent >= exponent {
+            return self.clone();
+        }
+        match usize::try_from(self.magnitude() - exponent + 1) {
+            Ok(precision) => self.round_to(precision),
+            Err(_) => Self::zero(),
+        }
+    }
+
+    /// Rounds to `precision` significant digits (half away from zero).
+    pub fn round_to(&self, precision: usize) -> Self {
+        if self.digits.len() <= precision {
+            return self.clone();
+        }
+        let drop = self.digits.len() - precision;
+        let round_up = self.digits[drop - 1] >= 5;
+        let mut digits = self.digits[drop..].to_vec();
+        if round_up {
+            digits = add_magnitudes(&digits, &[1]);
+        }
+        Self::from_parts(self.negative, digits, self.exponent + drop as i64)
+    }
+
+    /// Divides, rounding the quotient to `precision` significant digits.
+    ///
+    /// # Returns
+    ///
+    /// `None` if `other` is zero
+    pub fn div_rounded(&self, other: &Self, precision: usize) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        // Scale the dividend so the integer quotient has at least one digit to spare
+        let shift = (precision + 1 + other.digits.len()).saturating_sub(self.digits.len());
+        let dividend = self.coefficient_at(self.exponent - shift as i64);
+        let (quotient, _) = divide_magnitudes(&dividend, &other.digits);
+        let exponent = self.exponent - shift as i64 - other.exponent;
+        Some(Self::from_parts(self.negative != other.negative, quotient, exponent).round_to(precision))
+    }
+
+    /// Returns the remainder of truncated division, with the sign of `self` (like `f64`'s `%`).
+    ///
+    /// # Returns
+    ///
+    /// `None` if `other` is zero
+    pub fn remainder(&self, other: &Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        if self.is_zero() || compare_values(self, other) == Ordering::Less {
+            return Some(self.clone());
+        }
+        // The quotient would have more digits than an exact result is allowed
+        if self.magnitude() - other.exponent > MAX_EXACT_DIGITS as i64 {
+            return None;
+        }
+        let exponent = self.exponent.min(other.exponent);
+        let (_, remainder) = divide_magnitudes(&self.coefficient_at(exponent), &other.coefficient_at(exponent));
+        Some(Self::from_parts(self.negative, remainder, exponent))
+    }
+
+    /// Raises to an integer power. Negative powers are rounded to `precision` significant digits.
+    ///
+    /// # Returns
+    ///
+    /// `None` for a negative power of zero
+    pub fn powi(&self, n: i64, precision: usize) -> Option<Self> {
+        let exact = self.digits.len().saturating_mul(n.unsigned_abs() as usize) <= MAX_EXACT_DIGITS;
+        let round = |x: Self| if exact { x } else { x.round_to(precision + 10) };
+
+        let (mut base, mut k, mut result) = (self.clone(), n.unsigned_abs(), Self::parse("1")?);
+        while k > 0 {
+            if k % 2 == 1 {
+                result = round(&result * &base);
+            }
+            base = round(&base * &base);
+            k /= 2;
+        }
+        if n < 0 {
+            return Self::parse("1")?.div_rounded(&result, precision);
+        }
+        Some(if exact { result } else { result.round_to(precision) })
+    }
+
+    /// Takes the `n`-th root of a non-negative value, rounded to `precision` significant digits.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the value is negative or `n` is zero
+    pub fn nth_root(&self, n: u32, precision: usize) -> Option<Self> {
+        if self.negative || n == 0 {
+            return None;
+        }
+        if self.is_zero() || n == 1 {
+            return Some(self.clone());
+        }
+
+        // Split off a power of ten divisible by n so the rest fits comfortably in an f64
+        let shift = self.magnitude().div_euclid(n as i64) * n as i64;
+        let scaled = Self { exponent: self.exponent - shift, ..self.clone() };
+        let working = precision + 10;
+        let n_decimal = Self::parse(&n.to_string())?;
+        let n_minus_one = Self::parse(&(n - 1).to_string())?;
+
+        // Newton's method from the f64 estimate: y ← ((n-1)·y + x / y^(n-1)) / n
+        let mut y = Self::from_f64(scaled.to_f64().powf(1.0 / n as f64))?;
+        for _ in 0..100 {
+            let power = y.powi(n as i64 - 1, working)?;
+            let next = (&(&n_minus_one * &y) + &scaled.div_rounded(&power, working)?)
+                .div_rounded(&n_decimal, working)?;
+            let converged = (&next - &y).is_zero()
+                || (&next - &y).magnitude() < next.magnitude() - working as i64;
+            y = next;
+            if converged {
+                break;
+            }
+        }
+        let root = y.round_to(precision);
+        Some(Self { exponent: root.exponent + shift / n as i64, ..root })
+    }
+}
+
+impl Ord for BigDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_values(self, other),
+            (true, true) => compare_values(other, self),
+        }
+    }
+}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the magnitudes of two values.
+fn compare_values(a: &BigDecimal, b: &BigDecimal) -> Ordering {
+    if a.is_zero() || b.is_zero() {
+        return a.digits.len().min(1).cmp(&b.digits.len().min(1));
+    }
+    if a.magnitude() != b.magnitude() {
+        return a.magnitude().cmp(&b.magnitude());
+    }
+    let exponent = a.exponent.min(b.exponent);
+    compare_magnitudes(&a.coefficient_at(exponent), &b.coefficient_at(exponent))
+}
+
+impl fmt::Display for BigDecimal {
+    /// Prints in positional notation for moderate magnitudes and scientific notation otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        let digits: String = self.digits.iter().rev().map(|d| char::from(b'0' + d)).collect();
+        let magnitude = self.magnitude();
+        if !(-7..21).contains(&magnitude) {
+            let (first, rest) = digits.split_at(1);
+            let point = if rest.is_empty() { "" } else { "." };
+            return write!(f, "{}{}{}e{}", first, point, rest, magnitude);
+        }
+        if self.exponent >= 0 {
+            write!(f, "{}{}", digits, "0".repeat(self.exponent as usize))
+        } else if magnitude >= 0 {
+            let (integer, fraction) = digits.split_at(magnitude as usize + 1);
+            write!(f, "{}.{}", integer, fraction)
+        } else {
+            write!(f, "0.{}{}", "0".repeat((-magnitude - 1) as usize), digits)
+        }
+    }
+}
+
+impl Add for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn add(self, other: &BigDecimal) -> BigDecimal {
+        if self.is_zero() || other.is_zero() {
+            return if self.is_zero() { other.clone() } else { self.clone() };
+        }
+        // An exact sum of operands this far apart (1e100000000 + 1) would need more digits
+        // than is allowed, so round both well below the working precision of the larger,
+        // which drops an operand too small to matter
+        let magnitude = self.magnitude().max(other.magnitude());
+        if magnitude - self.exponent.min(other.exponent) > MAX_EXACT_DIGITS as i64 {
+            let floor = magnitude - (PRECISION + 10) as i64;
+            return (&self.round_at(floor) + &other.round_at(floor)).round_to(PRECISION);
+        }
+
+        let exponent = self.exponent.min(other.exponent);
+        let (a, b) = (self.coefficient_at(exponent), other.coefficient_at(exponent));
+        if self.negative == other.negative {
+            return BigDecimal::from_parts(self.negative, add_magnitudes(&a, &b), exponent);
+        }
+        match compare_magnitudes(&a, &b) {
+            Ordering::Less => BigDecimal::from_parts(other.negative, subtract_magnitudes(&b, &a), exponent),
+            _ => BigDecimal::from_parts(self.negative, subtract_magnitudes(&a, &b), exponent),
+        }
+    }
+}
+
+impl Sub for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn sub(self, other: &BigDecimal) -> BigDecimal {
+        self + &-other
+    }
+}
+
+impl Mul for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, other: &BigDecimal) -> BigDecimal {
+        if self.is_zero() || other.is_zero() {
+            return BigDecimal::zero();
+        }
+        let mut product = vec![0u32; self.digits.len() + other.digits.len()];
+        for (i, a) in self.digits.iter().enumerate() {
+            for (j, b) in other.digits.iter().enumerate() {
+                product[i + j] += (*a as u32) * (*b as u32);
+            }
+            // Carry as we go so no column overflows
+            for k in 0..product.len() - 1 {
+                product[k + 1] += product[k] / 10;
+                product[k] %= 10;
+            }
+        }
+        let digits = product.into_iter().map(|d| d as u8).collect();
+        BigDecimal::from_parts(self.negative != other.negative, digits, self.exponent + other.exponent)
+    }
+}
+
+impl Neg for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn neg(self) -> BigDecimal {
+        BigDecimal { negative: !self.negative && !self.is_zero(), ..self.clone() }
+    }
+}
+
+/// Compares two digit vectors (least significant first) as non-negative integers.
+fn compare_magnitudes(a: &[u8], b: &[u8]) -> Ordering {
+    let significant = |x: &[u8]| x.len() - x.iter().rev().take_while(|d| **d == 0).count();
+    let (la, lb) = (significant(a), significant(b));
+    la.cmp(&lb).then_with(|| a[..la].iter().rev().cmp(b[..lb].iter().rev()))
+}
+
+fn add_magnitudes(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let digit = a.get(i).unwrap_or(&0) + b.get(i).unwrap_or(&0) + carry;
+        sum.push(digit % 10);
+        carry = digit / 10;
+    }
+    if carry > 0 {
+        sum.push(carry);
+    }
+    sum
+}
+
+/// Subtracts `b` from `a`, which must not be smaller.
+fn subtract_magnitudes(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, a) in a.iter().enumerate() {
+        let subtrahend = b.get(i).unwrap_or(&0) + borrow;
+        if *a >= subtrahend {
+            difference.push(a - subtrahend);
+            borrow = 0;
+        } else {
+            difference.push(a + 10 - subtrahend);
+            borrow = 1;
+        }
+    }
+    difference
+}
+
+/// Long division of non-negative integers, returning `(quotient, remainder)`.
+fn divide_magnitudes(dividend: &[u8], divisor: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let mut quotient = vec![0; dividend.len()];
+    let mut remainder: Vec<u8> = Vec::new();
+    for i in (0..dividend.len()).rev() {
+        // remainder = remainder · 10 + next digit
+        remainder.insert(0, dividend[i]);
+        let mut count = 0;
+        while compare_magnitudes(&remainder, divisor) != Ordering::Less {
+            remainder = subtract_magnitudes(&remainder, divisor);
+            count += 1;
+        }
+        while remainder.last() == Some(&0) {
+            remainder.pop();
+        }
+        quotient[i] = count;
+    }
+    (quotient, remainder)
+}
+
+/// Number with an arbitrary-precision decimal value and an `f64` uncertainty.
+///
+/// See the module documentation for which operations are exact.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decimal {
+    /// Exact value, or `None` if the value is not finite
+    exact: Option<BigDecimal>,
+
+    /// Value as the nearest `f64`
+    approx: f64,
+
+    error: f64,
+}
+
+impl Decimal {
+    /// Creates a number from an exact decimal value.
+    pub fn from_decimal(value: BigDecimal, error: f64) -> Self {
+        Self { approx: value.to_f64(), exact: Some(value), error: error.abs() }
+    }
+
+    /// Returns the exact decimal value, or `None` if the value is not finite.
+    pub fn decimal(&self) -> Option<&BigDecimal> {
+        self.exact.as_ref()
+    }
+
+    /// Returns the `Real` with the same (approximate) value and uncertainty, whose rules
+    /// give the propagated uncertainty of each operation.
+    fn real(&self) -> Real {
+        Real::with_error(self.approx, self.error)
+    }
+
+    /// Builds a result from its exact value (if one could be computed) and the matching
+    /// `Real` result, which supplies the uncertainty and the fallback value.
+    fn result(exact: Option<BigDecimal>, real: Real) -> Self {
+        match exact {
+            Some(value) => Self::from_decimal(value, real.error()),
+            None => Self::with_error(real.value(), real.error()),
+        }
+    }
+
+    /// Applies an exact operation if both values are exact.
+    fn both(&self, other: &Self, op: impl Fn(&BigDecimal, &BigDecimal) -> Option<BigDecimal>) -> Option<BigDecimal> {
+        op(self.exact.as_ref()?, other.exact.as_ref()?)
+    }
+}
+
+impl Number for Decimal {
+    fn value(&self) -> f64 {
+        self.approx
+    }
+
+    fn error(&self) -> f64 {
+        self.error
+    }
+
+    fn with_error(value: f64, error: f64) -> Self {
+        Self { exact: BigDecimal::from_f64(value), approx: value, error: error.abs() }
+    }
+
+    fn from_literal(literal: Option<&str>, value: f64, upper: f64, lower: f64) -> Self {
+        match literal.and_then(BigDecimal::parse) {
+            Some(exact) => Self::from_decimal(exact, upper.abs().max(lower.abs())),
+            None => Self::with_asymmetric_error(value, upper, lower),
+        }
+    }
+
+    fn power(&self, other: Self) -> Self {
+        let real = self.real().power(other.real());
+        let exact = self.both(&other, |x, n| x.powi(n.to_i64()?, PRECISION));
+        Self::result(exact, real)
+    }
+
+    fn root(&self, other: Self) -> Self {
+        let real = self.real().root(other.real());
+        let exact = self.both(&other, |x, n| {
+            let n = u32::try_from(n.to_i64()?).ok()?;
+            x.nth_root(n, PRECISION)
+        });
+        Self::result(exact, real)
+    }
+
+    fn plus_minus(&self, other: Self) -> Self {
+        let real = self.real().plus_minus(other.real());
+        Self::result(self.exact.clone(), real)
+    }
+
+    fn std_function(function: &StdFunction, args: &[Self], order: PropagationOrder) -> Self {
+        let reals: Vec<Real> = args.iter().map(Decimal::real).collect();
+        let real = Real::std_function(function, &reals, order);
+        let exact = match (function.name, args[0].exact.as_ref()) {
+            ("sqrt", Some(x)) => x.nth_root(2, PRECISION),
+            ("abs", Some(x)) => Some(x.abs()),
+            _ => BigDecimal::from_f64(real.value()),
+        };
+        Self::result(exact, real)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.exact {
+            Some(value) => write!(f, "{}", value)?,
+            None => write!(f, "{}", self.approx)?,
+        }
+        if self.error != 0.0 {
+            write!(f, " +/- {}", self.error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Add for Decimal {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::result(self.both(&other, |a, b| Some(a + b)), self.real() + other.real())
+    }
+}
+
+impl Sub for Decimal {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::result(self.both(&other, |a, b| Some(a - b)), self.real() - other.real())
+    }
+}
+
+impl Mul for Decimal {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::result(self.both(&other, |a, b| Some(a * b)), self.real() * other.real())
+    }
+}
+
+impl Div for Decimal {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::result(self.both(&other, |a, b| a.div_rounded(b, PRECISION)), self.real() / other.real())
+    }
+}
+
+impl Rem for Decimal {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        Self::result(self.both(&other, BigDecimal::remainder), self.real() % other.real())
+    }
+}
+
+impl Neg for Decimal {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { exact: self.exact.as_ref().map(|x| -x), approx: -self.approx, error: self.error }
+    }
+}
@@ -0,0 +1,117 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! A single error type for every stage of the interpreter.
+//!
+//! Tokenizer errors are produced as `Diagnostic`s directly. Parse, semantic and runtime
+//! errors keep their own types (so callers can match on them) and convert with
+//! `to_diagnostic`. Lints produce warnings, which use the same type with a
+//! `Severity::Warning`. A diagnostic renders against a `SourceMap`, quoting the offending
+//! source line with the span underlined:
+//!
+//! ```text
+//! error: Unexpected character '@'
+//!  --> lib/calibration.sig:2:6
+//!   |
+//! 2 | y := @
+//!   |      ^
+//! ```
+
+use std::fmt;
+
+use crate::significance::source_map::{SourceMap, Span};
+
+/// Pipeline stage that produced a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stage {
+    Tokenizer,
+    Parser,
+    Semantic,
+    Lint,
+    Runtime,
+}
+
+/// Whether a diagnostic stops the program from running.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// An error or warning located in the source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// Stage that found the error
+    pub stage: Stage,
+
+    /// Error, or a warning that does not stop the program
+    pub severity: Severity,
+
+    /// Description of the error, without its location
+    pub message: String,
+
+    /// Offending source text
+    pub span: Span,
+}
+
+impl Diagnostic {
+    /// Creates an error diagnostic.
+    pub fn new(stage: Stage, message: impl Into<String>, span: Span) -> Self {
+        Self { stage, severity: Severity::Error, message: message.into(), span }
+    }
+
+    /// Creates a warning diagnostic.
+    pub fn warning(stage: Stage, message: impl Into<String>, span: Span) -> Self {
+        Self { severity: Severity::Warning, ..Self::new(stage, message, span) }
+    }
+
+    /// Returns whether this is an error (rather than a warning).
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// Renders the diagnostic with its file, line and column, and the source line with
+    /// the span underlined.
+    ///
+    /// If the source text is not in `sources`, only the message and location are shown.
+    pub fn render(&self, sources: &SourceMap) -> String {
+        let start = self.span.start;
+        let name = sources.name(self.span.file).unwrap_or("<unknown>");
+        let label = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        let mut rendered = format!("{}: {}\n --> {}:{}:{}", label, self.message, name, start.line, start.column);
+
+        if let Some(line) = sources.line(self.span.file, start.line) {
+            let gutter = " ".repeat(start.line.to_string().len());
+            let line_length = line.chars().count();
+            let underline_end = match self.span.end.line == start.line {
+                true => self.span.end.column.min(line_length + 1),
+                false => line_length + 1,
+            };
+            let carets = underline_end.saturating_sub(start.column).max(1);
+            rendered.push_str(&format!(
+                "\n{} |\n{} | {}\n{} | {}{}",
+                gutter, start.line, line, gutter, " ".repeat(start.column.saturating_sub(1)), "^".repeat(carets)
+            ));
+        }
+        rendered
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.severity {
+            Severity::Error => "Error",
+            Severity::Warning => "Warning",
+        };
+        write!(f, "{} at {}:{}: {}", label, self.span.start.line, self.span.start.column, self.message)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
@@ -23,12 +23,35 @@
 //! Runtime errors are collected rather than immediately halting execution, allowing the
 //! executor to continue and potentially find multiple errors. This is useful for interactive
 //! REPL sessions where recovering from errors is important.
+//!
+//! # Tracing
+//!
+//! In trace mode (`set_trace`) the executor records every sub-expression it evaluates as
+//! a `TraceEntry`: its position, the operation, the values of its operands and the
+//! result, so it can be seen step by step how a result got its uncertainty. Entries are
+//! recorded in evaluation order (operands before the operation that uses them), and
+//! indented by depth. For `y := x * 2`:
+//!
+//! ```text
+//!   5:6    x = 12.3 +/- 0.5
+//!  5:10    2 = 2
+//!   5:6  (12.3 +/- 0.5) * (2) = 24.6 +/- 1
+//! ```
 
 use std::collections::HashMap;
-use crate::Real;
-use crate::significance::ast_parser::{Program, Statement, VarType, Expression, BinaryOp, UnaryOp};
+use std::fmt;
+use std::rc::Rc;
+use crate::{Number, Real};
+use crate::significance::ast_parser::{is_true, Program, Statement, VarType, Expression, BinaryOp, UnaryOp, UncertaintyNotation};
 use crate::significance::tokenizer::Position;
-use crate::significance::std_lib_call;
+use crate::significance::source_map::{FileId, Span};
+use crate::significance::diagnostic::{Diagnostic, Stage};
+use crate::significance::constants::CONSTANTS;
+use crate::significance::propagation::PropagationOrder;
+use crate::significance::budget::{error_budget, ErrorBudget};
+use crate::significance::standard_lib::{assertion_call, is_assertion, resolve_builtin, Builtin, STD_LIB};
+use crate::significance::symbolic::{formula, Formula};
+use crate::significance::optimizer::fold_expression;
 
 /// Runtime errors that can occur during program execution.
 ///
@@ -59,37 +82,143 @@ pub enum RunTimeError {
     /// ```ignore
     /// y := x  // Runtime error if x wasn't declared (should be caught earlier)
     /// ```
-    UndefinedVariable(String, Position)
+    UndefinedVariable(String, Position),
+
+    /// An `assert`, `assert_close` or `assert_consistent` call that does not hold.
+    ///
+    /// Contains the reason and the span of the call. Execution continues, so every
+    /// failing assertion is reported.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// assert_close(gain, 1.5, 0.01)  // Runtime error if gain is 1.52
+    /// ```
+    AssertionFailed(String, Span),
+}
+impl RunTimeError {
+    /// Converts the error into a `Diagnostic` located in `file`.
+    pub fn to_diagnostic(&self, file: FileId) -> Diagnostic {
+        let (message, position, length) = match self {
+            RunTimeError::DivisionByZero(position) => ("Division by zero".to_string(), position, 1),
+            RunTimeError::UndefinedVariable(name, position) => (format!("Undefined variable '{}'", name), position, name.chars().count()),
+            RunTimeError::AssertionFailed(message, span) => {
+                return Diagnostic::new(Stage::Runtime, message.clone(), Span { file, ..*span });
+            },
+        };
+        Diagnostic::new(Stage::Runtime, message, Span::on_line(file, *position, length))
+    }
 }
+
 impl std::fmt::Display for RunTimeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             RunTimeError::DivisionByZero(position) => write!(f, "Division by zero error at {}:{}.", position.line, position.column),
             RunTimeError::UndefinedVariable(name, position) => write!(f, "Undefined variable '{}' at {}:{}.", name, position.line, position.column),
+            RunTimeError::AssertionFailed(message, span) => write!(f, "{} at {}:{}.", message, span.start.line, span.start.column),
         }
     }
 }
 
+/// What a traced sub-expression evaluated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceOperation {
+    /// A numeric literal
+    Literal,
+
+    /// A variable reference, by name
+    Variable(String),
+
+    /// A binary operation
+    Binary(BinaryOp),
+
+    /// A unary operation
+    Unary(UnaryOp),
+
+    /// A function call, by name (including `budget`, `nominal` and assertions)
+    Call(String),
+}
+
+/// One sub-expression evaluated in trace mode.
+#[derive(Debug, Clone)]
+pub struct TraceEntry<N = Real> {
+    /// Position the sub-expression is reported at (see `Expression::position`)
+    pub pos: Position,
+
+    /// Nesting depth within the statement's expression, 0 for the whole expression
+    pub depth: usize,
+
+    /// What was evaluated
+    pub operation: TraceOperation,
+
+    /// Values of the operands or arguments, in order. A `&&` or `||` whose right
+    /// operand was skipped has only the left one.
+    pub operands: Vec<N>,
+
+    /// The computed value
+    pub result: N,
+}
+
+impl<N: fmt::Display> fmt::Display for TraceEntry<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operand = |index: usize| format!("({})", self.operands[index]);
+        let description = match &self.operation {
+            TraceOperation::Literal => self.result.to_string(),
+            TraceOperation::Variable(name) => name.clone(),
+            TraceOperation::Binary(op) if self.operands.len() == 1 => format!("{} {} ...", operand(0), op.symbol()),
+            TraceOperation::Binary(op) => format!("{} {} {}", operand(0), op.symbol(), operand(1)),
+            TraceOperation::Unary(op) => format!("{}{}", op.symbol(), operand(0)),
+            TraceOperation::Call(name) => {
+                let args: Vec<String> = self.operands.iter().map(ToString::to_string).collect();
+                format!("{}({})", name, args.join(", "))
+            },
+        };
+        let position = format!("{}:{}", self.pos.line, self.pos.column);
+        write!(f, "{:>5}  {}{} = {}", position, "  ".repeat(self.depth), description, self.result)
+    }
+}
+
 /// Runtime representation of a variable.
 ///
 /// Stores the current value of a variable during execution. The type information
 /// is not stored at runtime since all variables in the current implementation are
-/// of type `Real` (real numbers with uncertainty).
+/// real numbers, held in the executor's numeric backend (`Real` by default).
 #[derive(Debug, Clone)]
-pub struct VarRunTime{
-    /// Current value of the variable (real number with uncertainty)
-    value: Real
+pub struct VarRunTime<N = Real> {
+    /// Current value of the variable (number with uncertainty)
+    value: N,
+
+    /// Expression the variable was assigned from, if it has been assigned.
+    ///
+    /// Kept so that error budgets can trace a result back to its source measurements.
+    /// Shared, so that running compiled bytecode again does not copy the expression.
+    definition: Option<Rc<Expression>>,
 }
 
-impl VarRunTime {
+impl<N> VarRunTime<N> {
     /// Returns the current value of this variable.
     ///
     /// # Returns
     ///
-    /// Reference to the `Real` value stored in this variable
-    pub fn get_value(&self) -> &Real {
+    /// Reference to the value stored in this variable
+    pub fn get_value(&self) -> &N {
         &self.value
     }
+
+    /// Returns the expression this variable was assigned from.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Expression)` - The assigned expression
+    /// * `None` - The variable has only been declared
+    pub fn get_definition(&self) -> Option<&Expression> {
+        self.definition.as_deref()
+    }
+
+    /// Creates a variable holding `value`, assigned from `definition` (if any).
+    pub(crate) fn new(value: N, definition: Option<Rc<Expression>>) -> Self {
+        Self { value, definition }
+    }
 }
 
 /// Runtime execution engine for the Significance language.
@@ -98,9 +227,15 @@ impl VarRunTime {
 /// and performing computations with automatic uncertainty propagation. It operates on
 /// the assumption that semantic analysis has already validated the program structure.
 ///
+/// # Numeric Backends
+///
+/// The executor is generic over the `Number` it computes with. `Executor::new()` uses
+/// `Real` (statistical uncertainty); `Executor::<Interval>::with_backend()` runs the same
+/// programs with guaranteed interval bounds.
+///
 /// # Execution Strategy
 ///
-/// - **Variables**: Stored in a HashMap mapping names to `Real` values
+/// - **Variables**: Stored in a HashMap mapping names to backend values
 /// - **Expressions**: Evaluated recursively with uncertainty propagated automatically
 /// - **Statements**: Executed sequentially, modifying runtime state
 /// - **Errors**: Collected non-fatally, allowing execution to continue
@@ -131,39 +266,210 @@ impl VarRunTime {
 ///     println!("Result: {}", var.get_value());
 /// }
 /// ```
-pub struct Executor {
+pub struct Executor<N = Real> {
     /// Runtime variable storage mapping names to values.
     ///
     /// Variables are added via declarations and updated via assignments.
-    /// All variables store `Real` values (numbers with uncertainty).
-    run_time_vars: HashMap<String, VarRunTime>,
+    /// All variables store backend values (numbers with uncertainty).
+    run_time_vars: HashMap<String, VarRunTime<N>>,
     
     /// Accumulated runtime errors encountered during execution.
     ///
     /// Errors are logged but don't halt execution, allowing the executor
     /// to continue and potentially discover multiple issues.
-    errors: Vec<RunTimeError>
+    errors: Vec<RunTimeError>,
+
+    /// Taylor order used to propagate uncertainty through operators and functions.
+    propagation_order: PropagationOrder,
+
+    /// Whether printing is suppressed (expression results and `budget` reports).
+    quiet: bool,
+
+    /// Lines that would have been printed, kept instead while output is captured.
+    output: Option<Vec<String>>,
+
+    /// Sub-expressions evaluated so far, kept while trace mode is on.
+    trace: Option<Vec<TraceEntry<N>>>,
+
+    /// Nesting depth of the sub-expression being evaluated, for the trace.
+    depth: usize,
+
+    /// Whether exact literal subexpressions are folded before each statement runs.
+    constant_folding: bool,
 }
 
-impl Executor{
+impl Default for Executor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Executor {
     /// Creates a new executor with no variables or errors.
     ///
     /// The executor starts with a clean slate and is ready to execute programs.
-    /// Variables must be declared via the program being executed.
+    /// Apart from the built-in constants, variables must be declared via the program
+    /// being executed.
     ///
     /// # Returns
     ///
     /// A new `Executor` instance ready for program execution
     pub fn new() -> Self {
+        Self::with_backend()
+    }
+}
+
+impl<N: Number> Executor<N> {
+    /// Creates a new executor that computes with the numeric backend `N`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Executor` instance ready for program execution
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut executor = Executor::<Interval>::with_backend();
+    /// ```
+    pub fn with_backend() -> Self {
         Self {
-            run_time_vars: HashMap::new(),
-            errors: Vec::new()
+            run_time_vars: Self::constant_vars(),
+            errors: Vec::new(),
+            propagation_order: PropagationOrder::First,
+            quiet: false,
+            output: None,
+            trace: None,
+            depth: 0,
+            constant_folding: false,
+        }
+    }
+
+    /// Selects the order of uncertainty propagation for all subsequent evaluations.
+    ///
+    /// `PropagationOrder::First` (the default) uses the closed-form rules in `Real`.
+    /// `PropagationOrder::Second` routes every operator and function through the
+    /// generic propagation engine with second-order Taylor terms. Backends that do not
+    /// support the propagation engine (such as `Interval`) ignore the order.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Propagation order to use
+    pub fn set_propagation_order(&mut self, order: PropagationOrder) {
+        self.propagation_order = order;
+    }
+
+    /// Turns folding of exact literal subexpressions on or off (off by default).
+    ///
+    /// Each statement is folded (see `optimizer.rs`) just before it runs, but an
+    /// assignment keeps its definition as written, so error budgets and `formula` see
+    /// the same expressions whether folding is on or not. Folding computes with `Real`,
+    /// so it stays off for backends that do not allow it (`Number::FOLDS_CONSTANTS`).
+    pub fn set_constant_folding(&mut self, enabled: bool) {
+        self.constant_folding = enabled && N::FOLDS_CONSTANTS;
+    }
+
+    /// Returns whether exact literal subexpressions are folded before each statement runs.
+    pub fn is_constant_folding(&self) -> bool {
+        self.constant_folding
+    }
+
+    /// Suppresses (or restores) printing of expression results and `budget` reports.
+    ///
+    /// Used by tools that evaluate a program for its values and own stdout themselves,
+    /// such as the language server.
+    pub fn set_quiet(&mut self, quiet: bool) {
+        self.quiet = quiet;
+    }
+
+    /// Returns whether printing is suppressed.
+    pub fn is_quiet(&self) -> bool {
+        self.quiet
+    }
+
+    /// Starts (or stops) keeping the lines the program prints instead of printing them.
+    ///
+    /// Used by the golden test runner to compare a program's output with its expectations.
+    /// The kept lines are returned by `take_output`.
+    pub fn set_capture(&mut self, capture: bool) {
+        self.output = capture.then(Vec::new);
+    }
+
+    /// Returns the lines kept since output capture started (or was last taken).
+    pub fn take_output(&mut self) -> Vec<String> {
+        self.output.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    /// Turns trace mode on or off.
+    ///
+    /// While it is on, every evaluated sub-expression is recorded (see the module
+    /// documentation); the entries are returned by `take_trace`.
+    pub fn set_trace(&mut self, trace: bool) {
+        self.trace = trace.then(Vec::new);
+    }
+
+    /// Returns the entries recorded since trace mode was turned on (or last taken).
+    pub fn take_trace(&mut self) -> Vec<TraceEntry<N>> {
+        self.trace.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    /// Returns the operands to record for a trace entry, cloned only in trace mode.
+    fn traced(&self, operands: &[&N]) -> Vec<N> {
+        match self.trace {
+            Some(_) => operands.iter().map(|operand| (*operand).clone()).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Records an evaluated sub-expression, in trace mode.
+    ///
+    /// Called once the operands have been evaluated, at the depth of the sub-expression
+    /// (`evaluate_expression` is still inside it).
+    fn record(&mut self, pos: &Position, operation: TraceOperation, operands: Vec<N>, result: &N) {
+        let depth = self.depth.saturating_sub(1);
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceEntry { pos: *pos, depth, operation, operands, result: result.clone() });
+        }
+    }
+
+    /// Prints a result, or keeps its lines if output is captured.
+    fn print(&mut self, text: &str) {
+        if self.quiet {
+            return;
+        }
+        match &mut self.output {
+            Some(output) => output.extend(text.lines().map(str::to_string)),
+            None => println!("{}", text),
         }
     }
 
+    /// Returns the order of uncertainty propagation currently in use.
+    pub fn get_propagation_order(&self) -> PropagationOrder {
+        self.propagation_order
+    }
+
+    /// Evaluates a single expression with a specific propagation order.
+    ///
+    /// The executor's configured order is restored afterward, so this can be used to
+    /// compare first- and second-order results for the same expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression to evaluate
+    /// * `order` - Propagation order to use for this evaluation only
+    ///
+    /// # Returns
+    ///
+    /// The computed value with propagated uncertainty
+    pub fn evaluate_expression_with_order(&mut self, expression: &Expression, order: PropagationOrder) -> N {
+        let previous = std::mem::replace(&mut self.propagation_order, order);
+        let value = self.evaluate_expression(expression);
+        self.propagation_order = previous;
+        value
+    }
+
     /// Resets the executor to its initial state.
     ///
-    /// Clears all variables but preserves error history. This is useful for
+    /// Clears all variables (except the built-in constants) but preserves error history. This is useful for
     /// executing multiple independent programs with the same executor instance
     /// or for resetting REPL state.
     ///
@@ -172,7 +478,17 @@ impl Executor{
     /// This method only clears variables, not accumulated errors. To clear errors,
     /// create a new executor instance.
     pub fn reset(&mut self) {
-        self.run_time_vars.clear();
+        self.run_time_vars = Self::constant_vars();
+    }
+
+    /// Builds the runtime variables holding the built-in constants.
+    ///
+    /// Constants have no recorded definition, so error budgets report an uncertain
+    /// constant (such as `G`) as a source under its own name.
+    fn constant_vars() -> HashMap<String, VarRunTime<N>> {
+        CONSTANTS.iter()
+            .map(|constant| (constant.name.to_string(), VarRunTime { value: constant.to_number(), definition: None }))
+            .collect()
     }
 
     pub fn clear_errors(&mut self) {
@@ -192,10 +508,30 @@ impl Executor{
     ///
     /// * `Some(VarRunTime)` - Variable found with its current value
     /// * `None` - Variable does not exist
-    pub fn get_var(&self, name: &str) -> Option<VarRunTime> {
+    pub fn get_var(&self, name: &str) -> Option<VarRunTime<N>> {
         self.run_time_vars.get(name).cloned()
     }
 
+    /// Iterates over every runtime variable, including the built-in constants, in no
+    /// particular order. Used by the debugger to inspect the program's state.
+    pub fn variables(&self) -> impl Iterator<Item = (&str, &VarRunTime<N>)> {
+        self.run_time_vars.iter().map(|(name, var)| (name.as_str(), var))
+    }
+
+    /// Adds the variables of an imported source file.
+    ///
+    /// Definitions are dropped, since they refer to names inside the imported file, so
+    /// error budgets report each imported variable as a single source.
+    ///
+    /// # Arguments
+    ///
+    /// * `variables` - Imported names (already qualified by any namespace) and their values
+    pub fn import_variables(&mut self, variables: HashMap<String, VarRunTime<N>>) {
+        for (name, var) in variables {
+            self.run_time_vars.insert(name, VarRunTime { value: var.value, definition: None });
+        }
+    }
+
     /// Executes a complete program.
     ///
     /// Processes all statements in the program sequentially, maintaining runtime
@@ -235,15 +571,24 @@ impl Executor{
     /// - May accumulate runtime errors
     pub fn execute_statement(&mut self, statement: &Statement) {
         match statement {
-            Statement::VarDeclaration { name, var_type, pos } => {
+            Statement::VarDeclaration { name, var_type, pos, .. } => {
                 self.declare_variable(name, var_type, pos);
             }
-            Statement::Assignment { name, value, pos } => {
+            Statement::Assignment { name, value, pos, .. } => {
                 self.assign_variable(name, value, pos);
             }
             Statement::Expression(expression) => {
-                let value = self.evaluate_expression(expression);
-                print!("{}\n", value);
+                let value = match self.constant_folding {
+                    true => self.evaluate_expression(&fold_expression(expression)),
+                    false => self.evaluate_expression(expression),
+                };
+                // An assertion is a check, not a result
+                if !matches!(expression, Expression::FunctionCall { name, .. } if is_assertion(name)) {
+                    self.print(&value.to_string());
+                }
+            }
+            Statement::Import { .. } => {
+                // Imported files are run by the interpreter, which calls `import_variables`
             }
         }
     }
@@ -268,7 +613,8 @@ impl Executor{
         self.run_time_vars.insert(
             name.to_string(),
             VarRunTime {
-                value: Real::new(0.0)
+                value: N::new(0.0),
+                definition: None,
             }
         );
     }
@@ -290,19 +636,24 @@ impl Executor{
     /// Records `UndefinedVariable` if the variable doesn't exist (should be prevented
     /// by semantic analysis).
     fn assign_variable(&mut self, name: &str, value: &Expression, pos: &Position) {
-        let value = self.evaluate_expression(value);
+        let definition = Rc::new(value.clone());
+        let value = match self.constant_folding {
+            true => self.evaluate_expression(&fold_expression(value)),
+            false => self.evaluate_expression(value),
+        };
         
         if let Some(var) = self.run_time_vars.get_mut(name) {
             var.value = value;
+            var.definition = Some(definition);
         } else {
-            self.errors.push(RunTimeError::UndefinedVariable(name.to_string(), pos.clone()));
+            self.errors.push(RunTimeError::UndefinedVariable(name.to_string(), *pos));
         }
     }
 
     /// Evaluates an expression and returns its computed value.
     ///
     /// Recursively evaluates the expression tree, automatically propagating uncertainty
-    /// through all operations. The result is a `Real` number that includes both the
+    /// through all operations. The result is a backend number that includes both the
     /// computed value and its uncertainty.
     ///
     /// # Arguments
@@ -311,29 +662,36 @@ impl Executor{
     ///
     /// # Returns
     ///
-    /// The computed `Real` value with propagated uncertainty
+    /// The computed value with propagated uncertainty
     ///
     /// # Expression Types
     ///
-    /// - **Numbers**: Direct conversion to `Real`
+    /// - **Numbers**: Direct conversion to the backend number
     /// - **Variables**: Lookup in runtime environment
     /// - **Binary operations**: Evaluate operands, apply operator with uncertainty propagation
     /// - **Unary operations**: Evaluate operand, apply operator
     /// - **Function calls**: Evaluate arguments, call standard library function
-    pub fn evaluate_expression(&mut self, expression: &Expression) -> Real {
-        match expression {
-            Expression::NumberWithUncertainty { value, error, pos:_ } => Real::with_error(value.clone(), error.clone()),
-            Expression::Variable(name) => self.evaluate_variable(name),
-            Expression::Binary { left, op, right, pos } => {
+    pub fn evaluate_expression(&mut self, expression: &Expression) -> N {
+        self.depth += 1;
+        let value = match expression {
+            Expression::NumberWithUncertainty { value, error, notation, literal, pos, .. } => {
+                let value = literal_value(*value, *error, notation, literal.as_deref());
+                self.record(pos, TraceOperation::Literal, Vec::new(), &value);
+                value
+            },
+            Expression::Variable { name, pos, .. } => self.evaluate_variable(name, pos),
+            Expression::Binary { left, op, right, pos, .. } => {
                 self.evaluate_expression_binary(left, op, right, pos)
             },
-            Expression::Unary { op, operand, pos } => {
+            Expression::Unary { op, operand, pos, .. } => {
                 self.evaluate_expression_unary(op, operand, pos)
             },
-            Expression::FunctionCall { name, args, pos } => {
-                self.evaluate_function_call(name, args, pos)
+            Expression::FunctionCall { name, args, pos, span } => {
+                self.evaluate_function_call(name, args, pos, span)
             }
-        }
+        };
+        self.depth -= 1;
+        value
     }
 
     /// Evaluates a variable reference by looking up its current value.
@@ -344,6 +702,7 @@ impl Executor{
     /// # Arguments
     ///
     /// * `name` - Variable name to look up
+    /// * `pos` - Source position of the reference (for error reporting)
     ///
     /// # Returns
     ///
@@ -353,22 +712,21 @@ impl Executor{
     ///
     /// Records `UndefinedVariable` if the variable doesn't exist (should be prevented
     /// by semantic analysis).
-    fn evaluate_variable(&mut self, name: &str) -> Real {
-        if let Some(var) = self.run_time_vars.get(name) {
+    fn evaluate_variable(&mut self, name: &str, pos: &Position) -> N {
+        let value = if let Some(var) = self.run_time_vars.get(name) {
             var.value.clone()
         } else {
-            self.errors.push(RunTimeError::UndefinedVariable(
-                name.to_string(),
-                Position { line: 0, column: 0 } // Position not available here
-            ));
-            Real::new(f64::NAN) // Return default value after logging error
-        }
+            self.errors.push(RunTimeError::UndefinedVariable(name.to_string(), *pos));
+            N::new(f64::NAN) // Return default value after logging error
+        };
+        self.record(pos, TraceOperation::Variable(name.to_string()), Vec::new(), &value);
+        value
     }
 
     /// Evaluates a binary operation with uncertainty propagation.
     ///
     /// Evaluates both operands and applies the specified operator. Uncertainty is
-    /// automatically propagated according to the rules implemented in the `Real` type.
+    /// automatically propagated according to the rules implemented by the backend.
     ///
     /// # Arguments
     ///
@@ -387,30 +745,94 @@ impl Executor{
     /// - **Mul/Div**: Relative uncertainties combined in quadrature
     /// - **Mod**: Conservative uncertainty estimation
     /// - **Power/Root**: Uncertainty propagated via derivatives
+    /// - **PlusMinus**: Right value added to the left uncertainty in quadrature
+    /// - **Comparisons, And/Or**: Nominal values compared, giving an exact 1 or 0. The
+    ///   right operand of `&&` and `||` is skipped when the left one decides the result.
     ///
     /// # Errors
     ///
     /// Records `DivisionByZero` if dividing by zero (result is infinity with infinite error).
-    fn evaluate_expression_binary(&mut self, left: &Expression, op: &BinaryOp, right: &Expression, pos: &Position) -> Real {
+    fn evaluate_expression_binary(&mut self, left: &Expression, op: &BinaryOp, right: &Expression, pos: &Position) -> N {
         let left_value = self.evaluate_expression(left);
+        let short_circuit = match op {
+            BinaryOp::And if !is_true(left_value.value()) => Some(N::new(0.0)),
+            BinaryOp::Or if is_true(left_value.value()) => Some(N::new(1.0)),
+            _ => None,
+        };
+        if let Some(result) = short_circuit {
+            let operands = self.traced(&[&left_value]);
+            self.record(pos, TraceOperation::Binary(op.clone()), operands, &result);
+            return result;
+        }
         let right_value = self.evaluate_expression(right);
 
+        if op == &BinaryOp::Div && right_value.value() == 0.0 && right_value.error() == 0.0 {
+            self.errors.push(RunTimeError::DivisionByZero(*pos));
+        }
+
+        let operands = self.traced(&[&left_value, &right_value]);
+        let result = match self.propagation_order {
+            PropagationOrder::Second => Self::apply_binary_second_order(left_value, op, right_value),
+            PropagationOrder::First => Self::apply_binary(left_value, op, right_value),
+        };
+        self.record(pos, TraceOperation::Binary(op.clone()), operands, &result);
+        result
+    }
+
+    /// Applies a binary operator using the backend's own (first-order) rules.
+    pub(crate) fn apply_binary(left: N, op: &BinaryOp, right: N) -> N {
+        if let Some(result) = op.apply_boolean(left.value(), right.value()) {
+            return N::new(result);
+        }
         match op {
-            BinaryOp::Add => left_value + right_value,
-            BinaryOp::Sub => left_value - right_value,
-            BinaryOp::Mul => left_value * right_value,
-            BinaryOp::Div => {
-                if right_value == Real::new(0.0) {
-                    self.errors.push(RunTimeError::DivisionByZero(pos.clone()));
-                }
-                left_value / right_value
-            },
-            BinaryOp::Mod => left_value % right_value,
-            BinaryOp::Power => left_value.power(right_value),
-            BinaryOp::Root => left_value.root(right_value),
+            BinaryOp::Add => left + right,
+            BinaryOp::Sub => left - right,
+            BinaryOp::Mul => left * right,
+            BinaryOp::Div => left / right,
+            BinaryOp::Mod => left % right,
+            BinaryOp::Power => left.power(right),
+            BinaryOp::Root => left.root(right),
+            BinaryOp::PlusMinus => left.plus_minus(right),
+            _ => unreachable!("comparison and logical operators are applied above"),
         }
     }
 
+    /// Applies a binary operator using second-order propagation.
+    ///
+    /// Each operator is expressed as a plain `f64` function and handed to the generic
+    /// propagation engine. Modulus has no useful Taylor expansion and keeps its
+    /// conservative first-order rule. The root degree is treated as exact, matching
+    /// the first-order `Real::root`, and `+/-` is not a function of its operands so
+    /// it is applied as is. Backends without a propagation engine fall back to their
+    /// own operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - Left operand value
+    /// * `op` - Binary operator to apply
+    /// * `right` - Right operand value
+    ///
+    /// # Returns
+    ///
+    /// The result including the second-order mean shift and variance terms
+    pub(crate) fn apply_binary_second_order(left: N, op: &BinaryOp, right: N) -> N {
+        let order = PropagationOrder::Second;
+        let args = [left.clone(), right.clone()];
+        let propagated = match op {
+            BinaryOp::Add => N::propagate_with_order(|v| v[0] + v[1], &args, order),
+            BinaryOp::Sub => N::propagate_with_order(|v| v[0] - v[1], &args, order),
+            BinaryOp::Mul => N::propagate_with_order(|v| v[0] * v[1], &args, order),
+            BinaryOp::Div => N::propagate_with_order(|v| v[0] / v[1], &args, order),
+            BinaryOp::Power => N::propagate_with_order(|v| v[0].powf(v[1]), &args, order),
+            BinaryOp::Root => {
+                let degree = right.value();
+                N::propagate_with_order(|v| v[0].powf(1.0 / degree), &args[..1], order)
+            },
+            _ => None,
+        };
+        propagated.unwrap_or_else(|| Self::apply_binary(left, op, right))
+    }
+
     /// Evaluates a unary operation.
     ///
     /// Evaluates the operand and applies the specified unary operator.
@@ -420,7 +842,7 @@ impl Executor{
     ///
     /// * `op` - Unary operator to apply
     /// * `operand` - Operand expression
-    /// * `_pos` - Source position (unused currently)
+    /// * `pos` - Source position, recorded in trace mode
     ///
     /// # Returns
     ///
@@ -430,12 +852,17 @@ impl Executor{
     ///
     /// - **Plus**: Identity operation (returns operand unchanged)
     /// - **Minus**: Negates value (preserves uncertainty magnitude)
-    fn evaluate_expression_unary(&mut self, op: &UnaryOp, operand: &Expression, _pos: &Position) -> Real {
+    /// - **Not**: An exact 1 if the nominal value is zero, otherwise 0
+    fn evaluate_expression_unary(&mut self, op: &UnaryOp, operand: &Expression, pos: &Position) -> N {
         let operand_value = self.evaluate_expression(operand);
-        match op {
+        let operands = self.traced(&[&operand_value]);
+        let result = match op {
             UnaryOp::Plus => operand_value,
             UnaryOp::Minus => -operand_value,
-        }
+            UnaryOp::Not => N::new(if is_true(operand_value.value()) { 0.0 } else { 1.0 }),
+        };
+        self.record(pos, TraceOperation::Unary(op.clone()), operands, &result);
+        result
     }
 
     /// Evaluates a function call by delegating to the standard library.
@@ -444,11 +871,18 @@ impl Executor{
     /// standard library function implementation. The standard library handles
     /// uncertainty propagation for mathematical functions.
     ///
+    /// `budget(expr)` is handled here instead: it prints the error budget of its
+    /// argument and evaluates to the argument's value. `formula(expr)` also evaluates to
+    /// its argument, then prints the argument's uncertainty formula along with that
+    /// value. `nominal(expr)` is also
+    /// handled here and evaluates to the argument's value without uncertainty.
+    ///
     /// # Arguments
     ///
     /// * `name` - Function name to call
     /// * `args` - Slice of argument expressions
     /// * `pos` - Source position (passed to standard library for error reporting)
+    /// * `span` - Source range of the call, which a failed assertion is reported at
     ///
     /// # Returns
     ///
@@ -456,11 +890,64 @@ impl Executor{
     ///
     /// # Panics
     ///
-    /// Panics if the function doesn't exist or has incorrect arity. These should
-    /// be caught by semantic analysis, so panics here indicate bugs.
-    fn evaluate_function_call(&mut self, name: &str, args: &[Expression], pos: &Position) -> Real {
-        let vals: Vec<Real> = args.iter().map(|arg| self.evaluate_expression(arg)).collect();
-        std_lib_call(name, &vals, pos)
+    /// Panics (through `resolve_builtin`, which the bytecode compiler shares) if the
+    /// function doesn't exist or has incorrect arity. These should be caught by
+    /// semantic analysis, so panics here indicate bugs.
+    fn evaluate_function_call(&mut self, name: &str, args: &[Expression], pos: &Position, span: &Span) -> N {
+        let builtin = resolve_builtin(name, args.len(), pos);
+        if builtin == Builtin::Budget && !self.quiet {
+            let budget = self.error_budget(&args[0]).to_string();
+            self.print(&budget);
+        }
+
+        let vals: Vec<N> = args.iter().map(|arg| self.evaluate_expression(arg)).collect();
+        if builtin == Builtin::Formula && !self.quiet {
+            let report = self.formula(&args[0]).report(&vals[0]);
+            self.print(&report);
+        }
+
+        let result = match builtin {
+            Builtin::Budget | Builtin::Formula => vals[0].clone(),
+            Builtin::Nominal => N::new(vals[0].value()),
+            Builtin::Assertion(_) => assertion_call(name, &vals, pos).unwrap_or_else(|message| {
+                self.errors.push(RunTimeError::AssertionFailed(message, *span));
+                vals[0].clone()
+            }),
+            Builtin::Std(function) => N::std_function(&STD_LIB[function], &vals, self.propagation_order),
+        };
+
+        self.record(pos, TraceOperation::Call(name.to_string()), vals, &result);
+        result
+    }
+
+    /// Computes the error budget of an expression against the current variables.
+    ///
+    /// Reports how much each source measurement contributes to the uncertainty of
+    /// the expression. See the `budget` module for how sources are identified.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression to analyze
+    ///
+    /// # Returns
+    ///
+    /// The error budget, largest contribution first
+    pub fn error_budget(&self, expression: &Expression) -> ErrorBudget {
+        error_budget(expression, &self.run_time_vars)
+    }
+
+    /// Derives the symbolic uncertainty formula of an expression against the current
+    /// variables. See the `symbolic` module for how it is built.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression to analyze
+    ///
+    /// # Returns
+    ///
+    /// The formula, with a partial derivative for every source measurement
+    pub fn formula(&self, expression: &Expression) -> Formula {
+        formula(expression, &self.run_time_vars)
     }
 
     /// Returns all runtime errors accumulated during execution.
@@ -476,4 +963,16 @@ impl Executor{
         self.errors.clone()
     }
 
-}
\ No newline at end of file
+}
+
+/// Builds the value of a numeric literal in the backend `N`.
+///
+/// An asymmetric uncertainty is kept as such, and a literal with its source text is
+/// built from the text, so that arbitrary-precision backends do not round it through `f64`.
+pub(crate) fn literal_value<N: Number>(value: f64, error: f64, notation: &Option<UncertaintyNotation>, literal: Option<&str>) -> N {
+    let (upper, lower) = match notation {
+        Some(UncertaintyNotation::Asymmetric { upper, lower }) => (*upper, *lower),
+        _ => (error, error),
+    };
+    N::from_literal(literal, value, upper, lower)
+}
@@ -0,0 +1,324 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Canonical source formatter for `.sig` files (`significance fmt`).
+//!
+//! The source is tokenized with its comments, parsed, and re-emitted from the AST:
+//!
+//! - every statement on its own line, with one space around binary operators, `:=`
+//!   and `+/-` (`±` is written as `+/-`), and none after unary operators;
+//! - only the parentheses the precedence table (DesignGuide §2.4.3) requires;
+//! - the names of consecutive declarations padded so that their colons line up;
+//! - standalone comments on their own line and trailing comments after their
+//!   statement, aligned within a block of commented lines, with comment text untouched;
+//! - at most one blank line between statements, none at the start or end.
+//!
+//! Numeric literals keep their source spelling (`1.5e-3`, `12.3(5)`, `2ppm`).
+//! Formatting is idempotent, and a source with tokenizer or syntax errors is
+//! left alone.
+
+use crate::significance::ast_parser::{AstParser, BinaryOp, Expression, ImportNames, Statement, UncertaintyNotation, VarType};
+use crate::significance::diagnostic::Diagnostic;
+use crate::significance::source_map::Span;
+use crate::significance::tokenizer::{Position, Token, TokenWithPos, Tokenizer};
+
+/// Binding strength of each expression form, loosest first, following the grammar.
+const OR: u8 = 1;
+const AND: u8 = 2;
+const COMPARISON: u8 = 3;
+const ADDITIVE: u8 = 4;
+const MULTIPLICATIVE: u8 = 5;
+const POWER: u8 = 6;
+const UNARY: u8 = 7;
+const UNCERTAIN: u8 = 8;
+const PRIMARY: u8 = 9;
+
+/// Formats a `.sig` source text.
+///
+/// # Arguments
+///
+/// * `text` - The complete source text
+///
+/// # Returns
+///
+/// * `Ok(String)` - The formatted source, ending in a single newline (empty if there
+///   is nothing but whitespace)
+/// * `Err(Vec<Diagnostic>)` - The tokenizer or syntax errors that prevent formatting
+pub fn format_source(text: &str) -> Result<String, Vec<Diagnostic>> {
+    let (tokens, mut errors) = Tokenizer::new(text).tokenize_recovering();
+
+    let (program, parse_errors) = AstParser::new().parse_program_recovering(tokens.clone());
+    errors.extend(parse_errors.iter().map(|error| error.to_diagnostic()));
+    if !errors.is_empty() {
+        errors.sort_by_key(|error| (error.span.start.line, error.span.start.column));
+        return Err(errors);
+    }
+
+    let formatter = Formatter { lines: text.split('\n').map(|line| line.chars().collect()).collect(), tokens };
+
+    // Statements and comments in source order; statements never span lines
+    let mut items: Vec<(Position, Item)> = program.statements.iter()
+        .map(|statement| (statement.span().start, Item::Statement(statement)))
+        .collect();
+    items.extend(formatter.tokens.iter().filter_map(|token| match &token.token {
+        Token::Comment(comment) => Some((token.position, Item::Comment(format!("#{}", comment.trim_end())))),
+        _ => None,
+    }));
+    items.sort_by_key(|(position, _)| (position.line, position.column));
+
+    let mut output: Vec<Option<Line>> = Vec::new();
+    let mut previous_line = None;
+    let mut previous_comment_column = None;
+
+    for (position, item) in items {
+        let same_line = previous_line == Some(position.line);
+        if !same_line && previous_line.is_some_and(|line| position.line > line + 1) {
+            output.push(None);
+        }
+
+        match item {
+            Item::Statement(statement) => {
+                output.push(Some(Line { code: Some(formatter.statement(statement)), comment: None, continues: false }));
+                previous_comment_column = None;
+            },
+            Item::Comment(comment) => match output.last_mut() {
+                Some(Some(line)) if same_line => {
+                    line.comment = Some(comment);
+                    previous_comment_column = Some(position.column);
+                },
+                _ => {
+                    // A comment lined up under the previous line's trailing comment continues it
+                    let continues = previous_line == Some(position.line - 1) && previous_comment_column == Some(position.column);
+                    output.push(Some(Line { code: None, comment: Some(comment), continues }));
+                    if !continues {
+                        previous_comment_column = None;
+                    }
+                },
+            },
+        }
+        previous_line = Some(position.line);
+    }
+
+    Ok(render(output))
+}
+
+/// A statement or a comment, positioned in the source.
+enum Item<'a> {
+    Statement(&'a Statement),
+    Comment(String),
+}
+
+/// Code of one formatted line, before alignment.
+enum Code {
+    /// `{name : type}`, whose name is padded to line up with neighbouring declarations
+    Declaration { name: String, var_type: &'static str },
+    Other(String),
+}
+
+/// One formatted line, before alignment.
+struct Line {
+    code: Option<Code>,
+    comment: Option<String>,
+
+    /// Whether a comment-only line continues the trailing comment above it
+    continues: bool,
+}
+
+/// Prints AST nodes, taking the spelling of literals from the source.
+struct Formatter {
+    /// Source lines as characters, since positions count characters
+    lines: Vec<Vec<char>>,
+    tokens: Vec<TokenWithPos>,
+}
+
+impl Formatter {
+    fn statement(&self, statement: &Statement) -> Code {
+        match statement {
+            Statement::VarDeclaration { name, var_type, .. } => Code::Declaration {
+                name: name.clone(),
+                var_type: match var_type {
+                    VarType::Real => "real",
+                    VarType::RealFunction => "real function",
+                },
+            },
+            Statement::Assignment { name, value, .. } => Code::Other(format!("{} := {}", name, self.expression(value, 0))),
+            Statement::Expression(expression) => Code::Other(self.expression(expression, 0)),
+            Statement::Import { path, names, .. } => Code::Other(match names {
+                ImportNames::Namespace(namespace) if *namespace == default_namespace(path) => format!("import \"{}\"", path),
+                ImportNames::Namespace(namespace) => format!("import \"{}\" as {}", path, namespace),
+                ImportNames::Selected(selected) => format!("import \"{}\" ({})", path, selected.join(", ")),
+            }),
+        }
+    }
+
+    /// Prints an expression, parenthesized if it binds more loosely than `min_level`.
+    fn expression(&self, expression: &Expression, min_level: u8) -> String {
+        let text = match expression {
+            Expression::NumberWithUncertainty { notation, error, span, .. } => self.literal(*span, notation, *error),
+            Expression::Variable { name, .. } => name.clone(),
+            Expression::FunctionCall { name, args, .. } => {
+                let args: Vec<String> = args.iter().map(|arg| self.expression(arg, 0)).collect();
+                format!("{}({})", name, args.join(", "))
+            },
+            Expression::Unary { op, operand, .. } => {
+                format!("{}{}", op.symbol(), self.expression(operand, UNARY))
+            },
+            Expression::Binary { left, op, right, .. } => {
+                let level = binary_level(op);
+                // Left-associative operators need parentheses around an equal right operand;
+                // the operands of `**` and `//` are a unary and a power, and comparisons
+                // do not chain
+                let (left_level, right_level) = match op {
+                    BinaryOp::Power | BinaryOp::Root => (UNARY, POWER),
+                    BinaryOp::PlusMinus => (UNCERTAIN, PRIMARY),
+                    _ if level == COMPARISON => (ADDITIVE, ADDITIVE),
+                    _ => (level, level + 1),
+                };
+                format!("{} {} {}", self.expression(left, left_level), op.symbol(), self.expression(right, right_level))
+            },
+        };
+
+        if level(expression) < min_level {
+            format!("({})", text)
+        } else {
+            text
+        }
+    }
+
+    /// Prints a numeric literal with the source spelling of its numbers.
+    ///
+    /// Parenthetical and asymmetric uncertainty (`12.3(5)`, `12.3(+0.4/-0.2)`) are lexed
+    /// as one number token whose text includes the parentheses, followed by synthesized
+    /// tokens with empty spans.
+    fn literal(&self, span: Span, notation: &Option<UncertaintyNotation>, error: f64) -> String {
+        let parts: Vec<String> = self.tokens.iter()
+            .filter(|token| token.span.start != token.span.end && starts_within(token.span, span))
+            .filter(|token| matches!(token.token, Token::Number(_) | Token::RelativeNumber(..)))
+            .map(|token| self.text(token.span))
+            .collect();
+
+        let value = parts.first().cloned().unwrap_or_default();
+        match notation {
+            Some(UncertaintyNotation::Asymmetric { .. }) => value,
+            Some(UncertaintyNotation::Relative { .. }) => format!("{} +/- {}", value, parts[parts.len() - 1]),
+            None if error == 0.0 || value.contains('(') => value,
+            None => format!("{} +/- {}", value, parts[parts.len() - 1]),
+        }
+    }
+
+    /// Returns the source text of a single-line span.
+    fn text(&self, span: Span) -> String {
+        let line = &self.lines[span.start.line - 1];
+        line[span.start.column - 1..(span.end.column - 1).min(line.len())].iter().collect()
+    }
+}
+
+/// Lays out the formatted lines: pads declaration names, aligns trailing comments and
+/// drops blank lines at the start and end.
+fn render(mut lines: Vec<Option<Line>>) -> String {
+    while matches!(lines.last(), Some(None)) {
+        lines.pop();
+    }
+    if matches!(lines.first(), Some(None)) {
+        lines.remove(0);
+    }
+
+    let is_declaration = |line: &Line| matches!(line.code, Some(Code::Declaration { .. }));
+    let codes: Vec<String> = lines.iter().enumerate()
+        .map(|(index, line)| match line.as_ref().and_then(|line| line.code.as_ref()) {
+            Some(Code::Declaration { name, var_type }) => {
+                let width = block_indices(&lines, is_declaration, index)
+                    .filter_map(|other| match lines[other].as_ref().and_then(|line| line.code.as_ref()) {
+                        Some(Code::Declaration { name, .. }) => Some(name.chars().count()),
+                        _ => None,
+                    })
+                    .max()
+                    .unwrap_or(0);
+                format!("{{{:<width$} : {}}}", name, var_type, width = width)
+            },
+            Some(Code::Other(code)) => code.clone(),
+            None => String::new(),
+        })
+        .collect();
+
+    let mut output = String::new();
+    let mut comment_column = 0;
+    for (index, line) in lines.iter().enumerate() {
+        let Some(line) = line else {
+            output.push('\n');
+            continue;
+        };
+
+        let code = &codes[index];
+        match (&line.code, &line.comment) {
+            (Some(_), Some(comment)) => {
+                comment_column = block_indices(&lines, |line| line.code.is_some() && line.comment.is_some(), index)
+                    .map(|other| codes[other].chars().count())
+                    .max()
+                    .unwrap_or(0) + 1;
+                output.push_str(&format!("{:<width$}{}", code, comment, width = comment_column));
+            },
+            (None, Some(comment)) if line.continues => output.push_str(&format!("{:<width$}{}", "", comment, width = comment_column)),
+            (None, Some(comment)) => output.push_str(comment),
+            _ => output.push_str(code),
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// Indices of the run of consecutive lines around `lines[index]` that satisfy `member`.
+fn block_indices(lines: &[Option<Line>], member: impl Fn(&Line) -> bool, index: usize) -> std::ops::Range<usize> {
+    let is_member = |index: usize| lines[index].as_ref().is_some_and(&member);
+    let mut start = index;
+    while start > 0 && is_member(start - 1) {
+        start -= 1;
+    }
+    let mut end = index + 1;
+    while end < lines.len() && is_member(end) {
+        end += 1;
+    }
+    start..end
+}
+
+/// Returns how tightly an expression binds; see the constants above.
+fn level(expression: &Expression) -> u8 {
+    match expression {
+        Expression::NumberWithUncertainty { error: 0.0, notation: None, .. } => PRIMARY,
+        Expression::NumberWithUncertainty { .. } => UNCERTAIN,
+        Expression::Variable { .. } | Expression::FunctionCall { .. } => PRIMARY,
+        Expression::Unary { .. } => UNARY,
+        Expression::Binary { op, .. } => binary_level(op),
+    }
+}
+
+fn binary_level(op: &BinaryOp) -> u8 {
+    match op {
+        BinaryOp::Add | BinaryOp::Sub => ADDITIVE,
+        BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => MULTIPLICATIVE,
+        BinaryOp::Power | BinaryOp::Root => POWER,
+        BinaryOp::PlusMinus => UNCERTAIN,
+        BinaryOp::Less | BinaryOp::LessEqual | BinaryOp::Greater | BinaryOp::GreaterEqual
+            | BinaryOp::Equal | BinaryOp::NotEqual => COMPARISON,
+        BinaryOp::And => AND,
+        BinaryOp::Or => OR,
+    }
+}
+
+/// Whether `inner` starts within `outer`.
+///
+/// The span of a parenthetical literal ends at its synthesized error token, inside the
+/// number token, so containment is decided by where tokens start.
+fn starts_within(inner: Span, outer: Span) -> bool {
+    let key = |position: Position| (position.line, position.column);
+    key(inner.start) >= key(outer.start) && key(inner.start) < key(outer.end)
+}
+
+/// Namespace an `import` without `as` gets: the file name without its extension.
+fn default_namespace(path: &str) -> String {
+    std::path::Path::new(path).file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default()
+}
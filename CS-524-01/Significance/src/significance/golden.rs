@@ -0,0 +1,294 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Golden-output tests for `.sig` programs (`significance test`).
+//!
+//! A test is a program together with the lines it is expected to produce: what its
+//! expression statements and `budget` reports print, followed by its diagnostics in
+//! their one-line form (`Error at 2:6: Division by zero`). The expectations are
+//! either embedded in the program as comments, in output order,
+//!
+//! ```text
+//! z := x + y
+//! z # expect: 14.9 +/- 0.54
+//! ```
+//!
+//! or kept one per line in a sibling file with the extension `.expected`. Embedded
+//! expectations take precedence.
+//!
+//! Lines are compared number by number: an expected number written with decimals
+//! matches any actual number that rounds to it at the precision it is written with, so
+//! `0.54` matches `0.5385164807134505` but `0.540` does not. A number written without
+//! a decimal point, such as `158` or `1e2`, is exact. Exact numbers match within
+//! `assert_real`'s tolerance of `1e-6`. Everything else must match exactly, apart
+//! from the amount of whitespace.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::significance::language_parser::Significance;
+use crate::significance::numbers::Number;
+use crate::significance::tokenizer::{Position, Token, Tokenizer};
+
+/// The smallest tolerance of a number, the `EPSILON` of `assert_real`.
+const EPSILON: f64 = 0.000001;
+
+/// Prefix of an embedded expectation, after the comment's `#`.
+const EXPECT: &str = "expect:";
+
+/// Where a test's expectations are kept.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expectations {
+    /// `# expect:` comments in the program, at these positions
+    Embedded(Vec<Position>),
+
+    /// A sibling `.expected` file
+    File(PathBuf),
+
+    /// The program has no expectations, so it is not a test
+    Missing,
+}
+
+/// A program that has been run, with what it was expected to produce.
+#[derive(Debug, Clone)]
+pub struct TestCase {
+    /// Path of the program
+    pub path: PathBuf,
+
+    /// Where the expectations came from
+    pub expectations: Expectations,
+
+    /// The expected lines
+    pub expected: Vec<String>,
+
+    /// The lines the program produced: its output, then its diagnostics
+    pub actual: Vec<String>,
+}
+
+impl TestCase {
+    /// Returns whether the program produced what was expected.
+    pub fn passed(&self) -> bool {
+        self.expected.len() == self.actual.len()
+            && self.expected.iter().zip(&self.actual).all(|(expected, actual)| lines_match(expected, actual))
+    }
+
+    /// Describes every line that differs, for a failure report.
+    pub fn mismatches(&self) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        for index in 0..self.expected.len().max(self.actual.len()) {
+            match (self.expected.get(index), self.actual.get(index)) {
+                (Some(expected), Some(actual)) if lines_match(expected, actual) => {},
+                (Some(expected), Some(actual)) => mismatches.push(format!("line {}: expected `{}`, got `{}`", index + 1, expected, actual)),
+                (Some(expected), None) => mismatches.push(format!("line {}: expected `{}`, got nothing", index + 1, expected)),
+                (None, Some(actual)) => mismatches.push(format!("line {}: unexpected `{}`", index + 1, actual)),
+                (None, None) => {},
+            }
+        }
+        mismatches
+    }
+}
+
+/// Runs a program and collects its expectations.
+///
+/// # Arguments
+///
+/// * `interpreter` - A fresh interpreter, configured with the backend, propagation
+///   order and lint levels to test with
+/// * `path` - Path of the program
+///
+/// # Returns
+///
+/// * `Ok(TestCase)` - The program was run (whether or not it had errors)
+/// * `Err(String)` - The program or its `.expected` file could not be read
+pub fn run_test<N: Number>(mut interpreter: Significance<N>, path: &Path) -> Result<TestCase, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read file '{}': {}", path.display(), e))?;
+    let (expectations, expected) = read_expectations(path, &text)?;
+
+    let (output, diagnostics) = interpreter.capture(&path.display().to_string(), &text, Some(path));
+    let mut actual = output;
+    actual.extend(diagnostics.iter().map(|diagnostic| diagnostic.to_string()));
+
+    Ok(TestCase { path: path.to_path_buf(), expectations, expected, actual })
+}
+
+/// Finds the expectations of a program: its `# expect:` comments, or else its
+/// `.expected` file.
+///
+/// # Returns
+///
+/// * `Ok((Expectations, Vec<String>))` - Where the expectations are, and the expected lines
+/// * `Err(String)` - The `.expected` file exists but could not be read
+pub fn read_expectations(path: &Path, text: &str) -> Result<(Expectations, Vec<String>), String> {
+    let (positions, expected) = embedded_expectations(text);
+    if !positions.is_empty() {
+        return Ok((Expectations::Embedded(positions), expected));
+    }
+
+    let file = expected_path(path);
+    if !file.exists() {
+        return Ok((Expectations::Missing, Vec::new()));
+    }
+    let contents = fs::read_to_string(&file)
+        .map_err(|e| format!("Failed to read file '{}': {}", file.display(), e))?;
+    let expected = contents.lines().map(str::trim_end).map(str::to_string).collect::<Vec<_>>();
+    let end = expected.iter().rposition(|line| !line.is_empty()).map_or(0, |index| index + 1);
+    Ok((Expectations::File(file), expected[..end].to_vec()))
+}
+
+/// Returns the `# expect:` comments of a source text, with their positions.
+///
+/// A text that does not tokenize has no embedded expectations.
+fn embedded_expectations(text: &str) -> (Vec<Position>, Vec<String>) {
+    let tokens = Tokenizer::new(text).tokenize().unwrap_or_default();
+    tokens.iter()
+        .filter_map(|token| match &token.token {
+            Token::Comment(comment) => comment.trim().strip_prefix(EXPECT).map(|line| (token.position, line.trim().to_string())),
+            _ => None,
+        })
+        .unzip()
+}
+
+/// Returns the path of a program's `.expected` file: `tests/gain.sig` → `tests/gain.expected`.
+pub fn expected_path(path: &Path) -> PathBuf {
+    path.with_extension("expected")
+}
+
+/// Updates a test's expectations to what the program actually produced.
+///
+/// Embedded expectations are rewritten in place: each `# expect:` comment gets the
+/// next actual line, comments left over are removed, and lines left over are appended
+/// to the program as new comments. Otherwise the `.expected` file is (re)written.
+///
+/// # Returns
+///
+/// * `Ok(())` - The expectations were written
+/// * `Err(String)` - A file could not be read or written
+pub fn bless(case: &TestCase) -> Result<(), String> {
+    let (path, contents) = match &case.expectations {
+        Expectations::Embedded(positions) => {
+            let text = fs::read_to_string(&case.path)
+                .map_err(|e| format!("Failed to read file '{}': {}", case.path.display(), e))?;
+            (case.path.clone(), rewrite_expectations(&text, positions, &case.actual))
+        },
+        Expectations::File(file) => (file.clone(), expected_file(&case.actual)),
+        Expectations::Missing => (expected_path(&case.path), expected_file(&case.actual)),
+    };
+    fs::write(&path, contents).map_err(|e| format!("Failed to write file '{}': {}", path.display(), e))
+}
+
+fn expected_file(lines: &[String]) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// Replaces the `# expect:` comments at `positions` in a source text with `lines`.
+pub fn rewrite_expectations(text: &str, positions: &[Position], lines: &[String]) -> String {
+    let mut source: Vec<Option<String>> = text.split('\n').map(|line| Some(line.to_string())).collect();
+
+    for (index, position) in positions.iter().enumerate() {
+        let Some(Some(line)) = source.get_mut(position.line - 1) else { continue };
+        let code: String = line.chars().take(position.column - 1).collect();
+        match lines.get(index) {
+            Some(expected) => *line = format!("{}# {} {}", code, EXPECT, expected),
+            None if code.trim().is_empty() => source[position.line - 1] = None,
+            None => *line = code.trim_end().to_string(),
+        }
+    }
+
+    let mut text = source.into_iter().flatten().collect::<Vec<_>>().join("\n");
+    for expected in lines.iter().skip(positions.len()) {
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text.push_str(&format!("# {} {}\n", EXPECT, expected));
+    }
+    text
+}
+
+/// Compares an expected line with an actual one (see the module documentation).
+pub fn lines_match(expected: &str, actual: &str) -> bool {
+    let (expected, actual) = (pieces(expected), pieces(actual));
+    expected.len() == actual.len() && expected.iter().zip(&actual).all(|(expected, actual)| match (expected, actual) {
+        (Piece::Number(expected, digits), Piece::Number(actual, _)) => {
+            let rounding = digits.map_or(0.0, |digits| 0.5 * 10f64.powi(-digits));
+            let tolerance = f64::max(EPSILON, rounding);
+            (expected - actual).abs() <= tolerance * (1.0 + EPSILON)
+        },
+        (Piece::Text(expected), Piece::Text(actual)) => expected == actual,
+        _ => false,
+    })
+}
+
+/// Part of a line, for comparison.
+#[derive(Debug, PartialEq)]
+enum Piece {
+    /// A number, and the power of ten of its last written decimal, negated (`0.54` → 2,
+    /// `1.5e-3` → 4), or `None` if it has no decimal point
+    Number(f64, Option<i32>),
+
+    /// Anything else, with runs of whitespace reduced to one space
+    Text(String),
+}
+
+/// Splits a line into numbers and the text between them.
+fn pieces(line: &str) -> Vec<Piece> {
+    let chars: Vec<char> = line.split_whitespace().collect::<Vec<_>>().join(" ").chars().collect();
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        // A number starts with a digit (or a sign before one), but not inside a word like `x2`
+        let after_word = index > 0 && (chars[index - 1].is_alphanumeric() || chars[index - 1] == '_');
+        let sign = matches!(chars[index], '-' | '+') && chars.get(index + 1).is_some_and(char::is_ascii_digit);
+        if after_word || !(chars[index].is_ascii_digit() || sign) {
+            text.push(chars[index]);
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        if sign {
+            index += 1;
+        }
+        let mut digits = 0;
+        let mut fraction = false;
+        while index < chars.len() && (chars[index].is_ascii_digit() || (chars[index] == '.' && !fraction)) {
+            if fraction {
+                digits += 1;
+            }
+            fraction |= chars[index] == '.';
+            index += 1;
+        }
+        if index < chars.len() && matches!(chars[index], 'e' | 'E') {
+            let exponent_digits = match chars.get(index + 1) {
+                Some('-' | '+') => index + 2,
+                _ => index + 1,
+            };
+            let end = (exponent_digits..chars.len()).find(|&i| !chars[i].is_ascii_digit()).unwrap_or(chars.len());
+            if end > exponent_digits {
+                digits -= chars[index + 1..end].iter().collect::<String>().parse::<i32>().unwrap_or(0);
+                index = end;
+            }
+        }
+
+        let number: String = chars[start..index].iter().collect();
+        match number.parse::<f64>() {
+            Ok(value) => {
+                if !text.is_empty() {
+                    pieces.push(Piece::Text(std::mem::take(&mut text)));
+                }
+                pieces.push(Piece::Number(value, Some(digits).filter(|_| fraction)));
+            },
+            Err(_) => text.push_str(&number),
+        }
+    }
+    if !text.is_empty() {
+        pieces.push(Piece::Text(text));
+    }
+    pieces
+}
@@ -0,0 +1,244 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Export of a program's computation graph as Graphviz DOT (`significance graph`).
+//!
+//! Every declared (or referenced) variable is one node, and every literal, operator and
+//! function call in the program's expressions is a node of its own. Edges run from each
+//! operand to the operation that uses it, and from an assignment's expression to the
+//! variable it is assigned to, so a variable used in a later assignment links the two
+//! computations. The results of expression statements are drawn with a double border.
+//!
+//! The graph is built from the program alone. Once the program has run, its nodes can be
+//! annotated with their values (`annotate_values`) and with each input's share of the
+//! variance of the program's final result (`annotate_shares`): the last expression
+//! statement, or the last assigned variable if there is none. Shares come from the
+//! result's error budget, so the inputs are the budget's sources: uncertain literals,
+//! `+/-` operators, and variables assigned directly from an uncertain literal.
+//!
+//! ```text
+//! digraph program {
+//!     rankdir=LR;
+//!     n0 [label="x", shape=box];
+//!     n1 [label="12.3 +/- 0.5", shape=plaintext];
+//!     n1 -> n0;
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::significance::ast_parser::{Expression, Program, Statement};
+use crate::significance::budget::source_name;
+use crate::significance::language_parser::Significance;
+use crate::significance::numbers::Number;
+use crate::significance::source_map::Span;
+use crate::significance::tokenizer::Position;
+
+/// What a node of the graph computes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeKind {
+    /// A variable, shared by every statement that uses it
+    Variable(String),
+
+    /// A numeric literal
+    Literal,
+
+    /// A unary or binary operator, by its symbol
+    Operator(&'static str),
+
+    /// A call of a built-in function
+    Call(String),
+}
+
+/// One node of a computation graph.
+#[derive(Debug, Clone)]
+pub struct GraphNode {
+    /// What the node computes
+    pub kind: NodeKind,
+
+    /// The expression the node evaluates (a variable node's is the variable itself)
+    pub expression: Expression,
+
+    /// Whether an expression statement prints the node's value
+    pub output: bool,
+
+    /// Evaluated value, set by `annotate_values`
+    pub value: Option<String>,
+
+    /// Percentage of the final result's variance, set by `annotate_shares`
+    pub share: Option<f64>,
+
+    /// Name of the node in error budgets, if it can be a source
+    source: Option<String>,
+}
+
+impl GraphNode {
+    /// Returns the text the node is drawn with, before any annotations.
+    pub fn label(&self) -> String {
+        match (&self.kind, &self.expression) {
+            (NodeKind::Variable(name), _) | (NodeKind::Call(name), _) => name.clone(),
+            (NodeKind::Operator(symbol), _) => symbol.to_string(),
+            (NodeKind::Literal, Expression::NumberWithUncertainty { value, error, .. }) if *error != 0.0 => {
+                format!("{} +/- {}", value, error)
+            },
+            (NodeKind::Literal, Expression::NumberWithUncertainty { value, .. }) => value.to_string(),
+            (NodeKind::Literal, _) => String::new(),
+        }
+    }
+
+    fn shape(&self) -> &'static str {
+        match self.kind {
+            NodeKind::Variable(_) => "box",
+            NodeKind::Literal => "plaintext",
+            NodeKind::Operator(_) => "circle",
+            NodeKind::Call(_) => "ellipse",
+        }
+    }
+}
+
+/// The computation graph of a program.
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    /// Nodes, in the order the program first mentions them
+    pub nodes: Vec<GraphNode>,
+
+    /// Edges as `(from, to)` node indices, from an operand to the node that uses it
+    pub edges: Vec<(usize, usize)>,
+
+    /// The final result, whose variance the shares are of
+    result: Option<Expression>,
+
+    /// Index of each variable's node
+    variables: HashMap<String, usize>,
+}
+
+impl Graph {
+    /// Builds the computation graph of an analyzed program.
+    ///
+    /// Imports add no nodes; a variable they bring in gets a node where it is used.
+    pub fn from_program(program: &Program) -> Self {
+        let mut graph = Graph::default();
+        let (mut last_assigned, mut last_output) = (None, None);
+        for statement in &program.statements {
+            match statement {
+                Statement::VarDeclaration { name, pos, span, .. } => {
+                    graph.variable(name, *pos, *span);
+                },
+                Statement::Assignment { name, value, pos, span } => {
+                    let target = graph.variable(name, *pos, *span);
+                    let definition = graph.expression(value, name);
+                    graph.edges.push((definition, target));
+                    last_assigned = Some(graph.nodes[target].expression.clone());
+                },
+                Statement::Expression(expression) => {
+                    let node = graph.expression(expression, "");
+                    graph.nodes[node].output = true;
+                    last_output = Some(expression.clone());
+                },
+                Statement::Import { .. } => {},
+            }
+        }
+        graph.result = last_output.or(last_assigned);
+        graph
+    }
+
+    /// Returns the expression whose variance the shares are of, if the program has one.
+    pub fn result(&self) -> Option<&Expression> {
+        self.result.as_ref()
+    }
+
+    /// Sets the value of every node, evaluated with the variables of a program that
+    /// has run. Runtime errors are not reported again.
+    pub fn annotate_values<N: Number>(&mut self, interpreter: &mut Significance<N>) {
+        for node in &mut self.nodes {
+            let value = match &node.kind {
+                NodeKind::Variable(name) => interpreter.get_var(name).map(|var| var.get_value().clone()),
+                _ => Some(interpreter.evaluate(&node.expression)),
+            };
+            node.value = value.map(|value| value.to_string());
+        }
+    }
+
+    /// Sets each input's share of the variance of the final result, from its error budget.
+    pub fn annotate_shares<N: Number>(&mut self, interpreter: &Significance<N>) {
+        let Some(result) = &self.result else { return };
+        let budget = interpreter.error_budget(result);
+        for node in &mut self.nodes {
+            node.share = node.source.as_ref()
+                .and_then(|source| budget.entries.iter().find(|entry| &entry.source == source))
+                .map(|entry| entry.percent);
+        }
+    }
+
+    /// Renders the graph in Graphviz DOT.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph program {\n    rankdir=LR;\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let mut label = node.label();
+            if let Some(value) = &node.value {
+                if node.kind != NodeKind::Literal || *value != label {
+                    label.push_str(&format!("\n= {}", value));
+                }
+            }
+            if let Some(share) = node.share {
+                label.push_str(&format!("\n{:.1}% of variance", share));
+            }
+            let border = if node.output { ", peripheries=2" } else { "" };
+            dot.push_str(&format!("    n{} [label=\"{}\", shape={}{}];\n", index, escape(&label), node.shape(), border));
+        }
+        for (from, to) in &self.edges {
+            dot.push_str(&format!("    n{} -> n{};\n", from, to));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Returns the node of a variable, adding it the first time the variable is seen.
+    fn variable(&mut self, name: &str, pos: Position, span: Span) -> usize {
+        if let Some(&index) = self.variables.get(name) {
+            return index;
+        }
+        let expression = Expression::Variable { name: name.to_string(), pos, span };
+        let index = self.add(NodeKind::Variable(name.to_string()), expression, "");
+        self.variables.insert(name.to_string(), index);
+        index
+    }
+
+    /// Adds the nodes of an expression inside the definition of `owner` ("" at top
+    /// level), returning the node of its result.
+    fn expression(&mut self, expression: &Expression, owner: &str) -> usize {
+        let (node, operands): (usize, Vec<&Expression>) = match expression {
+            Expression::Variable { name, pos, span } => return self.variable(name, *pos, *span),
+            Expression::NumberWithUncertainty { .. } => return self.add(NodeKind::Literal, expression.clone(), owner),
+            Expression::Binary { left, op, right, .. } => {
+                (self.add(NodeKind::Operator(op.symbol()), expression.clone(), owner), vec![left, right])
+            },
+            Expression::Unary { op, operand, .. } => {
+                (self.add(NodeKind::Operator(op.symbol()), expression.clone(), owner), vec![operand])
+            },
+            Expression::FunctionCall { name, args, .. } => {
+                (self.add(NodeKind::Call(name.clone()), expression.clone(), owner), args.iter().collect())
+            },
+        };
+        for operand in operands {
+            let from = self.expression(operand, owner);
+            self.edges.push((from, node));
+        }
+        node
+    }
+
+    fn add(&mut self, kind: NodeKind, expression: Expression, owner: &str) -> usize {
+        let source = source_name(&expression, owner);
+        self.nodes.push(GraphNode { kind, expression, output: false, value: None, share: None, source });
+        self.nodes.len() - 1
+    }
+}
+
+/// Escapes a label for a double-quoted DOT string, keeping line breaks as `\n`.
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
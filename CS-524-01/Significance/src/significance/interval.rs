@@ -0,0 +1,589 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Interval arithmetic backend.
+//!
+//! An `Interval` is a guaranteed enclosure `[lo, hi]` of the true result: every operation
+//! returns an interval containing all results that any combination of values from its
+//! operands could produce. This is a worst-case bound rather than a statistical one, so
+//! it suits safety-margin calculations where `Real`'s quadrature sums would understate
+//! the spread.
+//!
+//! # Rounding
+//!
+//! Bounds are rounded outward. Addition, subtraction, multiplication, division and square
+//! roots recover the exact rounding error of the `f64` result (two-sum and fused
+//! multiply-add), so a bound only moves by one ulp when the operation was actually
+//! inexact; exact calculations such as `2 + 3` stay exact. Other library functions are
+//! widened by one ulp on each side, which assumes the platform math library is faithful.
+//!
+//! Literals are rounded outward too. The tokenizer stores the nearest `f64` to a literal's
+//! decimal text, which for `0.1` is not `0.1`, so a literal that is not an integer is
+//! widened to the neighbouring doubles on each side before it is used.
+//!
+//! # Uncertain inputs
+//!
+//! An uncertain value `x +/- e` becomes `[x - e, x + e]`, and `+/-` widens an interval by
+//! the magnitude of its right operand, so errors add linearly instead of in quadrature.
+//! An interval reports its midpoint as `value()` and its radius as `error()`.
+//!
+//! Results outside a function's domain (e.g. `ln` of a negative interval) are empty and
+//! represented by NaN bounds. Parts of an interval outside the domain are ignored, so
+//! `sqrt([-1, 4])` is `[0, 2]`.
+
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::Number;
+use crate::significance::propagation::PropagationOrder;
+use crate::significance::standard_lib::StdFunction;
+
+/// Closed interval of real numbers with outward-rounded `f64` bounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+/// The empty interval, produced outside a function's domain.
+const EMPTY: Interval = Interval { lo: f64::NAN, hi: f64::NAN };
+
+/// The whole real line, produced by division by an interval containing zero.
+const ENTIRE: Interval = Interval { lo: f64::NEG_INFINITY, hi: f64::INFINITY };
+
+impl Interval {
+    /// Creates the interval `[lo, hi]`, swapping the bounds if they are given in reverse.
+    pub fn with_bounds(lo: f64, hi: f64) -> Self {
+        if lo > hi {
+            Self { lo: hi, hi: lo }
+        } else {
+            Self { lo, hi }
+        }
+    }
+
+    /// Returns the lower bound.
+    pub fn lower(&self) -> f64 {
+        self.lo
+    }
+
+    /// Returns the upper bound.
+    pub fn upper(&self) -> f64 {
+        self.hi
+    }
+
+    /// Returns the width `hi - lo`, rounded up.
+    pub fn width(&self) -> f64 {
+        sub_up(self.hi, self.lo)
+    }
+
+    /// Returns whether `x` lies within the interval.
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Returns whether the interval is empty (the result of leaving a function's domain).
+    pub fn is_empty(&self) -> bool {
+        self.lo.is_nan() || self.hi.is_nan()
+    }
+
+    /// Returns whether the interval holds a single number.
+    fn is_point(&self) -> bool {
+        self.lo == self.hi
+    }
+
+    /// Returns the smallest magnitude in the interval.
+    fn mig(&self) -> f64 {
+        if self.lo >= 0.0 {
+            self.lo
+        } else if self.hi <= 0.0 {
+            -self.hi
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the largest magnitude in the interval.
+    fn mag(&self) -> f64 {
+        self.lo.abs().max(self.hi.abs())
+    }
+
+    /// Returns the part of the interval within `[lo, hi]`, or the empty interval if none is.
+    fn restrict(&self, lo: f64, hi: f64) -> Self {
+        let (lo, hi) = (self.lo.max(lo), self.hi.min(hi));
+        if lo > hi { EMPTY } else { Self { lo, hi } }
+    }
+
+    /// Widens both bounds by one ulp, to cover a library function's rounding.
+    fn widened(self) -> Self {
+        Self { lo: self.lo.next_down(), hi: self.hi.next_up() }
+    }
+
+    /// Raises the interval to a non-negative integer power by repeated squaring.
+    fn powi(&self, n: u32) -> Self {
+        // Bounds of x^n for x >= 0, each computed with its own rounding direction
+        let pow_down = |x: f64| pow_rounded(x, n, mul_down);
+        let pow_up = |x: f64| pow_rounded(x, n, mul_up);
+
+        if n == 0 {
+            return Self::new(1.0);
+        }
+        if n.is_multiple_of(2) {
+            return Self { lo: pow_down(self.mig()), hi: pow_up(self.mag()) };
+        }
+        // Odd powers are increasing
+        let lo = if self.lo >= 0.0 { pow_down(self.lo) } else { -pow_up(-self.lo) };
+        let hi = if self.hi >= 0.0 { pow_up(self.hi) } else { -pow_down(-self.hi) };
+        Self { lo, hi }
+    }
+
+    /// Evaluates the standard library function `name` on interval arguments.
+    ///
+    /// Returns `None` if `name` has no tailored enclosure.
+    fn std_function_by_name(name: &str, args: &[Self]) -> Option<Self> {
+        if args.iter().any(Interval::is_empty) {
+            return Some(EMPTY);
+        }
+        let x = args[0];
+        let result = match name {
+            "sin" => sin(x),
+            "cos" => cos(x),
+            "tan" => tan(x),
+            "asin" => increasing(x.restrict(-1.0, 1.0), f64::asin).restrict(-FRAC_PI_2, FRAC_PI_2),
+            "acos" => decreasing(x.restrict(-1.0, 1.0), f64::acos).restrict(0.0, PI),
+            "atan" => increasing(x, f64::atan).restrict(-FRAC_PI_2, FRAC_PI_2),
+            "sinh" => increasing(x, f64::sinh),
+            "cosh" => Interval { lo: x.mig().cosh().next_down().max(1.0), hi: x.mag().cosh().next_up() },
+            "tanh" => increasing(x, f64::tanh).restrict(-1.0, 1.0),
+            "exp" => increasing(x, f64::exp).restrict(0.0, f64::INFINITY),
+            "ln" => increasing(x.restrict(0.0, f64::INFINITY), f64::ln),
+            "log10" => increasing(x.restrict(0.0, f64::INFINITY), f64::log10),
+            "sqrt" => sqrt(x.restrict(0.0, f64::INFINITY)),
+            "abs" => Interval { lo: x.mig(), hi: x.mag() },
+            "atan2" => atan2(x, args[1]),
+            "hypot" => {
+                let y = args[1];
+                Interval { lo: x.mig().hypot(y.mig()).next_down().max(0.0), hi: x.mag().hypot(y.mag()).next_up() }
+            },
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    /// Encloses a standard library function that has no tailored enclosure.
+    ///
+    /// Point arguments are evaluated with the function's `eval` and widened by one ulp;
+    /// anything else could reach any value, so the result is the whole real line.
+    fn generic_enclosure(function: &StdFunction, args: &[Self]) -> Self {
+        if !args.iter().all(Interval::is_point) {
+            return ENTIRE;
+        }
+        let points: Vec<f64> = args.iter().map(|arg| arg.lo).collect();
+        let value = (function.eval)(&points);
+        if value.is_nan() { EMPTY } else { Interval { lo: value, hi: value }.widened() }
+    }
+}
+
+impl Number for Interval {
+    /// Returns the midpoint of the interval.
+    fn value(&self) -> f64 {
+        if self.is_point() {
+            self.lo
+        } else if self.lo == f64::NEG_INFINITY && self.hi == f64::INFINITY {
+            0.0
+        } else if self.lo.is_finite() && self.hi.is_finite() {
+            self.lo / 2.0 + self.hi / 2.0
+        } else if self.lo.is_finite() {
+            self.hi
+        } else {
+            self.lo
+        }
+    }
+
+    /// Returns the radius of the interval (rounded up), so `value() +/- error()` encloses it.
+    fn error(&self) -> f64 {
+        if self.is_point() {
+            return 0.0;
+        }
+        if !self.lo.is_finite() || !self.hi.is_finite() {
+            return f64::INFINITY;
+        }
+        let mid = self.value();
+        sub_up(self.hi, mid).max(sub_up(mid, self.lo))
+    }
+
+    fn with_error(value: f64, error: f64) -> Self {
+        let error = error.abs();
+        Self { lo: sub_down(value, error), hi: add_up(value, error) }
+    }
+
+    fn with_asymmetric_error(value: f64, upper: f64, lower: f64) -> Self {
+        Self { lo: sub_down(value, lower.abs()), hi: add_up(value, upper.abs()) }
+    }
+
+    fn from_literal(_literal: Option<&str>, value: f64, upper: f64, lower: f64) -> Self {
+        let (value_lo, value_hi) = literal_bounds(value);
+        let (_, upper) = literal_bounds(upper.abs());
+        let (_, lower) = literal_bounds(lower.abs());
+        Self { lo: sub_down(value_lo, lower), hi: add_up(value_hi, upper) }
+    }
+
+    fn power(&self, other: Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return EMPTY;
+        }
+
+        // Exact integer exponents work for negative bases too
+        let n = other.lo;
+        if other.is_point() && n.fract() == 0.0 && n.abs() <= u32::MAX as f64 {
+            let positive = self.powi(n.abs() as u32);
+            return if n < 0.0 { Interval::new(1.0) / positive } else { positive };
+        }
+
+        // x^y = exp(y ln x) is monotone in each argument for x >= 0, so the corners bound it
+        if self.lo < 0.0 {
+            return EMPTY;
+        }
+        let corners = [
+            self.lo.powf(other.lo), self.lo.powf(other.hi),
+            self.hi.powf(other.lo), self.hi.powf(other.hi),
+        ];
+        hull(&corners).widened().restrict(0.0, f64::INFINITY)
+    }
+
+    fn root(&self, other: Self) -> Self {
+        if other == Interval::new(2.0) {
+            return sqrt(self.restrict(0.0, f64::INFINITY));
+        }
+        // x^(1/n), with 1/n itself enclosed
+        self.power(Interval::new(1.0) / other)
+    }
+
+    fn plus_minus(&self, other: Self) -> Self {
+        // Worst case: the attached uncertainty adds linearly
+        let extra = other.mag();
+        Self { lo: sub_down(self.lo, extra), hi: add_up(self.hi, extra) }
+    }
+
+    fn std_function(function: &StdFunction, args: &[Self], _order: PropagationOrder) -> Self {
+        Self::std_function_by_name(function.name, args)
+            .unwrap_or_else(|| Self::generic_enclosure(function, args))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_point() {
+            write!(f, "{}", self.lo)
+        } else {
+            write!(f, "[{}, {}]", self.lo, self.hi)
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return EMPTY;
+        }
+        Self { lo: add_down(self.lo, other.lo), hi: add_up(self.hi, other.hi) }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return EMPTY;
+        }
+        Self { lo: sub_down(self.lo, other.hi), hi: sub_up(self.hi, other.lo) }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return EMPTY;
+        }
+        let (a, b, c, d) = (self.lo, self.hi, other.lo, other.hi);
+        Self {
+            lo: mul_down(a, c).min(mul_down(a, d)).min(mul_down(b, c)).min(mul_down(b, d)),
+            hi: mul_up(a, c).max(mul_up(a, d)).max(mul_up(b, c)).max(mul_up(b, d)),
+        }
+    }
+}
+
+impl Div for Interval {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return EMPTY;
+        }
+        if other.contains(0.0) {
+            // Dividing by values arbitrarily close to zero
+            return ENTIRE;
+        }
+        let (a, b, c, d) = (self.lo, self.hi, other.lo, other.hi);
+        Self {
+            lo: div_down(a, c).min(div_down(a, d)).min(div_down(b, c)).min(div_down(b, d)),
+            hi: div_up(a, c).max(div_up(a, d)).max(div_up(b, c)).max(div_up(b, d)),
+        }
+    }
+}
+
+impl Rem for Interval {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        if self.is_empty() || other.is_empty() || (other.lo == 0.0 && other.hi == 0.0) {
+            return EMPTY;
+        }
+        if self.hi <= 0.0 {
+            // The remainder takes the sign of the dividend, so mirror the positive case
+            return -((-self) % other);
+        }
+        if self.lo < 0.0 {
+            let m = other.mag();
+            return Self { lo: self.lo.max(-m), hi: self.hi.min(m) };
+        }
+
+        // Non-negative dividend. `%` on f64 is exact, so an exact divisor that does not
+        // wrap the interval around keeps it contiguous.
+        if other.is_point() {
+            let (r_lo, r_hi) = (self.lo % other.lo, self.hi % other.lo);
+            if r_lo <= r_hi && self.width() < other.lo.abs() {
+                return Self { lo: r_lo, hi: r_hi };
+            }
+        }
+        Self { lo: 0.0, hi: self.hi.min(other.mag()) }
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { lo: -self.hi, hi: -self.lo }
+    }
+}
+
+/// Returns the smallest interval containing every value, ignoring NaNs.
+/// Returns an enclosure of the decimal literal whose nearest `f64` is `x`.
+///
+/// Integers up to 2^53 are exact; anything else may have been rounded by half an ulp
+/// either way when its text was parsed, so it is widened to its neighbours.
+fn literal_bounds(x: f64) -> (f64, f64) {
+    if x.fract() == 0.0 && x.abs() <= 9_007_199_254_740_992.0 {
+        (x, x)
+    } else {
+        (x.next_down(), x.next_up())
+    }
+}
+
+fn hull(values: &[f64]) -> Interval {
+    let lo = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let hi = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if lo > hi { EMPTY } else { Interval { lo, hi } }
+}
+
+/// Encloses an increasing function over the interval.
+fn increasing(x: Interval, f: fn(f64) -> f64) -> Interval {
+    if x.is_empty() {
+        return EMPTY;
+    }
+    Interval { lo: f(x.lo), hi: f(x.hi) }.widened()
+}
+
+/// Encloses a decreasing function over the interval.
+fn decreasing(x: Interval, f: fn(f64) -> f64) -> Interval {
+    if x.is_empty() {
+        return EMPTY;
+    }
+    Interval { lo: f(x.hi), hi: f(x.lo) }.widened()
+}
+
+/// Beyond this magnitude the phase of a trigonometric argument is too imprecise to locate
+/// the extrema, and the full range is returned.
+const MAX_PHASE_ARGUMENT: f64 = 1e8;
+
+/// Returns whether `x` contains `phase + k·period` for some integer `k`.
+///
+/// The test is widened by a few ulps of the arguments, so a critical point lying on
+/// a bound is never missed because of rounding in `k·period`.
+fn contains_phase(x: Interval, phase: f64, period: f64) -> bool {
+    let slack = 4.0 * f64::EPSILON * x.mag().max(1.0);
+    let k = ((x.lo - slack - phase) / period).ceil();
+    phase + k * period <= x.hi + slack
+}
+
+fn sin(x: Interval) -> Interval {
+    if x.width() >= TAU || x.mag() > MAX_PHASE_ARGUMENT {
+        return Interval { lo: -1.0, hi: 1.0 };
+    }
+    let mut y = hull(&[x.lo.sin(), x.hi.sin()]).widened();
+    if contains_phase(x, FRAC_PI_2, TAU) {
+        y.hi = 1.0;
+    }
+    if contains_phase(x, -FRAC_PI_2, TAU) {
+        y.lo = -1.0;
+    }
+    y.restrict(-1.0, 1.0)
+}
+
+fn cos(x: Interval) -> Interval {
+    if x.width() >= TAU || x.mag() > MAX_PHASE_ARGUMENT {
+        return Interval { lo: -1.0, hi: 1.0 };
+    }
+    let mut y = hull(&[x.lo.cos(), x.hi.cos()]).widened();
+    if contains_phase(x, 0.0, TAU) {
+        y.hi = 1.0;
+    }
+    if contains_phase(x, PI, TAU) {
+        y.lo = -1.0;
+    }
+    y.restrict(-1.0, 1.0)
+}
+
+fn tan(x: Interval) -> Interval {
+    if x.width() >= PI || x.mag() > MAX_PHASE_ARGUMENT || contains_phase(x, FRAC_PI_2, PI) {
+        return ENTIRE;
+    }
+    increasing(x, f64::tan)
+}
+
+/// Encloses `atan2(y, x)`, the angle of the point `(x, y)`.
+fn atan2(y: Interval, x: Interval) -> Interval {
+    // The angle is undefined at the origin and jumps from π to -π across the negative x-axis
+    if (x.contains(0.0) && y.contains(0.0)) || (x.lo < 0.0 && y.lo < 0.0 && y.hi >= 0.0) {
+        return Interval { lo: -PI, hi: PI };
+    }
+    // Otherwise the box subtends an angle bounded by its corners
+    let corners = [y.lo.atan2(x.lo), y.lo.atan2(x.hi), y.hi.atan2(x.lo), y.hi.atan2(x.hi)];
+    hull(&corners).widened().restrict(-PI, PI)
+}
+
+fn sqrt(x: Interval) -> Interval {
+    if x.is_empty() {
+        return EMPTY;
+    }
+    let (lo, hi) = (x.lo.sqrt(), x.hi.sqrt());
+    // s² - x is exact with a fused multiply-add, which tells which way s was rounded
+    Interval {
+        lo: round_down(lo, -lo.mul_add(lo, -x.lo)),
+        hi: round_up(hi, -hi.mul_add(hi, -x.hi)),
+    }
+}
+
+/// Computes `x^n` for `x >= 0` by repeated squaring, rounding every product the same way.
+fn pow_rounded(x: f64, n: u32, mul: fn(f64, f64) -> f64) -> f64 {
+    let (mut base, mut n, mut result) = (x, n, 1.0);
+    while n > 0 {
+        if n % 2 == 1 {
+            result = mul(result, base);
+        }
+        base = mul(base, base);
+        n /= 2;
+    }
+    result
+}
+
+/// Rounds `x` down unless `err` (the exact result minus `x`) shows it is not above the exact result.
+fn round_down(x: f64, err: f64) -> f64 {
+    if x.is_nan() {
+        f64::NEG_INFINITY
+    } else if err < 0.0 || err.is_nan() {
+        x.next_down()
+    } else {
+        x
+    }
+}
+
+/// Rounds `x` up unless `err` (the exact result minus `x`) shows it is not below the exact result.
+fn round_up(x: f64, err: f64) -> f64 {
+    if x.is_nan() {
+        f64::INFINITY
+    } else if err > 0.0 || err.is_nan() {
+        x.next_up()
+    } else {
+        x
+    }
+}
+
+/// Exact rounding error of `s = a + b` (two-sum).
+fn add_error(a: f64, b: f64, s: f64) -> f64 {
+    let b_virtual = s - a;
+    (a - (s - b_virtual)) + (b - b_virtual)
+}
+
+fn add_down(a: f64, b: f64) -> f64 {
+    let s = a + b;
+    round_down(s, add_error(a, b, s))
+}
+
+fn add_up(a: f64, b: f64) -> f64 {
+    let s = a + b;
+    round_up(s, add_error(a, b, s))
+}
+
+fn sub_down(a: f64, b: f64) -> f64 {
+    add_down(a, -b)
+}
+
+fn sub_up(a: f64, b: f64) -> f64 {
+    add_up(a, -b)
+}
+
+/// Rounding error of `p = a · b`, or NaN where the fused multiply-add cannot represent it.
+fn mul_error(a: f64, b: f64, p: f64) -> f64 {
+    // Below this magnitude the error may be subnormal and itself rounded
+    const TINY: f64 = f64::MIN_POSITIVE * (1u64 << 53) as f64;
+    if p != 0.0 && p.abs() < TINY {
+        return f64::NAN;
+    }
+    a.mul_add(b, -p)
+}
+
+fn mul_down(a: f64, b: f64) -> f64 {
+    // Zero times an infinite bound is zero here, since bounds only approach infinity
+    if a == 0.0 || b == 0.0 {
+        return 0.0;
+    }
+    let p = a * b;
+    round_down(p, mul_error(a, b, p))
+}
+
+fn mul_up(a: f64, b: f64) -> f64 {
+    if a == 0.0 || b == 0.0 {
+        return 0.0;
+    }
+    let p = a * b;
+    round_up(p, mul_error(a, b, p))
+}
+
+/// Sign of the rounding error of `q = a / b`, from the exact remainder `a - q·b`.
+fn div_error(a: f64, b: f64, q: f64) -> f64 {
+    if q != 0.0 && q.abs() < f64::MIN_POSITIVE {
+        return f64::NAN;
+    }
+    (-q).mul_add(b, a) * b.signum()
+}
+
+fn div_down(a: f64, b: f64) -> f64 {
+    let q = a / b;
+    round_down(q, div_error(a, b, q))
+}
+
+fn div_up(a: f64, b: f64) -> f64 {
+    let q = a / b;
+    round_up(q, div_error(a, b, q))
+}
@@ -39,13 +39,55 @@
 //!
 //! // File mode
 //! Significance::parse_file("program.sig")?;
+//!
+//! // Guaranteed bounds instead of statistical uncertainty
+//! let mut sig = Significance::<Interval>::with_backend();
+//! sig.parse_repl("(12.3 +/- 0.5) * 2")?;  // Prints: [23.599999999999994, 25.60000000000001]
 //! ```
+//!
+//! # Imports
+//!
+//! `import "path.sig"` runs another file in a fresh interpreter and merges the variables
+//! it assigns into the importing file's symbol table and runtime variables, either under
+//! a namespace (`calib.offset`) or by name. Paths are resolved relative to the importing
+//! file (or the working directory in the REPL), import cycles are reported, and errors
+//! inside an imported file are rendered against that file.
+//!
+//! # Diagnostics
+//!
+//! Every source text (including imported files) is registered in one `SourceMap`, and
+//! errors from every stage are collected as `Diagnostic`s. They are only rendered to text
+//! at the public entry points (`parse_repl`, `run_file`); `check` returns them as they are
+//! for tools such as the language server.
+//!
+//! # Lints
+//!
+//! Lint warnings from the analyzer are reported at the levels of a `LintConfig`, set with
+//! `set_lint_level` and overridden by the `# lint:` directives of each file (or, in the
+//! REPL, of any line entered so far). Warnings are reported after any errors; a denied
+//! lint is an error and stops the program from running. Imported files are not linted.
+//!
+//! # Constant Folding
+//!
+//! After analysis, the executor folds the exact literal subexpressions of each statement
+//! before running it (see `optimizer.rs`), keeping assigned definitions as written.
+//! Folding never changes results, and can be turned off with `set_constant_folding` to
+//! compare. It only applies to the `Real` backend.
 //! 
+use std::collections::HashMap;
 use std::fs::{self, File};
-use crate::significance::tokenizer::{Tokenizer, Token, TokenWithPos};
-use crate::significance::ast_parser::{AstParser, Program};
-use crate::significance::semantic_analyzer::SemanticAnalyzer;
-use crate::significance::executor::Executor;
+use std::path::{Path, PathBuf};
+use crate::significance::tokenizer::{Tokenizer, Token, TokenWithPos, Position};
+use crate::significance::source_map::{SourceMap, FileId, Span};
+use crate::significance::diagnostic::{Diagnostic, Stage};
+use crate::significance::lints::{Lint, LintConfig, LintLevel};
+use crate::significance::ast_parser::{AstParser, Expression, Program, Statement, ImportNames};
+use crate::significance::budget::ErrorBudget;
+use crate::significance::symbolic::Formula;
+use crate::significance::semantic_analyzer::{SemanticAnalyzer, VarInfo};
+use crate::significance::executor::{Executor, TraceEntry, VarRunTime};
+use crate::significance::propagation::PropagationOrder;
+use crate::{Number, Real};
 
 /// Main interpreter interface for the Significance language.
 ///
@@ -59,6 +101,12 @@ use crate::significance::executor::Executor;
 /// - **Analyzer**: Validates ASTs for semantic correctness
 /// - **Executor**: Evaluates validated ASTs with uncertainty propagation
 ///
+/// # Numeric Backends
+///
+/// The interpreter computes with `Real` (statistical uncertainty) by default. Any other
+/// `Number` backend, such as `Interval` for guaranteed worst-case bounds, is selected
+/// with `Significance::<N>::with_backend()`.
+///
 /// # State Persistence
 ///
 /// The `Significance` instance maintains state across `parse_repl` calls, enabling:
@@ -89,7 +137,7 @@ use crate::significance::executor::Executor;
 /// // Calculate with automatic uncertainty propagation
 /// interpreter.parse_repl("mass * velocity")?;  // kinetic energy
 /// ```
-pub struct Significance{
+pub struct Significance<N = Real> {
     /// AST parser for converting token streams into syntax trees
     parser: AstParser,
     
@@ -97,7 +145,38 @@ pub struct Significance{
     analyzer: SemanticAnalyzer,
     
     /// Runtime executor that maintains variable state and performs calculations
-    executor: Executor
+    executor: Executor<N>,
+
+    /// Canonical paths of the files currently being run, outermost first.
+    ///
+    /// An import of a file already on this stack is an import cycle.
+    import_stack: Vec<PathBuf>,
+
+    /// Whether this interpreter runs an imported file (whose expressions are not printed).
+    is_module: bool,
+
+    /// Every source text read so far, used to render diagnostics with their source line.
+    ///
+    /// Imported files are registered here too: the map is lent to the interpreter that
+    /// runs the import and returned afterward.
+    sources: SourceMap,
+
+    /// File `run_file` writes the parsed AST to as JSON, if any
+    ast_output: Option<PathBuf>,
+
+    /// Warnings from the last `run_file`, `check` or `parse_repl` call, including
+    /// those of imported files
+    warnings: Vec<Diagnostic>,
+
+    /// Lint levels before the directives of a file are applied
+    lints: LintConfig,
+
+}
+
+impl Default for Significance {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Significance {
@@ -118,16 +197,184 @@ impl Significance {
     /// sig.parse_repl("2 + 2")?;  // Prints: 4
     /// ```
     pub fn new() -> Self {
+        Self::with_backend()
+    }
+
+    /// Parses and executes a complete program from a source file.
+    ///
+    /// Reads a `.sig` file and processes it through the complete interpretation pipeline.
+    /// This is the batch execution mode for running complete programs.
+    ///
+    /// # Pipeline Steps
+    ///
+    /// 1. Read source file to string
+    /// 2. Tokenize the complete input
+    /// 3. Parse tokens into AST
+    /// 4. Perform semantic analysis
+    /// 5. Execute the validated program
+    ///
+    /// # Arguments
+    ///
+    /// * `filename` - Path to the source file (typically `.sig` extension)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(0)` - Program executed successfully
+    /// * `Err(String)` - Error message describing what went wrong
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for:
+    /// - File I/O errors (file not found, permission denied, etc.)
+    /// - Tokenization errors (invalid characters, malformed numbers)
+    /// - Parse errors (syntax violations)
+    /// - Semantic errors (the program is not executed)
+    ///
+    /// # Side Effects
+    ///
+    /// - Prints output from expression statements to stdout
+    /// - May print error messages if semantic or runtime errors occur
+    ///
+    /// # Note
+    ///
+    /// Unlike `parse_repl`, this method does not maintain state. Each file is executed
+    /// in a fresh interpreter instance. Runtime errors are not currently surfaced in
+    /// the return value (only parse and semantic errors are).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // program.sig:
+    /// // {x : real}
+    /// // {y : real}
+    /// // x := 12.3 +/- 0.5
+    /// // y := 2.6 +/- 0.2
+    /// // x + y
+    ///
+    /// Significance::parse_file("program.sig")?;
+    /// // Prints: 14.9 ± 0.5385164807134504
+    /// ```
+    pub fn parse_file(filename: &str) -> Result<i32, String> {
+        Self::new().run_file(filename)
+    }
+}
+
+impl<N: Number> Significance<N> {
+
+    /// Creates a new interpreter that computes with the numeric backend `N`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Significance` instance ready for REPL or file execution
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut sig = Significance::<Interval>::with_backend();
+    /// sig.parse_repl("sqrt(4 +/- 1)")?;  // Prints the bounds of sqrt over [3, 5]
+    /// ```
+    pub fn with_backend() -> Self {
         let mut analyzer = SemanticAnalyzer::new();
-        analyzer.import_standard_library();
+        analyzer.import_standard_library().import_constants_library();
+
+        let mut executor = Executor::with_backend();
+        executor.set_constant_folding(true);
 
         Self {
             parser: AstParser::new(),
             analyzer,
-            executor: Executor::new()
+            executor,
+            import_stack: Vec::new(),
+            is_module: false,
+            sources: SourceMap::new(),
+            ast_output: None,
+            warnings: Vec::new(),
+            lints: LintConfig::new(),
         }
     }
 
+    /// Selects the order of uncertainty propagation used by the executor.
+    ///
+    /// Applies to every statement evaluated afterward, in both REPL and file mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - `PropagationOrder::First` (default) or `PropagationOrder::Second`
+    pub fn set_propagation_order(&mut self, order: PropagationOrder) {
+        self.executor.set_propagation_order(order);
+    }
+
+    /// Writes the AST of each file run with `run_file` to `path` as JSON, for inspection.
+    ///
+    /// Off by default; `None` turns it off again. Imported files are not written.
+    pub fn set_ast_output(&mut self, path: Option<&Path>) {
+        self.ast_output = path.map(Path::to_path_buf);
+    }
+
+    /// Sets the level a lint is reported at, unless a file's directives override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `lint` - The lint to configure
+    /// * `level` - `Allow`, `Warn` (default) or `Deny`
+    pub fn set_lint_level(&mut self, lint: Lint, level: LintLevel) {
+        self.lints.set(lint, level);
+    }
+
+    /// Turns folding of exact literal subexpressions on (the default) or off.
+    ///
+    /// Folding does not change results; turning it off lets them be compared. Backends
+    /// other than `Real` are never folded.
+    pub fn set_constant_folding(&mut self, enabled: bool) {
+        self.executor.set_constant_folding(enabled);
+    }
+
+    /// Returns whether exact literal subexpressions are folded for this backend.
+    pub fn is_constant_folding(&self) -> bool {
+        self.executor.is_constant_folding()
+    }
+
+    /// Retrieves the current value of a variable by name.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(VarRunTime)` - Variable found with its current value
+    /// * `None` - Variable does not exist
+    pub fn get_var(&self, name: &str) -> Option<VarRunTime<N>> {
+        self.executor.get_var(name)
+    }
+
+    /// Looks up an identifier in the semantic analyzer's symbol table.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&VarInfo)` - The identifier's declared type and state
+    /// * `None` - The identifier is not declared
+    pub fn get_symbol(&self, name: &str) -> Option<&VarInfo> {
+        self.analyzer.get_symbol(name)
+    }
+
+    /// Returns whether `name` refers to a built-in constant the program has not shadowed.
+    pub fn is_constant(&self, name: &str) -> bool {
+        self.analyzer.is_constant(name)
+    }
+
+    /// Iterates over every declared identifier, including the standard library,
+    /// constants and imported variables.
+    pub fn symbols(&self) -> impl Iterator<Item = (&str, &VarInfo)> {
+        self.analyzer.symbols()
+    }
+
+    /// Returns every source text read so far, for rendering diagnostics.
+    pub fn sources(&self) -> &SourceMap {
+        &self.sources
+    }
+
+    /// Suppresses (or restores) printing of expression results and `budget` reports.
+    pub fn set_quiet(&mut self, quiet: bool) {
+        self.executor.set_quiet(quiet);
+    }
+
     /// Parses and executes a single statement in REPL mode.
     ///
     /// Processes one line of input through the complete interpretation pipeline,
@@ -183,18 +430,21 @@ impl Significance {
     pub fn parse_repl(&mut self, input: &str) -> Result<Vec<String>, std::io::Error> {
 
         self.analyzer.clear_errors();
+        self.analyzer.clear_warnings();
         self.executor.clear_errors();
+        self.warnings.clear();
 
         let mut errors = Vec::new();
         
         let input = input.trim();
-        let mut tokenizer = Tokenizer::new(input);
+        let file = self.sources.add("<repl>", input);
+        let mut tokenizer = Tokenizer::with_file(input, file);
         
         let tokens = match tokenizer.tokenize() {
             Ok(t) => t,
             Err(e) => {
-                errors.push(e);  // tokenize already returns String
-                return Ok(errors);
+                errors.push(e);
+                return Ok(self.render(&errors));
             }
         };
 
@@ -210,23 +460,41 @@ impl Significance {
             _ => (),
         }
 
+        self.warnings = self.lints.apply_directives(&tokens);
+
+        // A line holding only a comment (such as a lint directive) has nothing to run
+        if tokens.iter().all(|t| matches!(t.token, Token::Comment(_) | Token::Newline | Token::EOF)) {
+            return Ok(Vec::new());
+        }
+
         let ast = match self.parser.parse_statement_from_tokens(tokens) {
             Ok(a) => a,
             Err(e) => {
-                errors.push(e.to_string());
-                return Ok(errors);
+                errors.push(e.to_diagnostic());
+                return Ok(self.render(&errors));
             }
         };
 
+        if let Statement::Import { path, names, pos, span } = &ast {
+            if let Err(e) = self.import(path, names, pos, span, None) {
+                return Ok(self.render(&e));
+            }
+        }
+
         self.analyzer.analyze_statement(&ast);
-        errors.extend(self.analyzer.get_errors().iter().map(|e| e.to_string()));
+        errors.extend(self.analyzer.get_errors().iter().map(|e| e.to_diagnostic(file)));
+        let (denied, warnings): (Vec<Diagnostic>, Vec<Diagnostic>) = self.lints.report(self.analyzer.get_warnings())
+            .into_iter()
+            .partition(Diagnostic::is_error);
+        errors.extend(denied);
+        self.warnings.extend(warnings);
         
         if errors.is_empty() {
             self.executor.execute_statement(&ast);
-            errors.extend(self.executor.get_errors().iter().map(|e| e.to_string()));
+            errors.extend(self.executor.get_errors().iter().map(|e| e.to_diagnostic(file)));
         }
 
-        Ok(errors)
+        Ok(self.render(&errors))
     }
 
     /// Checks if a token indicates an incomplete statement.
@@ -264,6 +532,8 @@ impl Significance {
         matches!(token, 
             Token::Plus | Token::Minus | Token::Multiply | Token::Divide | 
             Token::Modulus | Token::Power | Token::Root |
+            Token::Less | Token::LessEqual | Token::Greater | Token::GreaterEqual |
+            Token::Equal | Token::NotEqual | Token::And | Token::Or | Token::Not |
             Token::Assign | Token::Colon |
             Token::LeftParen | Token::LeftBrace |
             Token::Comma
@@ -271,20 +541,11 @@ impl Significance {
     }
 
 
-    /// Parses and executes a complete program from a source file.
+    /// Parses and executes a complete program from a source file using this interpreter.
     ///
-    /// Reads a `.sig` file, processes it through the complete interpretation pipeline,
-    /// and optionally writes the AST to a JSON file for debugging. This is the batch
-    /// execution mode for running complete programs.
-    ///
-    /// # Pipeline Steps
-    ///
-    /// 1. Read source file to string
-    /// 2. Tokenize the complete input
-    /// 3. Parse tokens into AST
-    /// 4. Write AST to `ast.json` (for debugging/inspection)
-    /// 5. Perform semantic analysis
-    /// 6. Execute the validated program
+    /// Identical to `parse_file` except that the interpreter's configuration (such as
+    /// the propagation order and numeric backend) is used and its state is kept after the run.
+    /// If an AST output file is set (`set_ast_output`), the parsed AST is written to it first.
     ///
     /// # Arguments
     ///
@@ -293,59 +554,419 @@ impl Significance {
     /// # Returns
     ///
     /// * `Ok(0)` - Program executed successfully
-    /// * `Err(String)` - Error message describing what went wrong
+    /// * `Err(String)` - Error message describing what went wrong, including failing
+    ///   to write the AST output file
+    pub fn run_file(&mut self, filename: &str) -> Result<i32, String> {
+        self.warnings.clear();
+        let path = Path::new(filename);
+        let (ast, file, syntax_errors) = self.read_program(path)?;
+
+        if let Some(output) = &self.ast_output {
+            write_ast_to_file(&ast, output)
+                .map_err(|e| format!("error: Failed to write AST to file: {}", e))?;
+        }
+
+        self.import_stack.push(fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf()));
+        let result = self.run_program(&ast, Some(path), file, syntax_errors);
+        self.import_stack.pop();
+        result.map_err(|errors| self.render(&errors).join("\n"))?;
+        
+        Ok(0) // placeholder return value
+    }
+
+    /// Checks a source text without printing anything, returning its diagnostics.
     ///
-    /// # Errors
+    /// The text goes through the same pipeline as `run_file` (including imports and
+    /// execution, so runtime errors are found and variable values are available through
+    /// `get_var` afterward), but is not read from disk, and expression results and
+    /// `budget` reports are not printed.
     ///
-    /// Returns an error for:
-    /// - File I/O errors (file not found, permission denied, etc.)
-    /// - Tokenization errors (invalid characters, malformed numbers)
-    /// - Parse errors (syntax violations)
-    /// - AST write errors (cannot create `ast.json`)
+    /// # Arguments
     ///
-    /// # Side Effects
+    /// * `name` - Name of the text shown in diagnostics, typically its path
+    /// * `text` - The complete source text
+    /// * `path` - File the text belongs to, which imports are resolved relative to
     ///
-    /// - Creates/overwrites `ast.json` in the current directory with the parsed AST
-    /// - Prints output from expression statements to stdout
-    /// - May print error messages if semantic or runtime errors occur
+    /// # Returns
     ///
-    /// # Note
+    /// * `Program` - Every statement that parsed
+    /// * `FileId` - Id of `text` in `sources()`
+    /// * `Vec<Diagnostic>` - Every error, then every warning, including those inside
+    ///   imported files (whose spans are in those files)
+    pub fn check(&mut self, name: &str, text: &str, path: Option<&Path>) -> (Program, FileId, Vec<Diagnostic>) {
+        let quiet = self.executor.is_quiet();
+        self.executor.set_quiet(true);
+        let result = self.run_source(name, text, path);
+        self.executor.set_quiet(quiet);
+        result
+    }
+
+    /// Runs a source text like `check`, but keeps what the program prints.
     ///
-    /// Unlike `parse_repl`, this method does not maintain state. Each file is executed
-    /// in a fresh interpreter instance. Runtime and semantic errors are not currently
-    /// surfaced in the return value (only parse errors are).
+    /// Used by the golden test runner, which compares both the output and the
+    /// diagnostics of a program with its expectations.
     ///
-    /// # Example
+    /// # Arguments
     ///
-    /// ```ignore
-    /// // program.sig:
-    /// // {x : real}
-    /// // {y : real}
-    /// // x := 12.3 +/- 0.5
-    /// // y := 2.6 +/- 0.2
-    /// // x + y
+    /// * `name` - Name of the text shown in diagnostics, typically its path
+    /// * `text` - The complete source text
+    /// * `path` - File the text belongs to, which imports are resolved relative to
     ///
-    /// Significance::parse_file("program.sig")?;
-    /// // Prints: 14.9 ± 0.5385164807134504
-    /// // Creates: ast.json
-    /// ```
-    pub fn parse_file(filename: &str) -> Result<i32, String> {
-        let contents = fs::read_to_string(filename)
-            .map_err(|e| format!("Failed to read file '{}': {}", filename, e))?;
-        
-        let mut tokenizer = Tokenizer::new(contents.as_str());
-        let tokens = tokenizer.tokenize()?;
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The lines printed by expression statements and `budget` reports
+    /// * `Vec<Diagnostic>` - Every error followed by every lint warning, as from `check`
+    pub fn capture(&mut self, name: &str, text: &str, path: Option<&Path>) -> (Vec<String>, Vec<Diagnostic>) {
+        self.set_capture(true);
+        let (_, _, diagnostics) = self.run_source(name, text, path);
+        let output = self.take_output();
+        self.set_capture(false);
+        (output, diagnostics)
+    }
 
-        let ast = AstParser::new().parse_program(tokens)
-            .map_err(|e| format!("Failed to parse file '{}': {}", filename, e))?;
+    /// Loads and analyzes a source text without running it, so that it can be run one
+    /// statement at a time with `execute` (as the debugger does).
+    ///
+    /// The imports are run, as analysis needs the variables they bring in.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the text shown in diagnostics, typically its path
+    /// * `text` - The complete source text
+    /// * `path` - File the text belongs to, which imports are resolved relative to
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Program, FileId))` - The program to run, and the id of `text` in `sources()`
+    /// * `Err(Vec<Diagnostic>)` - The errors that keep the program from running
+    ///
+    /// Either way, the lint warnings are available from `warnings`.
+    pub fn prepare(&mut self, name: &str, text: &str, path: Option<&Path>) -> Result<(Program, FileId), Vec<Diagnostic>> {
+        self.warnings.clear();
+        let (ast, file, syntax_errors) = self.load(name, text);
 
-        write_ast_to_file(&ast, "ast.json")
-            .map_err(|e| format!("Failed to write AST to file: {}", e))?;
+        if let Some(path) = path {
+            self.import_stack.push(fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf()));
+        }
+        let result = self.analyze_program(&ast, path, file, syntax_errors);
+        if path.is_some() {
+            self.import_stack.pop();
+        }
 
-        SemanticAnalyzer::new().import_standard_library().analyze_program(&ast);
-        Executor::new().execute_program(&ast);
-        
-        Ok(0) // placeholder return value
+        result.map(|_| (ast, file))
+    }
+
+    /// Runs one statement of a program loaded with `prepare`.
+    ///
+    /// # Returns
+    ///
+    /// The runtime errors of the statement
+    pub fn execute(&mut self, statement: &Statement, file: FileId) -> Vec<Diagnostic> {
+        self.executor.clear_errors();
+        self.executor.execute_statement(statement);
+        self.executor.get_errors().iter().map(|e| e.to_diagnostic(file)).collect()
+    }
+
+    /// Starts (or stops) keeping the lines the program prints instead of printing them.
+    pub fn set_capture(&mut self, capture: bool) {
+        self.executor.set_capture(capture);
+    }
+
+    /// Returns the lines kept since output capture started (or was last taken).
+    pub fn take_output(&mut self) -> Vec<String> {
+        self.executor.take_output()
+    }
+
+    /// Turns the executor's trace mode on or off (see `Executor::set_trace`).
+    pub fn set_trace(&mut self, trace: bool) {
+        self.executor.set_trace(trace);
+    }
+
+    /// Returns the sub-expressions evaluated since trace mode was turned on (or the
+    /// trace was last taken).
+    pub fn take_trace(&mut self) -> Vec<TraceEntry<N>> {
+        self.executor.take_trace()
+    }
+
+    /// Iterates over every runtime variable, including the built-in constants.
+    pub fn variables(&self) -> impl Iterator<Item = (&str, &VarRunTime<N>)> {
+        self.executor.variables()
+    }
+
+    /// Evaluates an expression against the current variables, without printing it.
+    ///
+    /// Runtime errors it reports are discarded (along with any earlier ones kept by the
+    /// executor), so it is meant for inspecting a program after it has run, as the graph
+    /// export does.
+    pub fn evaluate(&mut self, expression: &Expression) -> N {
+        let quiet = self.executor.is_quiet();
+        self.executor.set_quiet(true);
+        let value = self.executor.evaluate_expression(expression);
+        self.executor.set_quiet(quiet);
+        self.executor.clear_errors();
+        value
+    }
+
+    /// Returns the error budget of an expression against the current variables.
+    pub fn error_budget(&self, expression: &Expression) -> ErrorBudget {
+        self.executor.error_budget(expression)
+    }
+
+    /// Returns the symbolic uncertainty formula of an expression against the current variables.
+    pub fn formula(&self, expression: &Expression) -> Formula {
+        self.executor.formula(expression)
+    }
+
+    /// Loads and runs a source text, returning its diagnostics (see `check`).
+    fn run_source(&mut self, name: &str, text: &str, path: Option<&Path>) -> (Program, FileId, Vec<Diagnostic>) {
+        self.warnings.clear();
+        let (ast, file, syntax_errors) = self.load(name, text);
+
+        if let Some(path) = path {
+            self.import_stack.push(fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf()));
+        }
+        let result = self.run_program(&ast, path, file, syntax_errors);
+        if path.is_some() {
+            self.import_stack.pop();
+        }
+
+        let mut diagnostics = result.err().unwrap_or_default();
+        diagnostics.extend(self.warnings.iter().cloned());
+        (ast, file, diagnostics)
+    }
+
+    /// Renders diagnostics against the source map, one string each.
+    fn render(&self, diagnostics: &[Diagnostic]) -> Vec<String> {
+        diagnostics.iter().map(|d| d.render(&self.sources)).collect()
+    }
+
+    /// Reads a source file and loads it (see `load`).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Program, FileId, Vec<Diagnostic>))` - The statements that parsed, the id of
+    ///   the source text, and every tokenizer or syntax error
+    /// * `Err(String)` - The file could not be read
+    fn read_program(&mut self, path: &Path) -> Result<(Program, FileId, Vec<Diagnostic>), String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("error: Failed to read file '{}': {}", path.display(), e))?;
+        Ok(self.load(&path.display().to_string(), &contents))
+    }
+
+    /// Registers a source text in the source map, then tokenizes and parses it.
+    ///
+    /// The tokenizer and parser both recover from errors, so the program may be partial.
+    ///
+    /// # Returns
+    ///
+    /// The statements that parsed, the id of the source text, and every tokenizer or
+    /// syntax error, in source order
+    fn load(&mut self, name: &str, text: &str) -> (Program, FileId, Vec<Diagnostic>) {
+        let file = self.sources.add(name, text);
+
+        let (tokens, lexical_errors) = Tokenizer::with_file(text, file).tokenize_recovering();
+
+        let (ast, parse_errors) = self.parser.parse_program_recovering(tokens);
+        let mut errors: Vec<Diagnostic> = lexical_errors.into_iter()
+            .chain(parse_errors.iter().map(|e| e.to_diagnostic()))
+            .collect();
+        errors.sort_by_key(|e| (e.span.start.line, e.span.start.column));
+        (ast, file, errors)
+    }
+
+    /// Analyzes and executes a parsed program, resolving its imports in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast` - The parsed program
+    /// * `path` - The program's source file, which imports are resolved relative to
+    ///   (the working directory if `None`)
+    /// * `file` - Id of the program's source text in the source map
+    /// * `errors` - Tokenizer and syntax errors found while loading the program
+    ///
+    /// Every statement is analyzed even if the program had syntax errors or an import
+    /// failed, so all errors are reported in one pass. Expression statements of an
+    /// imported file are skipped, so importing never prints anything, and an imported
+    /// file is not linted.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The program ran
+    /// * `Err(Vec<Diagnostic>)` - Syntax errors, failed imports, semantic errors or denied
+    ///   lints were found (the program is not executed), or runtime errors occurred
+    ///
+    /// Either way, the reported lint warnings are added to `warnings`.
+    fn run_program(&mut self, ast: &Program, path: Option<&Path>, file: FileId, errors: Vec<Diagnostic>) -> Result<(), Vec<Diagnostic>> {
+        self.analyze_program(ast, path, file, errors)?;
+
+        if self.is_module {
+            for statement in ast.statements.iter().filter(|s| !matches!(s, Statement::Expression(_))) {
+                self.executor.execute_statement(statement);
+            }
+        } else {
+            self.executor.execute_program(ast);
+        }
+
+        let runtime_errors: Vec<Diagnostic> = self.executor.get_errors().iter()
+            .map(|e| e.to_diagnostic(file))
+            .collect();
+        match runtime_errors.is_empty() {
+            true => Ok(()),
+            false => Err(runtime_errors),
+        }
+    }
+
+    /// Analyzes a parsed program, resolving its imports in order (see `run_program`).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The program can run
+    /// * `Err(Vec<Diagnostic>)` - Syntax errors, failed imports, semantic errors or denied
+    ///   lints were found
+    ///
+    /// Either way, the reported lint warnings are added to `warnings`.
+    fn analyze_program(&mut self, ast: &Program, path: Option<&Path>, file: FileId, mut errors: Vec<Diagnostic>) -> Result<(), Vec<Diagnostic>> {
+        for statement in &ast.statements {
+            match statement {
+                Statement::Import { path: import, names, pos, span } => {
+                    if let Err(e) = self.import(import, names, pos, span, path) {
+                        errors.extend(e);
+                    }
+                }
+                _ => self.analyzer.analyze_statement(statement),
+            }
+        }
+
+        errors.extend(self.analyzer.get_errors().iter().map(|e| e.to_diagnostic(file)));
+        if !self.is_module {
+            let warnings = self.lint(file, &mut errors);
+            self.warnings.extend(warnings);
+        }
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+
+    /// Reports the analyzer's lint warnings for a whole program, once every statement
+    /// has been analyzed.
+    ///
+    /// The levels are this interpreter's, overridden by the directives in the program's
+    /// source text, and warnings are sorted by position. Unused variables are only checked for if there are no `errors`, as
+    /// a statement that failed would otherwise leave its variable unassigned. Denied
+    /// lints are moved to `errors`.
+    ///
+    /// # Returns
+    ///
+    /// The lint warnings that are reported, including any malformed directives
+    fn lint(&mut self, file: FileId, errors: &mut Vec<Diagnostic>) -> Vec<Diagnostic> {
+        if errors.is_empty() {
+            self.analyzer.check_unused_variables();
+        }
+
+        let mut lints = self.lints.clone();
+        let tokens = self.sources.text(file).map(|text| Tokenizer::with_file(text, file).tokenize_recovering().0);
+        let mut warnings = lints.apply_directives(&tokens.unwrap_or_default());
+        warnings.extend(lints.report(self.analyzer.get_warnings()));
+        warnings.sort_by_key(|warning| (warning.span.start.line, warning.span.start.column));
+
+        let (denied, warnings): (Vec<Diagnostic>, Vec<Diagnostic>) = warnings.into_iter().partition(Diagnostic::is_error);
+        errors.extend(denied);
+        warnings
+    }
+
+    /// Runs an imported file and merges the variables it assigns into this interpreter.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path as written in the `import` statement
+    /// * `names` - Namespace or selected names to import under
+    /// * `pos` - Source position of the `import` statement
+    /// * `span` - Source text of the `import` statement
+    /// * `importer` - File containing the `import`, or `None` in the REPL
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The file ran and its variables were merged (name conflicts are
+    ///   reported as semantic errors)
+    /// * `Err(Vec<Diagnostic>)` - The file could not be run, is part of an import cycle,
+    ///   or does not assign a selected name; errors inside the imported file are located
+    ///   in that file
+    fn import(&mut self, path: &str, names: &ImportNames, pos: &Position, span: &Span, importer: Option<&Path>) -> Result<(), Vec<Diagnostic>> {
+        let resolved = match importer.and_then(Path::parent) {
+            Some(directory) => directory.join(path),
+            None => PathBuf::from(path),
+        };
+        let error = |message: String| {
+            vec![Diagnostic::new(Stage::Semantic, format!("Cannot import '{}': {}", path, message), *span)]
+        };
+
+        let canonical = fs::canonicalize(&resolved).map_err(|e| error(e.to_string()))?;
+        if let Some(start) = self.import_stack.iter().position(|file| *file == canonical) {
+            let cycle: Vec<String> = self.import_stack[start..].iter()
+                .chain(std::iter::once(&canonical))
+                .map(|file| file.display().to_string())
+                .collect();
+            return Err(error(format!("import cycle {}", cycle.join(" -> "))));
+        }
+
+        let mut module = Self::with_backend();
+        module.set_propagation_order(self.executor.get_propagation_order());
+        module.set_constant_folding(self.is_constant_folding());
+        module.import_stack = self.import_stack.clone();
+        module.import_stack.push(canonical);
+        module.is_module = true;
+        module.executor.set_quiet(self.executor.is_quiet());
+        module.sources = std::mem::take(&mut self.sources);
+
+        let result = match module.read_program(&resolved) {
+            Ok((ast, file, syntax_errors)) => module.run_program(&ast, Some(&resolved), file, syntax_errors).map(|_| ast),
+            Err(message) => Err(error(message)),
+        };
+        self.sources = std::mem::take(&mut module.sources);
+        self.warnings.append(&mut module.warnings);
+        let ast = result?;
+
+        // Only variables the file itself assigns are exported
+        let exported: Vec<&String> = ast.statements.iter()
+            .filter_map(|statement| match statement {
+                Statement::VarDeclaration { name, .. } => Some(name),
+                _ => None,
+            })
+            .filter(|name| module.analyzer.get_symbol(name).is_some_and(|info| info.is_assigned()))
+            .collect();
+
+        let renamed: Vec<(String, &String)> = match names {
+            ImportNames::Namespace(namespace) => exported.iter()
+                .map(|name| (format!("{}.{}", namespace, name), *name))
+                .collect(),
+            ImportNames::Selected(selected) => selected.iter()
+                .map(|name| match exported.iter().find(|exported| *exported == &name) {
+                    Some(exported) => Ok((name.clone(), *exported)),
+                    None => Err(error(format!("no assigned variable '{}'", name))),
+                })
+                .collect::<Result<_, _>>()?,
+        };
+
+        let mut symbols = HashMap::new();
+        let mut values = HashMap::new();
+        for (name, original) in renamed {
+            if let (Some(info), Some(var)) = (module.analyzer.get_symbol(original), module.executor.get_var(original)) {
+                symbols.insert(name.clone(), info.clone());
+                values.insert(name, var);
+            }
+        }
+        self.analyzer.import_variables(symbols, pos);
+        self.executor.import_variables(values);
+        Ok(())
+    }
+
+    /// Returns the rendered semantic warnings from the last `run_file` or `parse_repl` call.
+    ///
+    /// Warnings never stop execution, so they are reported separately from errors
+    /// and left to the caller to display.
+    pub fn warnings(&self) -> Vec<String> {
+        self.render(&self.warnings)
     }
 
 }
@@ -379,7 +1000,7 @@ impl Significance {
 /// - All source position information for debugging
 ///
 /// ```
-fn write_ast_to_file(program: &Program, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+fn write_ast_to_file(program: &Program, filename: &Path) -> Result<(), Box<dyn std::error::Error>> {
     let file = File::create(filename)?;
     serde_json::to_writer_pretty(file, program)?;
     Ok(())
@@ -0,0 +1,538 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! A Language Server Protocol server for `.sig` files.
+//!
+//! The server speaks JSON-RPC over a byte stream (stdin/stdout for the
+//! `significance-lsp` binary), with each message framed by a `Content-Length` header.
+//! Every time a document is opened or changed it is checked with `Significance::check`,
+//! which runs the whole pipeline (including imports and execution) without printing.
+//! The results back the supported requests:
+//!
+//! - **Diagnostics**: tokenizer, syntax, semantic and runtime errors and lint warnings,
+//!   published on open and change (errors inside an imported file are published for that file)
+//! - **Hover**: the declared type of an identifier and its evaluated value
+//! - **Go to definition**: the `{x : real}` declaration, or the `import` of an imported name
+//! - **Find references**: every use of an identifier in the document
+//! - **Completion**: declared variables, standard library functions and constants
+//!
+//! Documents are synchronized in full. LSP positions are 0-based and count UTF-16 code
+//! units; they are converted to and from the interpreter's 1-based character columns.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Value};
+
+use crate::significance::ast_parser::{ImportNames, Program, Statement, VarType};
+use crate::significance::constants::lookup_constant;
+use crate::significance::diagnostic::{Diagnostic, Severity};
+use crate::significance::language_parser::Significance;
+use crate::significance::source_map::{SourceMap, Span};
+use crate::significance::standard_lib::{lookup_std_function, function_arity, INTRINSICS};
+use crate::significance::tokenizer::{Position, Token, TokenWithPos, Tokenizer};
+
+/// JSON-RPC error code for a message body that is not valid JSON.
+const PARSE_ERROR: i64 = -32700;
+
+/// JSON-RPC error code for a request whose method the server does not implement.
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// JSON-RPC error code for a request with missing or malformed parameters.
+const INVALID_PARAMS: i64 = -32602;
+
+/// LSP diagnostic severities.
+const SEVERITY_ERROR: u32 = 1;
+const SEVERITY_WARNING: u32 = 2;
+
+/// LSP completion item kinds.
+const COMPLETION_FUNCTION: u32 = 3;
+const COMPLETION_VARIABLE: u32 = 6;
+const COMPLETION_CONSTANT: u32 = 21;
+
+/// An open document and the result of checking it.
+struct Document {
+    /// Full text, as last sent by the client
+    text: String,
+
+    /// Tokens of the text (lines with lexical errors have none)
+    tokens: Vec<TokenWithPos>,
+
+    /// Statements that parsed
+    program: Program,
+
+    /// Interpreter the document was checked with, holding its symbols and values
+    interpreter: Significance,
+
+    /// Published diagnostics by document URI: this document's, and those of any
+    /// imported file with errors
+    diagnostics: HashMap<String, Vec<Value>>,
+}
+
+/// Language server state: the open documents.
+///
+/// `handle` processes one decoded message at a time, so the server can be driven by
+/// `serve` over any byte stream or directly in tests.
+#[derive(Default)]
+pub struct LanguageServer {
+    /// Open documents by URI
+    documents: HashMap<String, Document>,
+
+    /// Whether the client has sent `exit`
+    exited: bool,
+}
+
+impl LanguageServer {
+    /// Creates a server with no open documents.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the client has sent `exit`, after which no more messages are read.
+    pub fn has_exited(&self) -> bool {
+        self.exited
+    }
+
+    /// Handles one JSON-RPC message.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - A request (with an `id`) or notification
+    ///
+    /// # Returns
+    ///
+    /// The messages to send back: the response to a request, and any notifications
+    /// (such as `textDocument/publishDiagnostics`)
+    pub fn handle(&mut self, message: &Value) -> Vec<Value> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+        let id = message.get("id").cloned();
+
+        let result = match method {
+            "initialize" => Some(Ok(Self::capabilities())),
+            "shutdown" => Some(Ok(Value::Null)),
+            "textDocument/hover" => Some(self.hover(params)),
+            "textDocument/definition" => Some(self.definition(params)),
+            "textDocument/references" => Some(self.references(params)),
+            "textDocument/completion" => Some(self.completion(params)),
+            "textDocument/didOpen" => {
+                let document = &params["textDocument"];
+                return match (document["uri"].as_str(), document["text"].as_str()) {
+                    (Some(uri), Some(text)) => self.update(uri, text),
+                    _ => Vec::new(),
+                };
+            }
+            "textDocument/didChange" => {
+                let uri = params["textDocument"]["uri"].as_str();
+                let text = params["contentChanges"].as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str());
+                return match (uri, text) {
+                    (Some(uri), Some(text)) => self.update(uri, text),
+                    _ => Vec::new(),
+                };
+            }
+            "textDocument/didClose" => {
+                return match params["textDocument"]["uri"].as_str() {
+                    Some(uri) => self.close(uri),
+                    None => Vec::new(),
+                };
+            }
+            "exit" => {
+                self.exited = true;
+                return Vec::new();
+            }
+            _ => None,
+        };
+
+        // Notifications get no response
+        let Some(id) = id else { return Vec::new() };
+        let response = match result {
+            Some(Ok(result)) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Some(Err(message)) => Self::error_response(id, INVALID_PARAMS, &message),
+            None => Self::error_response(id, METHOD_NOT_FOUND, &format!("Unknown method '{}'", method)),
+        };
+        vec![response]
+    }
+
+    /// Capabilities announced in response to `initialize`.
+    fn capabilities() -> Value {
+        json!({
+            "capabilities": {
+                "textDocumentSync": 1,
+                "hoverProvider": true,
+                "definitionProvider": true,
+                "referencesProvider": true,
+                "completionProvider": {},
+            },
+            "serverInfo": { "name": "significance-lsp" },
+        })
+    }
+
+    /// Builds a JSON-RPC error response.
+    fn error_response(id: Value, code: i64, message: &str) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+    }
+
+    /// Re-checks a document with new text and publishes its diagnostics.
+    ///
+    /// Files that had diagnostics from the previous check but have none now are
+    /// published with an empty list, clearing them in the client.
+    fn update(&mut self, uri: &str, text: &str) -> Vec<Value> {
+        let document = Self::check(uri, text);
+        let mut stale: Vec<String> = self.documents.remove(uri)
+            .map(|previous| previous.diagnostics.into_keys().collect())
+            .unwrap_or_default();
+        stale.retain(|file| !document.diagnostics.contains_key(file));
+
+        let mut files: Vec<(&String, &Vec<Value>)> = document.diagnostics.iter().collect();
+        files.sort_by_key(|(file, _)| *file != uri);
+        let mut notifications: Vec<Value> = files.into_iter()
+            .map(|(file, diagnostics)| Self::publish(file, diagnostics.clone()))
+            .collect();
+        notifications.extend(stale.iter().map(|file| Self::publish(file, Vec::new())));
+
+        self.documents.insert(uri.to_string(), document);
+        notifications
+    }
+
+    /// Forgets a closed document and clears its diagnostics.
+    fn close(&mut self, uri: &str) -> Vec<Value> {
+        match self.documents.remove(uri) {
+            Some(document) => document.diagnostics.into_keys()
+                .map(|file| Self::publish(&file, Vec::new()))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Builds a `textDocument/publishDiagnostics` notification.
+    fn publish(uri: &str, diagnostics: Vec<Value>) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        })
+    }
+
+    /// Checks a document's text in a fresh interpreter.
+    fn check(uri: &str, text: &str) -> Document {
+        let path = uri_to_path(uri);
+        let name = path.as_ref().map_or(uri.to_string(), |path| path.display().to_string());
+        let (tokens, _) = Tokenizer::new(text).tokenize_recovering();
+
+        let mut interpreter = Significance::new();
+        let (program, file, found) = interpreter.check(&name, text, path.as_deref());
+
+        let mut diagnostics: HashMap<String, Vec<Value>> = HashMap::new();
+        diagnostics.insert(uri.to_string(), Vec::new());
+        let sources = interpreter.sources();
+        for error in &found {
+            let target = match error.span.file == file {
+                true => uri.to_string(),
+                false => sources.name(error.span.file).map_or(uri.to_string(), |name| path_to_uri(Path::new(name))),
+            };
+            diagnostics.entry(target).or_default().push(to_lsp_diagnostic(error, sources));
+        }
+
+        Document { text: text.to_string(), tokens, program, interpreter, diagnostics }
+    }
+
+    /// Finds the open document and identifier token at a request's position.
+    fn identifier_at<'a>(&'a self, params: &Value) -> Result<Option<(&'a Document, &'a TokenWithPos, &'a str)>, String> {
+        let uri = params["textDocument"]["uri"].as_str().ok_or("Missing textDocument.uri")?;
+        let Some(document) = self.documents.get(uri) else { return Ok(None) };
+        let position = from_lsp_position(&document.text, &params["position"]).ok_or("Missing position")?;
+
+        let found = document.tokens.iter().find_map(|token| match &token.token {
+            Token::Identifier(name) if contains(&token.span, position) => Some((document, token, name.as_str())),
+            _ => None,
+        });
+        Ok(found)
+    }
+
+    /// Handles `textDocument/hover`: the identifier's declared type and current value.
+    fn hover(&self, params: &Value) -> Result<Value, String> {
+        let Some((document, token, name)) = self.identifier_at(params)? else { return Ok(Value::Null) };
+        let Some(symbol) = document.interpreter.get_symbol(name) else { return Ok(Value::Null) };
+
+        let mut lines = Vec::new();
+        match (symbol.get_type(), lookup_std_function(name)) {
+            (VarType::RealFunction, Some(function)) => {
+                let parameters = ["x", "y", "z"].iter().take(function.arity).copied().collect::<Vec<_>>().join(", ");
+                lines.push(format!("```\n{}({}) : real function\n```", name, parameters));
+            }
+            (VarType::RealFunction, None) => lines.push(format!("```\n{}(x) : real function\n```", name)),
+            (VarType::Real, _) => {
+                lines.push(format!("```\n{{{} : real}}\n```", name));
+                match document.interpreter.get_var(name) {
+                    Some(var) if symbol.is_assigned() => lines.push(format!("= `{}`", var.get_value())),
+                    _ => lines.push("(not assigned)".to_string()),
+                }
+            }
+        }
+        if let Some(constant) = lookup_constant(name).filter(|_| document.interpreter.is_constant(name)) {
+            lines.push(format!("Built-in constant: {}", constant.description));
+        }
+
+        Ok(json!({
+            "contents": { "kind": "markdown", "value": lines.join("\n\n") },
+            "range": to_lsp_range(&document.text, &token.span),
+        }))
+    }
+
+    /// Handles `textDocument/definition`: the variable's declaration, or the `import`
+    /// statement that brought it in.
+    fn definition(&self, params: &Value) -> Result<Value, String> {
+        let Some((document, _, name)) = self.identifier_at(params)? else { return Ok(Value::Null) };
+        let uri = params["textDocument"]["uri"].clone();
+
+        let declaration = declarations(&document.tokens).find(|token| matches!(&token.token, Token::Identifier(n) if n == name));
+        if let Some(token) = declaration {
+            return Ok(json!({ "uri": uri, "range": to_lsp_range(&document.text, &token.span) }));
+        }
+
+        let import = document.program.statements.iter().find_map(|statement| match statement {
+            Statement::Import { names: ImportNames::Selected(selected), span, .. } if selected.iter().any(|s| s == name) => Some(span),
+            Statement::Import { names: ImportNames::Namespace(namespace), span, .. }
+                if name.strip_prefix(namespace.as_str()).is_some_and(|rest| rest.starts_with('.')) => Some(span),
+            _ => None,
+        });
+        Ok(match import {
+            Some(span) => json!({ "uri": uri, "range": to_lsp_range(&document.text, span) }),
+            None => Value::Null,
+        })
+    }
+
+    /// Handles `textDocument/references`: every occurrence of the identifier in the document.
+    fn references(&self, params: &Value) -> Result<Value, String> {
+        let Some((document, _, name)) = self.identifier_at(params)? else { return Ok(Value::Null) };
+        let uri = params["textDocument"]["uri"].clone();
+        let include_declaration = params["context"]["includeDeclaration"].as_bool().unwrap_or(true);
+
+        let excluded: Vec<&Span> = match include_declaration {
+            true => Vec::new(),
+            false => declarations(&document.tokens).map(|token| &token.span).collect(),
+        };
+        let locations: Vec<Value> = document.tokens.iter()
+            .filter(|token| matches!(&token.token, Token::Identifier(n) if n == name))
+            .filter(|token| !excluded.contains(&&token.span))
+            .map(|token| json!({ "uri": uri, "range": to_lsp_range(&document.text, &token.span) }))
+            .collect();
+        Ok(Value::Array(locations))
+    }
+
+    /// Handles `textDocument/completion`: every declared variable, standard library
+    /// function and constant.
+    fn completion(&self, params: &Value) -> Result<Value, String> {
+        let uri = params["textDocument"]["uri"].as_str().ok_or("Missing textDocument.uri")?;
+        let fallback;
+        let interpreter = match self.documents.get(uri) {
+            Some(document) => &document.interpreter,
+            None => {
+                fallback = Significance::new();
+                &fallback
+            }
+        };
+
+        let mut items: Vec<Value> = interpreter.symbols()
+            .map(|(name, symbol)| {
+                let (kind, detail) = match (symbol.get_type(), lookup_constant(name).filter(|_| interpreter.is_constant(name)), lookup_std_function(name)) {
+                    (VarType::RealFunction, _, Some(function)) => (COMPLETION_FUNCTION, format!("function of {} argument(s)", function.arity)),
+                    (VarType::RealFunction, _, None) if INTRINSICS.contains(&name) => (COMPLETION_FUNCTION, format!("function of {} argument(s)", function_arity(name).unwrap_or(1))),
+                    (VarType::RealFunction, _, None) => (COMPLETION_FUNCTION, "function".to_string()),
+                    (VarType::Real, Some(constant), _) => (COMPLETION_CONSTANT, constant.description.to_string()),
+                    (VarType::Real, None, _) => (COMPLETION_VARIABLE, "real".to_string()),
+                };
+                json!({ "label": name, "kind": kind, "detail": detail })
+            })
+            .collect();
+        items.sort_by(|a, b| a["label"].as_str().cmp(&b["label"].as_str()));
+        Ok(Value::Array(items))
+    }
+}
+
+/// Runs a language server over a byte stream until the client sends `exit` or closes
+/// the stream.
+///
+/// # Arguments
+///
+/// * `reader` - Incoming messages (stdin for the `significance-lsp` binary)
+/// * `writer` - Outgoing messages (stdout for the `significance-lsp` binary)
+///
+/// # Errors
+///
+/// Returns an error if reading or writing fails, or a message is not valid JSON-RPC framing.
+/// A message whose body is not valid JSON is answered with a parse error, and the server
+/// keeps serving.
+pub fn serve(mut reader: impl BufRead, mut writer: impl Write) -> io::Result<()> {
+    let mut server = LanguageServer::new();
+    while let Some(message) = read_message(&mut reader)? {
+        let message = match message {
+            Ok(message) => message,
+            Err(e) => {
+                let reply = LanguageServer::error_response(Value::Null, PARSE_ERROR, &format!("Parse error: {}", e));
+                write_message(&mut writer, &reply)?;
+                continue;
+            }
+        };
+        for reply in server.handle(&message) {
+            write_message(&mut writer, &reply)?;
+        }
+        if server.has_exited() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Reads one `Content-Length`-framed JSON-RPC message.
+///
+/// # Returns
+///
+/// * `Ok(Some(Ok(Value)))` - The decoded message
+/// * `Ok(Some(Err(serde_json::Error)))` - A complete message whose body is not valid JSON;
+///   the stream is positioned at the next message
+/// * `Ok(None)` - The stream ended before another message started
+/// * `Err(io::Error)` - The header is malformed, or reading failed
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<serde_json::Result<Value>>> {
+    let mut length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return match length {
+                None => Ok(None),
+                Some(_) => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "stream ended inside a message header")),
+            };
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            if length.is_some() {
+                break;
+            }
+            continue;
+        }
+        if let Some((header, value)) = line.split_once(':') {
+            if header.eq_ignore_ascii_case("Content-Length") {
+                let value = value.trim().parse::<usize>()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("invalid Content-Length: {}", e)))?;
+                length = Some(value);
+            }
+        }
+    }
+
+    let mut body = vec![0; length.unwrap_or_default()];
+    reader.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body)))
+}
+
+/// Writes one JSON-RPC message with a `Content-Length` header.
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    writer.flush()
+}
+
+/// Iterates over the identifier tokens that name a variable in a `{x : real}` declaration.
+fn declarations(tokens: &[TokenWithPos]) -> impl Iterator<Item = &TokenWithPos> {
+    tokens.windows(2)
+        .filter(|pair| pair[0].token == Token::LeftBrace && matches!(pair[1].token, Token::Identifier(_)))
+        .map(|pair| &pair[1])
+}
+
+/// Whether a position is inside a span (or just after it, where the cursor sits after
+/// typing an identifier).
+fn contains(span: &Span, position: Position) -> bool {
+    let after_start = (span.start.line, span.start.column) <= (position.line, position.column);
+    let before_end = (position.line, position.column) <= (span.end.line, span.end.column);
+    after_start && before_end
+}
+
+/// Converts an interpreter diagnostic to an LSP diagnostic.
+fn to_lsp_diagnostic(diagnostic: &Diagnostic, sources: &SourceMap) -> Value {
+    let text = sources.text(diagnostic.span.file).unwrap_or_default();
+    let severity = match diagnostic.severity {
+        Severity::Error => SEVERITY_ERROR,
+        Severity::Warning => SEVERITY_WARNING,
+    };
+    json!({
+        "range": to_lsp_range(text, &diagnostic.span),
+        "severity": severity,
+        "source": "significance",
+        "message": diagnostic.message,
+    })
+}
+
+/// Converts a span to an LSP range.
+fn to_lsp_range(text: &str, span: &Span) -> Value {
+    json!({ "start": to_lsp_position(text, span.start), "end": to_lsp_position(text, span.end) })
+}
+
+/// Converts a 1-based line and character column to an LSP position (0-based, UTF-16).
+fn to_lsp_position(text: &str, position: Position) -> Value {
+    let line = text.lines().nth(position.line.saturating_sub(1)).unwrap_or_default();
+    let character: usize = line.chars().take(position.column.saturating_sub(1)).map(char::len_utf16).sum();
+    json!({ "line": position.line.saturating_sub(1), "character": character })
+}
+
+/// Converts an LSP position (0-based, UTF-16) to a 1-based line and character column.
+fn from_lsp_position(text: &str, position: &Value) -> Option<Position> {
+    let line = position["line"].as_u64()? as usize;
+    let character = position["character"].as_u64()? as usize;
+    let line_text = text.lines().nth(line).unwrap_or_default();
+
+    let mut units = 0;
+    let column = line_text.chars()
+        .take_while(|ch| {
+            units += ch.len_utf16();
+            units <= character
+        })
+        .count();
+    Some(Position { line: line + 1, column: column + 1 })
+}
+
+/// Converts a `file://` URI to a path, decoding percent escapes.
+///
+/// # Returns
+///
+/// `None` for other schemes (such as `untitled:`), whose imports resolve relative to
+/// the working directory
+fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let encoded = uri.strip_prefix("file://")?;
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut rest = encoded.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        let escaped = match (byte, tail) {
+            (b'%', [high, low, ..]) => std::str::from_utf8(&[*high, *low]).ok().and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+            _ => None,
+        };
+        match escaped {
+            Some(decoded) => {
+                bytes.push(decoded);
+                rest = &tail[2..];
+            }
+            None => {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+    }
+    String::from_utf8(bytes).ok().map(PathBuf::from)
+}
+
+/// Converts a path to a `file://` URI, percent-encoding reserved characters.
+fn path_to_uri(path: &Path) -> String {
+    let mut uri = String::from("file://");
+    for byte in path.display().to_string().bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'.' | b'_' | b'~' => uri.push(byte as char),
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    uri
+}
@@ -0,0 +1,216 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Lints: warnings about programs that are valid but probably wrong.
+//!
+//! The semantic analyzer records a `SemanticWarning` for every suspicious construct it
+//! finds, tagged with the `Lint` that found it. A `LintConfig` then decides, per lint,
+//! whether the warning is dropped (`allow`), reported (`warn`, the default) or turned
+//! into an error that stops the program from running (`deny`).
+//!
+//! Levels are set on the command line (`--deny=unused_variable`) and overridden by
+//! comment directives anywhere in a file, which apply to the whole file:
+//!
+//! ```text
+//! # lint: allow(excess_precision) deny(division_by_zero, unused_variable)
+//! ```
+
+use std::collections::HashMap;
+
+use crate::significance::diagnostic::{Diagnostic, Stage};
+use crate::significance::semantic_analyzer::SemanticWarning;
+use crate::significance::tokenizer::{Token, TokenWithPos};
+
+/// A check for a suspicious construct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// A variable is declared but never assigned: `{x : real}` alone
+    UnassignedVariable,
+
+    /// A variable is assigned but never read afterward
+    UnusedVariable,
+
+    /// A literal's uncertainty is larger than its value: `1 +/- 2`
+    LargeUncertainty,
+
+    /// A `+/-` operand that is zero or negative: `12.3 +/- 0`, `x +/- (-0.5)`
+    ///
+    /// A negative uncertainty is silently made positive, and a zero one does nothing.
+    NonPositiveUncertainty,
+
+    /// A divisor of `/` or `%` that is exactly zero: `x / (2 - 2)`
+    DivisionByZero,
+
+    /// A literal with digits below what its uncertainty can resolve: `12.3456 +/- 0.5`
+    ///
+    /// The value should stop at the place of the uncertainty's second significant digit.
+    ExcessPrecision,
+
+    /// A one-letter name that reads a built-in constant rather than a variable: `2 * c`
+    ///
+    /// `e`, `c`, `h`, `R`, `F` and `G` are easy to use by accident for an undeclared variable.
+    OneLetterConstant,
+}
+
+/// Every lint, in the order they are documented.
+pub const LINTS: [Lint; 7] = [
+    Lint::UnassignedVariable,
+    Lint::UnusedVariable,
+    Lint::LargeUncertainty,
+    Lint::NonPositiveUncertainty,
+    Lint::DivisionByZero,
+    Lint::ExcessPrecision,
+    Lint::OneLetterConstant,
+];
+
+impl Lint {
+    /// Returns the name used in directives, flags and warnings.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::UnassignedVariable => "unassigned_variable",
+            Lint::UnusedVariable => "unused_variable",
+            Lint::LargeUncertainty => "large_uncertainty",
+            Lint::NonPositiveUncertainty => "nonpositive_uncertainty",
+            Lint::DivisionByZero => "division_by_zero",
+            Lint::ExcessPrecision => "excess_precision",
+            Lint::OneLetterConstant => "one_letter_constant",
+        }
+    }
+
+    /// Looks up a lint by name.
+    pub fn from_name(name: &str) -> Option<Lint> {
+        LINTS.iter().copied().find(|lint| lint.name() == name)
+    }
+}
+
+/// What happens to the warnings of a lint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LintLevel {
+    /// Not reported
+    Allow,
+
+    /// Reported as a warning; the program still runs
+    Warn,
+
+    /// Reported as an error; the program is not run
+    Deny,
+}
+
+impl LintLevel {
+    /// Looks up a level by the name used in directives (`allow`, `warn`, `deny`).
+    pub fn from_name(name: &str) -> Option<LintLevel> {
+        match name {
+            "allow" => Some(LintLevel::Allow),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            _ => None,
+        }
+    }
+}
+
+/// The level of every lint. All lints warn by default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintConfig {
+    levels: HashMap<Lint, LintLevel>,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self { levels: LINTS.iter().map(|lint| (*lint, LintLevel::Warn)).collect() }
+    }
+}
+
+impl LintConfig {
+    /// Creates a configuration in which every lint warns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the level of a lint.
+    pub fn level(&self, lint: Lint) -> LintLevel {
+        self.levels.get(&lint).copied().unwrap_or(LintLevel::Warn)
+    }
+
+    /// Sets the level of a lint.
+    pub fn set(&mut self, lint: Lint, level: LintLevel) {
+        self.levels.insert(lint, level);
+    }
+
+    /// Applies the `# lint: level(name, ...)` directives in a token stream.
+    ///
+    /// Directives are applied in order, so a later directive overrides an earlier one.
+    /// The name `all` stands for every lint.
+    ///
+    /// # Returns
+    ///
+    /// A warning for every directive that could not be understood (which is otherwise ignored)
+    pub fn apply_directives(&mut self, tokens: &[TokenWithPos]) -> Vec<Diagnostic> {
+        let mut warnings = Vec::new();
+        for token in tokens {
+            let Token::Comment(comment) = &token.token else { continue };
+            let Some(directive) = comment.trim().strip_prefix("lint:") else { continue };
+            if let Err(message) = self.apply_directive(directive) {
+                warnings.push(Diagnostic::warning(Stage::Lint, message, token.span));
+            }
+        }
+        warnings
+    }
+
+    /// Applies the body of one directive: `allow(a, b) deny(c)`.
+    fn apply_directive(&mut self, directive: &str) -> Result<(), String> {
+        let mut rest = directive.trim();
+        if rest.is_empty() {
+            return Err("Empty lint directive".to_string());
+        }
+
+        while !rest.is_empty() {
+            let (level, after) = rest.split_once('(').ok_or("Malformed lint directive: expected 'level(lint, ...)'")?;
+            let (names, after) = after.split_once(')').ok_or("Malformed lint directive: missing ')'")?;
+            let level = LintLevel::from_name(level.trim())
+                .ok_or_else(|| format!("Unknown lint level '{}' (expected allow, warn or deny)", level.trim()))?;
+            for lint in parse_lint_names(names)? {
+                self.set(lint, level);
+            }
+            rest = after.trim();
+        }
+        Ok(())
+    }
+
+    /// Converts the warnings found by the analyzer into diagnostics at their lint's level.
+    ///
+    /// Allowed warnings are dropped, and denied ones become errors. The lint's name is
+    /// appended to the message, so that it can be looked up and allowed.
+    pub fn report(&self, warnings: &[SemanticWarning]) -> Vec<Diagnostic> {
+        warnings.iter()
+            .filter_map(|warning| {
+                let message = format!("{} [{}]", warning.message, warning.lint.name());
+                match self.level(warning.lint) {
+                    LintLevel::Allow => None,
+                    LintLevel::Warn => Some(Diagnostic::warning(Stage::Lint, message, warning.span)),
+                    LintLevel::Deny => Some(Diagnostic::new(Stage::Lint, message, warning.span)),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Parses a comma separated list of lint names, where `all` stands for every lint.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Lint>)` - The lints named
+/// * `Err(String)` - A name is not a lint
+pub fn parse_lint_names(names: &str) -> Result<Vec<Lint>, String> {
+    let mut lints = Vec::new();
+    for name in names.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+        match name {
+            "all" => lints.extend(LINTS),
+            _ => lints.push(Lint::from_name(name).ok_or_else(|| format!("Unknown lint '{}'", name))?),
+        }
+    }
+    Ok(lints)
+}
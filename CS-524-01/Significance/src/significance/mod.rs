@@ -6,29 +6,117 @@
 //------------------
 
 mod numbers;
+mod interval;
+mod decimal;
 mod language_parser;
 mod tokenizer;
 mod ast_parser;
 mod semantic_analyzer;
 mod executor;
 mod standard_lib;
+mod propagation;
+mod budget;
+mod constants;
+mod source_map;
+mod diagnostic;
+mod language_server;
+mod formatter;
+mod lints;
+mod golden;
+mod bytecode;
+mod vm;
+mod optimizer;
+mod debugger;
+mod graph;
+mod symbolic;
 
 #[cfg(test)]
+#[allow(clippy::approx_constant)]
 mod tests_numbers;
 
 #[cfg(test)]
+#[allow(clippy::borrowed_box, clippy::ptr_arg, clippy::too_many_arguments)]
 mod tests_ast_parser;
 
 #[cfg(test)]
+#[allow(clippy::approx_constant, clippy::needless_borrow)]
 mod tests_executor;
 
 #[cfg(test)]
 mod tests_tokenizer;
 
+#[cfg(test)]
+mod tests_propagation;
+
+#[cfg(test)]
+mod tests_semantic_analyzer;
+
+#[cfg(test)]
+mod tests_interval;
+
+#[cfg(test)]
+mod tests_decimal;
+
+#[cfg(test)]
+mod tests_constants;
+
+#[cfg(test)]
+mod tests_language_parser;
+
+#[cfg(test)]
+mod tests_support;
+
+#[cfg(test)]
+mod tests_diagnostic;
+
+#[cfg(test)]
+mod tests_language_server;
+
+#[cfg(test)]
+mod tests_formatter;
+
+#[cfg(test)]
+mod tests_lints;
+
+#[cfg(test)]
+mod tests_golden;
+
+#[cfg(test)]
+mod tests_vm;
+
+#[cfg(test)]
+mod tests_optimizer;
+
+#[cfg(test)]
+mod tests_debugger;
+
+#[cfg(test)]
+mod tests_graph;
+
+#[cfg(test)]
+mod tests_symbolic;
+
 pub use numbers::{Number, Real, assert_real};
+pub use interval::Interval;
+pub use decimal::{Decimal, BigDecimal, PRECISION};
 pub use language_parser::Significance;
-pub use tokenizer::{Tokenizer, Token, TokenWithPos, Position};
-pub use ast_parser::{AstParser, Program, Statement, VarType, Expression, BinaryOp, UnaryOp, ParseError};
-pub use semantic_analyzer::{SemanticAnalyzer, SemanticError, VarInfo};
-pub use executor::{Executor, VarRunTime, RunTimeError};
-pub use standard_lib::std_lib_call;
\ No newline at end of file
+pub use tokenizer::{Tokenizer, Token, TokenWithPos, Position, RelativeUnit};
+pub use ast_parser::{AstParser, Program, Statement, VarType, Expression, BinaryOp, UnaryOp, ParseError, UncertaintyNotation, ImportNames};
+pub use semantic_analyzer::{SemanticAnalyzer, SemanticError, SemanticWarning, VarInfo};
+pub use executor::{Executor, VarRunTime, RunTimeError, TraceEntry, TraceOperation};
+pub use bytecode::{Compiler, Chunk, Instruction};
+pub use vm::Vm;
+pub use optimizer::{fold_program, fold_statement, fold_expression};
+pub use debugger::{Debugger, debug};
+pub use graph::{Graph, GraphNode, NodeKind};
+pub use symbolic::{formula, Formula, FormulaInput, Symbolic};
+pub use standard_lib::{std_lib_call, std_lib_call_with_order, assertion_call, lookup_std_function, function_arity, is_assertion, resolve_builtin, Builtin, StdFunction, STD_LIB, INTRINSICS};
+pub use budget::{error_budget, ErrorBudget, BudgetEntry};
+pub use constants::{lookup_constant, Constant, CONSTANTS};
+pub use source_map::{SourceMap, Span, FileId};
+pub use diagnostic::{Diagnostic, Severity, Stage};
+pub use language_server::{LanguageServer, serve, read_message, write_message};
+pub use formatter::format_source;
+pub use lints::{Lint, LintLevel, LintConfig, LINTS, parse_lint_names};
+pub use golden::{run_test, bless, read_expectations, rewrite_expectations, expected_path, lines_match, TestCase, Expectations};
+pub use propagation::{propagate, propagate_with_order, gradient, partial_derivative, second_partial_derivative, PropagationOrder};
\ No newline at end of file
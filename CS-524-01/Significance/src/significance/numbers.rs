@@ -8,12 +8,26 @@
 use std::fmt;
 use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
+use crate::significance::propagation::{propagate, propagate_with_order, PropagationOrder};
+use crate::significance::standard_lib::StdFunction;
+
 /// Trait for numbers that track significance/error
+///
+/// This is the numeric backend interface: the executor, the standard library and the
+/// `Significance` interpreter are generic over it, so a new backend (Monte Carlo samples,
+/// arbitrary precision, dual numbers, ...) only needs to implement this trait and is
+/// selected with `Executor::<N>::with_backend()` or `Significance::<N>::with_backend()`.
+/// `Real` is the default backend and `Interval` the guaranteed-bounds one.
 pub trait Number: 
     Add<Output = Self> + Sub<Output = Self> + 
     Mul<Output = Self> + Div<Output = Self> + 
     Rem<Output = Self> + Neg<Output = Self> +
     Clone + fmt::Display + Sized {
+    /// Whether exact literal subexpressions may be folded before execution (see
+    /// `optimizer.rs`). Folding computes with `Real`, so it is only sound for backends
+    /// whose arithmetic on exact values is plain `f64` arithmetic.
+    const FOLDS_CONSTANTS: bool = false;
+
     fn value(&self) -> f64;
     fn error(&self) -> f64;
     fn with_error(value: f64, error: f64) -> Self;
@@ -21,25 +35,121 @@ pub trait Number:
     fn new(value: f64) -> Self {
         Self::with_error(value, 0.0)
     }
+
+    /// Creates a number with separate upper and lower errors (`12.3(+0.4/-0.2)`).
+    ///
+    /// Backends without asymmetric errors keep the larger of the two.
+    fn with_asymmetric_error(value: f64, upper: f64, lower: f64) -> Self {
+        Self::with_error(value, upper.abs().max(lower.abs()))
+    }
+
+    /// Creates a number from a literal in the source, with its upper and lower errors.
+    ///
+    /// The `f64` arguments are the nearest doubles to the decimal text, which may not
+    /// be exact (`0.1`). `literal` is the exact source text of the value (e.g.
+    /// `6.02214076e23`), where the tokenizer kept it. Backends that keep more precision
+    /// than an `f64` parse the text, and backends with guaranteed bounds round outward;
+    /// the default is `with_asymmetric_error` on the `f64` arguments.
+    fn from_literal(_literal: Option<&str>, value: f64, upper: f64, lower: f64) -> Self {
+        Self::with_asymmetric_error(value, upper, lower)
+    }
+
+    /// Raises this number to the power `other` (`**`).
+    fn power(&self, other: Self) -> Self;
+
+    /// Takes the `other`-th root of this number (`//`).
+    fn root(&self, other: Self) -> Self;
+
+    /// Attaches the magnitude of `other` as an additional uncertainty (`+/-`).
+    fn plus_minus(&self, other: Self) -> Self;
+
+    /// Applies a standard library function, propagating uncertainty to the given order
+    /// where the backend supports it.
+    fn std_function(function: &StdFunction, args: &[Self], order: PropagationOrder) -> Self;
+
+    /// Evaluates a plain `f64` function of the arguments through the propagation engine.
+    ///
+    /// Used by the executor for operators under second-order propagation. Backends that
+    /// cannot propagate through an arbitrary function return `None` (the default), and
+    /// their own operators are used instead.
+    fn propagate_with_order<F: Fn(&[f64]) -> f64>(_f: F, _args: &[Self], _order: PropagationOrder) -> Option<Self> {
+        None
+    }
 }
 
 /// Real number type that tracks significance
+///
+/// The uncertainty is symmetric unless the value was given separate upper and lower
+/// errors (`12.3(+0.4/-0.2)`). Operations on symmetric values use the closed-form rules
+/// below; as soon as an operand is asymmetric the operation goes through the generic
+/// propagation engine, which adds the upward and downward deviations of the result in
+/// quadrature separately (see `propagation::propagate`).
 #[derive(Debug, Clone, PartialEq)]
 pub struct Real {
     value: f64,
     error: f64,
+
+    /// Separate `(upper, lower)` errors, if asymmetric; `error` is then the larger of the two
+    bounds: Option<(f64, f64)>,
 }
 
 impl Real {
     pub fn new(value: f64) -> Self {
-        Self { value, error: 0.0 }
+        Self { value, error: 0.0, bounds: None }
     }
     
     pub fn with_error(value: f64, error: f64) -> Self {
-        Self { value: value, error: error.abs() }
+        Self { value, error: error.abs(), bounds: None }
+    }
+
+    pub fn with_asymmetric_error(value: f64, upper: f64, lower: f64) -> Self {
+        let (upper, lower) = (upper.abs(), lower.abs());
+        if upper == lower {
+            return Self::with_error(value, upper);
+        }
+        Self { value, error: upper.max(lower), bounds: Some((upper, lower)) }
+    }
+
+    /// Returns whether the upper and lower errors differ.
+    pub fn is_asymmetric(&self) -> bool {
+        self.bounds.is_some()
+    }
+
+    /// Returns the error above the value (`error()` when symmetric).
+    pub fn upper_error(&self) -> f64 {
+        self.bounds.map_or(self.error, |(upper, _)| upper)
+    }
+
+    /// Returns the error below the value (`error()` when symmetric).
+    pub fn lower_error(&self) -> f64 {
+        self.bounds.map_or(self.error, |(_, lower)| lower)
+    }
+}
+
+impl Number for Real {
+    const FOLDS_CONSTANTS: bool = true;
+
+    fn value(&self) -> f64 {
+        self.value
+    }
+    
+    fn error(&self) -> f64 {
+        self.error
     }
     
-    pub fn power(&self, other: Self) -> Self {
+    fn with_error(value: f64, error: f64) -> Self {
+        Self::with_error(value, error)
+    }
+
+    fn with_asymmetric_error(value: f64, upper: f64, lower: f64) -> Self {
+        Self::with_asymmetric_error(value, upper, lower)
+    }
+
+    fn power(&self, other: Self) -> Self {
+        if self.is_asymmetric() || other.is_asymmetric() {
+            return propagate(|v| v[0].powf(v[1]), &[self.clone(), other]);
+        }
+
         let value = self.value().powf(other.value());
     
         // If exponent has no uncertainty, use simpler formula
@@ -59,31 +169,38 @@ impl Real {
         Real::with_error(value, error)
     }
 
-    pub fn root(&self, other: Self) -> Self {
+    fn root(&self, other: Self) -> Self {
         // This is just x^(1/n), so reuse power implementation
         let reciprocal_n = Real::with_error(1.0 / other.value(), 0.0);
         self.power(reciprocal_n)
     }
-}
 
-impl Number for Real {
-    fn value(&self) -> f64 {
-        self.value
-    }
-    
-    fn error(&self) -> f64 {
-        self.error
+    fn plus_minus(&self, other: Self) -> Self {
+        // Attach |other| as an extra independent uncertainty (the error of `other` is not used)
+        let extra = other.value().powi(2);
+        if self.is_asymmetric() {
+            let upper = (self.upper_error().powi(2) + extra).sqrt();
+            let lower = (self.lower_error().powi(2) + extra).sqrt();
+            return Real::with_asymmetric_error(self.value(), upper, lower);
+        }
+        let error = (self.error().powi(2) + extra).sqrt();
+        Real::with_error(self.value(), error)
     }
-    
-    fn with_error(value: f64, error: f64) -> Self {
-        Self::with_error(value, error)
+
+    fn std_function(function: &StdFunction, args: &[Self], order: PropagationOrder) -> Self {
+        propagate_with_order(function.eval, args, order)
     }
 
+    fn propagate_with_order<F: Fn(&[f64]) -> f64>(f: F, args: &[Self], order: PropagationOrder) -> Option<Self> {
+        Some(propagate_with_order(f, args, order))
+    }
 }
 
 impl fmt::Display for Real {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.error == 0.0 {
+        if let Some((upper, lower)) = self.bounds {
+            write!(f, "{}(+{}/-{})", self.value, upper, lower)
+        } else if self.error == 0.0 {
             write!(f, "{}", self.value)
         } else {
             write!(f, "{} +/- {}", self.value, self.error)
@@ -95,6 +212,10 @@ impl Add for Real {
     type Output = Self;
     
     fn add(self, other: Self) -> Self {
+        if self.is_asymmetric() || other.is_asymmetric() {
+            return propagate(|v| v[0] + v[1], &[self, other]);
+        }
+
         // For independent uncertainties, combine in quadrature
         let combined_error = (self.error.powi(2) + other.error.powi(2)).sqrt();
         Self::with_error(self.value + other.value, combined_error)
@@ -105,6 +226,10 @@ impl Sub for Real {
     type Output = Self;
     
     fn sub(self, other: Self) -> Self {
+        if self.is_asymmetric() || other.is_asymmetric() {
+            return propagate(|v| v[0] - v[1], &[self, other]);
+        }
+
         // For independent uncertainties, combine in quadrature
         let combined_error = (self.error.powi(2) + other.error.powi(2)).sqrt();
         Self::with_error(self.value - other.value, combined_error)
@@ -115,6 +240,10 @@ impl Mul for Real {
     type Output = Self;
     
     fn mul(self, other: Self) -> Self {
+        if self.is_asymmetric() || other.is_asymmetric() {
+            return propagate(|v| v[0] * v[1], &[self, other]);
+        }
+
         let result_value = self.value * other.value;
         
         // Handle edge cases where value is zero
@@ -147,6 +276,8 @@ impl Div for Real {
             // Division by zero - return infinity with infinite error
             let result_value = self.value / other.value; // This will be ±∞ or NaN
             Self::with_error(result_value, f64::INFINITY)
+        } else if self.is_asymmetric() || other.is_asymmetric() {
+            propagate(|v| v[0] / v[1], &[self, other])
         } else {
             let result_value = self.value / other.value;
             
@@ -165,6 +296,17 @@ impl Rem for Real {
     type Output = Self;
 
     fn rem(self, other: Self) -> Self {
+        if self.is_asymmetric() || other.is_asymmetric() {
+            // Same rules as below, applied to each side separately
+            let value = self.value() % other.value();
+            if other.error() == 0.0 {
+                return Real::with_asymmetric_error(value, self.upper_error(), self.lower_error());
+            }
+            let upper = self.upper_error().max(other.upper_error());
+            let lower = self.lower_error().max(other.lower_error());
+            return Real::with_asymmetric_error(value, upper, lower);
+        }
+
         // If divisor has no uncertainty, just pass through input uncertainty
         if other.error() == 0.0 {
             let value = self.value() % other.value();
@@ -184,7 +326,8 @@ impl Neg for Real {
     type Output = Self;
     
     fn neg(self) -> Self {
-        Self::with_error(-self.value, self.error)
+        // Negation mirrors the interval, so the upper and lower errors swap
+        Self { value: -self.value, error: self.error, bounds: self.bounds.map(|(upper, lower)| (lower, upper)) }
     }
 }
 
@@ -0,0 +1,113 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Constant folding, run by the executor on each statement before it runs.
+//!
+//! Subexpressions built only from exact literals (no uncertainty) are evaluated once
+//! with the same `Real` arithmetic the executor uses and replaced by a single literal,
+//! so `2.0 * 3.14159 / 180` is computed before the statement is evaluated. Unary plus,
+//! which does nothing, is removed everywhere. The executor keeps the definition of an
+//! assigned variable as written, and the argument of `formula` is not folded, so the
+//! formulas it derives do not depend on whether folding is on.
+//!
+//! Only exact values are folded. An uncertain literal is a source measurement: error
+//! budgets report it by its position, and folding `x - x` or `x * 0` to `0` would drop
+//! the (correlated) uncertainty of `x`. So uncertain literals, variables (including
+//! constants) and the operations that use them are left as they are; only their exact
+//! operands are folded. A fold that would not give an exact, finite value (such as a
+//! division by zero, which the executor reports) is not done either, so folding never
+//! changes what a program prints or which errors it reports.
+//!
+//! Comparisons and logical operators of exact operands fold to an exact 1 or 0, like
+//! any other operator. `&&` and `||` are folded only when both operands are, so a right
+//! operand that reports an error is still skipped (or evaluated) by the executor.
+//!
+//! A folded literal keeps the position and span of the subexpression it replaces.
+
+use crate::Real;
+use crate::significance::ast_parser::{is_true, Expression, Program, Statement, UnaryOp};
+use crate::significance::executor::Executor;
+use crate::significance::numbers::Number;
+use crate::significance::propagation::PropagationOrder;
+use crate::significance::source_map::Span;
+use crate::significance::standard_lib::{lookup_std_function, FORMULA_FUNCTION, NOMINAL_FUNCTION};
+use crate::significance::tokenizer::Position;
+
+/// Folds the exact literal subexpressions of every statement of a program.
+pub fn fold_program(program: &Program) -> Program {
+    Program { statements: program.statements.iter().map(fold_statement).collect() }
+}
+
+/// Folds the exact literal subexpressions of a statement.
+pub fn fold_statement(statement: &Statement) -> Statement {
+    match statement {
+        Statement::Assignment { name, value, pos, span } => Statement::Assignment {
+            name: name.clone(),
+            value: fold_expression(value),
+            pos: *pos,
+            span: *span,
+        },
+        Statement::Expression(expression) => Statement::Expression(fold_expression(expression)),
+        Statement::VarDeclaration { .. } | Statement::Import { .. } => statement.clone(),
+    }
+}
+
+/// Folds the exact literal subexpressions of an expression, and removes unary plus.
+pub fn fold_expression(expression: &Expression) -> Expression {
+    match expression {
+        Expression::NumberWithUncertainty { .. } | Expression::Variable { .. } => expression.clone(),
+        Expression::Unary { op: UnaryOp::Plus, operand, .. } => fold_expression(operand),
+        Expression::Unary { op, operand, pos, span } => {
+            let operand = fold_expression(operand);
+            match (exact_value(&operand), op) {
+                (Some(value), UnaryOp::Not) => folded(Real::new(if is_true(value.value()) { 0.0 } else { 1.0 }), *pos, *span),
+                (Some(value), _) => folded(-value, *pos, *span),
+                (None, _) => Expression::Unary { op: op.clone(), operand: Box::new(operand), pos: *pos, span: *span },
+            }
+        },
+        Expression::Binary { left, op, right, pos, span } => {
+            let (left, right) = (fold_expression(left), fold_expression(right));
+            if let (Some(a), Some(b)) = (exact_value(&left), exact_value(&right)) {
+                if let Some(literal) = fold(Executor::apply_binary(a, op, b), *pos, *span) {
+                    return literal;
+                }
+            }
+            Expression::Binary { left: Box::new(left), op: op.clone(), right: Box::new(right), pos: *pos, span: *span }
+        },
+        Expression::FunctionCall { name, .. } if name == FORMULA_FUNCTION => expression.clone(),
+        Expression::FunctionCall { name, args, pos, span } => {
+            let args: Vec<Expression> = args.iter().map(fold_expression).collect();
+            let values: Option<Vec<Real>> = args.iter().map(exact_value).collect();
+            let result = match (values, lookup_std_function(name)) {
+                (Some(values), Some(function)) => Some(Real::std_function(function, &values, PropagationOrder::First)),
+                (Some(values), None) if name == NOMINAL_FUNCTION => values.into_iter().next(),
+                _ => None,
+            };
+            match result.and_then(|value| fold(value, *pos, *span)) {
+                Some(literal) => literal,
+                None => Expression::FunctionCall { name: name.clone(), args, pos: *pos, span: *span },
+            }
+        },
+    }
+}
+
+/// Returns the value of an exact literal, or `None` for anything else.
+fn exact_value(expression: &Expression) -> Option<Real> {
+    match expression {
+        Expression::NumberWithUncertainty { value, error, .. } if *error == 0.0 => Some(Real::new(*value)),
+        _ => None,
+    }
+}
+
+/// Returns the literal a computed value folds to, if it is exact and finite.
+fn fold(value: Real, pos: Position, span: Span) -> Option<Expression> {
+    (value.error() == 0.0 && value.value().is_finite()).then(|| folded(value, pos, span))
+}
+
+fn folded(value: Real, pos: Position, span: Span) -> Expression {
+    Expression::NumberWithUncertainty { value: value.value(), error: 0.0, pos, span, notation: None, literal: None }
+}
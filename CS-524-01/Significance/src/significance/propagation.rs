@@ -0,0 +1,245 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Generic uncertainty propagation for arbitrary real-valued functions.
+//!
+//! Rather than hand-deriving an uncertainty formula for every function, this module
+//! treats a function as a black box `f(x₁, …, xₙ) -> f64` and estimates its partial
+//! derivatives numerically with central finite differences. The derivatives are then
+//! combined using the standard first-order (linear) propagation law for independent inputs:
+//!
+//! ```text
+//! σ_f² = Σᵢ (∂f/∂xᵢ · σᵢ)²
+//! ```
+//!
+//! This lets new standard library (or host) functions be added by supplying only the
+//! `f64` implementation of the function itself.
+//!
+//! # Example
+//!
+//! ```
+//! # use significance::{propagate, Number, Real};
+//! let x = Real::with_error(2.1, 0.1);
+//! let z = propagate(|v: &[f64]| v[0].sin(), &[x]);
+//! // Same result as the hand-derived |cos(x)| * δx
+//! assert!((z.error() - 2.1f64.cos().abs() * 0.1).abs() < 1e-8);
+//! ```
+
+use crate::{Number, Real};
+
+/// Estimates the partial derivative `∂f/∂x[index]` at `point`.
+///
+/// Uses a central difference with a step scaled to the magnitude of the coordinate,
+/// `h = ∛ε · max(|x|, 1)`, which balances truncation error against floating-point
+/// cancellation. If one side of the stencil falls outside the function's domain
+/// (e.g. `sqrt` just below zero), a one-sided difference is used instead.
+///
+/// # Arguments
+///
+/// * `f` - Function of all arguments
+/// * `point` - Coordinates at which to differentiate
+/// * `index` - Which argument to differentiate with respect to
+///
+/// # Returns
+///
+/// The estimated derivative, or `NaN` if `f` is undefined on both sides of `point`
+pub fn partial_derivative<F>(f: &F, point: &[f64], index: usize) -> f64
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let x = point[index];
+    let step = f64::EPSILON.cbrt() * x.abs().max(1.0);
+
+    // Use the actually representable step to keep the difference quotient honest
+    let h = (x + step) - x;
+
+    let mut shifted = point.to_vec();
+
+    shifted[index] = x + h;
+    let forward = f(&shifted);
+
+    shifted[index] = x - h;
+    let backward = f(&shifted);
+
+    match (forward.is_finite(), backward.is_finite()) {
+        (true, true) => (forward - backward) / (2.0 * h),
+        (true, false) => (forward - f(point)) / h,
+        (false, true) => (f(point) - backward) / h,
+        (false, false) => f64::NAN,
+    }
+}
+
+/// Estimates every partial derivative of `f` at `point`.
+///
+/// # Arguments
+///
+/// * `f` - Function of all arguments
+/// * `point` - Coordinates at which to differentiate
+///
+/// # Returns
+///
+/// Vector of `∂f/∂xᵢ`, one entry per argument
+pub fn gradient<F>(f: &F, point: &[f64]) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    (0..point.len()).map(|i| partial_derivative(f, point, i)).collect()
+}
+
+/// Evaluates `f` on the values of `args` and propagates their uncertainty to first order.
+///
+/// Arguments are assumed to be independent. Arguments with no uncertainty contribute
+/// nothing and are not differentiated, so exact inputs always give an exact result.
+///
+/// If any argument has asymmetric errors, the upward and downward deviations of the
+/// result are accumulated separately: an argument with `∂f/∂xᵢ > 0` moves the result up
+/// by its upper error and down by its lower error, and the other way round for a negative
+/// derivative. Each side is then combined in quadrature, giving an asymmetric result.
+///
+/// # Arguments
+///
+/// * `f` - The function to apply, taking the argument values in order
+/// * `args` - Arguments with uncertainty
+///
+/// # Returns
+///
+/// `f(args)` with uncertainty `sqrt(Σ (∂f/∂xᵢ · σᵢ)²)`
+pub fn propagate<F>(f: F, args: &[Real]) -> Real
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let point: Vec<f64> = args.iter().map(|a| a.value()).collect();
+    let value = f(&point);
+
+    if args.iter().any(|arg| arg.is_asymmetric()) {
+        let (mut upper, mut lower) = (0.0, 0.0);
+        for (i, arg) in args.iter().enumerate().filter(|(_, arg)| arg.error() != 0.0) {
+            let derivative = partial_derivative(&f, &point, i);
+            let (up, down) = if derivative >= 0.0 {
+                (arg.upper_error(), arg.lower_error())
+            } else {
+                (arg.lower_error(), arg.upper_error())
+            };
+            upper += (derivative * up).powi(2);
+            lower += (derivative * down).powi(2);
+        }
+        return Real::with_asymmetric_error(value, upper.sqrt(), lower.sqrt());
+    }
+
+    let variance: f64 = args.iter()
+        .enumerate()
+        .filter(|(_, arg)| arg.error() != 0.0)
+        .map(|(i, arg)| (partial_derivative(&f, &point, i) * arg.error()).powi(2))
+        .sum();
+
+    Real::with_error(value, variance.sqrt())
+}
+
+/// How many terms of the Taylor expansion are used when propagating uncertainty.
+///
+/// First order is the classic linear propagation law and is the default. Second order
+/// additionally accounts for curvature, which matters near stationary points where the
+/// first derivative vanishes (e.g. `sin` near `π/2`, or `x**2` near zero).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PropagationOrder {
+    /// Linear propagation: `σ_f² = Σ (∂f/∂xᵢ · σᵢ)²`, result value `f(μ)`
+    #[default]
+    First,
+
+    /// Second-order propagation for independent Gaussian inputs:
+    ///
+    /// ```text
+    /// mean  = f(μ) + ½ Σᵢ ∂²f/∂xᵢ² σᵢ²
+    /// σ_f²  = Σᵢ (∂f/∂xᵢ σᵢ)² + ½ Σᵢ Σⱼ (∂²f/∂xᵢ∂xⱼ σᵢ σⱼ)²
+    /// ```
+    Second,
+}
+
+/// Estimates the second partial derivative `∂²f/∂xᵢ∂xⱼ` at `point`.
+///
+/// Uses the standard central difference stencils with a step of `∜ε · max(|x|, 1)`
+/// per coordinate, which is the error-balancing choice for second differences.
+///
+/// # Arguments
+///
+/// * `f` - Function of all arguments
+/// * `point` - Coordinates at which to differentiate
+/// * `i`, `j` - Which arguments to differentiate with respect to (may be equal)
+///
+/// # Returns
+///
+/// The estimated second derivative (`NaN` if `f` is undefined around `point`)
+pub fn second_partial_derivative<F>(f: &F, point: &[f64], i: usize, j: usize) -> f64
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let step = |x: f64| (x + f64::EPSILON.powf(0.25) * x.abs().max(1.0)) - x;
+    let h_i = step(point[i]);
+    let mut shifted = point.to_vec();
+
+    if i == j {
+        shifted[i] = point[i] + h_i;
+        let forward = f(&shifted);
+        shifted[i] = point[i] - h_i;
+        let backward = f(&shifted);
+        return (forward - 2.0 * f(point) + backward) / (h_i * h_i);
+    }
+
+    let h_j = step(point[j]);
+    let mut corner = |si: f64, sj: f64| {
+        shifted[i] = point[i] + si * h_i;
+        shifted[j] = point[j] + sj * h_j;
+        f(&shifted)
+    };
+
+    (corner(1.0, 1.0) - corner(1.0, -1.0) - corner(-1.0, 1.0) + corner(-1.0, -1.0)) / (4.0 * h_i * h_j)
+}
+
+/// Evaluates `f` on the values of `args`, propagating uncertainty to the requested order.
+///
+/// With `PropagationOrder::First` this is identical to [`propagate`]. With
+/// `PropagationOrder::Second` the result value includes the curvature-induced mean
+/// shift and the variance includes the second-order terms (see [`PropagationOrder`]).
+/// Second-order propagation assumes Gaussian inputs, so asymmetric arguments are
+/// treated as symmetric with the larger of their two errors.
+///
+/// # Arguments
+///
+/// * `f` - The function to apply, taking the argument values in order
+/// * `args` - Independent arguments with uncertainty
+/// * `order` - How many Taylor terms to keep
+///
+/// # Returns
+///
+/// The propagated result
+pub fn propagate_with_order<F>(f: F, args: &[Real], order: PropagationOrder) -> Real
+where
+    F: Fn(&[f64]) -> f64,
+{
+    if order == PropagationOrder::First {
+        return propagate(f, args);
+    }
+
+    let point: Vec<f64> = args.iter().map(|a| a.value()).collect();
+    let sigma: Vec<f64> = args.iter().map(|a| a.error()).collect();
+    let uncertain: Vec<usize> = (0..args.len()).filter(|&i| sigma[i] != 0.0).collect();
+
+    let mut mean = f(&point);
+    let mut variance = 0.0;
+
+    for &i in &uncertain {
+        variance += (partial_derivative(&f, &point, i) * sigma[i]).powi(2);
+        mean += 0.5 * second_partial_derivative(&f, &point, i, i) * sigma[i].powi(2);
+
+        for &j in &uncertain {
+            let curvature = second_partial_derivative(&f, &point, i, j) * sigma[i] * sigma[j];
+            variance += 0.5 * curvature.powi(2);
+        }
+    }
+
+    Real::with_error(mean, variance.sqrt())
+}
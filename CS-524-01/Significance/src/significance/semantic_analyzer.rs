@@ -12,14 +12,25 @@
 //! - Use of undeclared variables
 //! - Duplicate variable declarations
 //! - Calls to undefined functions
+//! - Uncertain values used as the uncertainty operand of `+/-`
 //!
 //! The semantic analyzer maintains a symbol table to track declared variables and functions,
 //! and collects errors for reporting without halting analysis (allowing multiple errors to
 //! be reported at once).
+//!
+//! It also records warnings for the lints in `lints.rs`: unused and unassigned variables,
+//! suspicious uncertainties, exact-zero divisors and over-precise literals. Whether each
+//! warning is reported is decided afterward by a `LintConfig`.
 //! 
 use crate::significance::tokenizer::Position;
-use crate::significance::ast_parser::{Program, Statement, VarType, Expression};
+use crate::significance::source_map::{FileId, Span};
+use crate::significance::diagnostic::{Diagnostic, Stage};
+use crate::significance::ast_parser::{is_true, Program, Statement, VarType, Expression, BinaryOp, UnaryOp, UncertaintyNotation};
+use crate::significance::lints::Lint;
+use crate::significance::standard_lib::{STD_LIB, INTRINSICS, NOMINAL_FUNCTION, function_arity};
+use crate::significance::constants::{lookup_constant, CONSTANTS};
 use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 
 /// Semantic errors that can occur during analysis.
 ///
@@ -85,23 +96,100 @@ pub enum SemanticError {
     /// result := unknown_func(5)  // Error: unknown_func not declared
     /// ```
     FunctionNotDeclared(String, Position),
+
+    /// Call of a built-in function with the wrong number of arguments.
+    ///
+    /// Contains the function name, the number of arguments it takes, the number it was
+    /// given, and the position of the call.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// assert_close(x, 1.5)  // Error: assert_close takes 3 arguments
+    /// ```
+    WrongArgumentCount(String, usize, usize, Position),
+
+    /// Use of an uncertain value as the uncertainty operand of `+/-`.
+    ///
+    /// Only the operand's value is attached as an uncertainty, so its own uncertainty
+    /// would be silently dropped. Wrapping it in `nominal(...)` drops it explicitly.
+    /// Contains the position of the `+/-` operator.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// dx := 0.5 +/- 0.1
+    /// x := 12.3 +/- dx           // Error: dx is uncertain
+    /// x := 12.3 +/- nominal(dx)  // OK
+    /// ```
+    UncertainErrorOperand(Position),
 }
 
-impl std::fmt::Display for SemanticError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl SemanticError {
+    /// Returns the position the error refers to.
+    pub fn position(&self) -> Position {
         match self {
-            SemanticError::VariableNotDeclared(name, pos) => 
-                write!(f, "Error at {}:{}: Variable '{}' not declared", pos.line, pos.column, name),
-            SemanticError::VariableAlreadyDeclared(name, pos) => 
-                write!(f, "Error at {}:{}: Variable '{}' already declared", pos.line, pos.column, name),
-            SemanticError::VariableAlreadyAssigned(name, pos) => 
-                write!(f, "Error at {}:{}: Variable '{}' already assigned", pos.line, pos.column, name),
-            SemanticError::FunctionNotDeclared(name, pos) => 
-                write!(f, "Error at {}:{}: Function '{}' not declared", pos.line, pos.column, name),
-            SemanticError::VariableNotAssigned(name, pos) => 
-                write!(f, "Error at {}:{}: Variable '{}' not assigned", pos.line, pos.column, name),
+            SemanticError::VariableNotDeclared(_, pos)
+            | SemanticError::VariableAlreadyDeclared(_, pos)
+            | SemanticError::VariableAlreadyAssigned(_, pos)
+            | SemanticError::VariableNotAssigned(_, pos)
+            | SemanticError::FunctionNotDeclared(_, pos)
+            | SemanticError::WrongArgumentCount(_, _, _, pos)
+            | SemanticError::UncertainErrorOperand(pos) => *pos,
         }
     }
+
+    /// Returns the description of the error, without its position.
+    pub fn message(&self) -> String {
+        match self {
+            SemanticError::VariableNotDeclared(name, _) => format!("Variable '{}' not declared", name),
+            SemanticError::VariableAlreadyDeclared(name, _) => format!("Variable '{}' already declared", name),
+            SemanticError::VariableAlreadyAssigned(name, _) => format!("Variable '{}' already assigned", name),
+            SemanticError::FunctionNotDeclared(name, _) => format!("Function '{}' not declared", name),
+            SemanticError::WrongArgumentCount(name, expected, given, _) => format!("Function '{}' takes {} argument(s) but was given {}", name, expected, given),
+            SemanticError::VariableNotAssigned(name, _) => format!("Variable '{}' not assigned", name),
+            SemanticError::UncertainErrorOperand(_) => "Uncertainty operand of '+/-' is itself uncertain (use nominal(...) to drop its uncertainty)".to_string(),
+        }
+    }
+
+    /// Converts the error into a `Diagnostic` located in `file`.
+    ///
+    /// Errors about a name underline as many characters as the name is long.
+    pub fn to_diagnostic(&self, file: FileId) -> Diagnostic {
+        let length = match self {
+            SemanticError::UncertainErrorOperand(_) => 3,
+            SemanticError::VariableNotDeclared(name, _)
+            | SemanticError::VariableAlreadyDeclared(name, _)
+            | SemanticError::VariableAlreadyAssigned(name, _)
+            | SemanticError::VariableNotAssigned(name, _)
+            | SemanticError::FunctionNotDeclared(name, _)
+            | SemanticError::WrongArgumentCount(name, _, _, _) => name.chars().count(),
+        };
+        Diagnostic::new(Stage::Semantic, self.message(), Span::on_line(file, self.position(), length))
+    }
+}
+
+impl std::fmt::Display for SemanticError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pos = self.position();
+        write!(f, "Error at {}:{}: {}", pos.line, pos.column, self.message())
+    }
+}
+
+/// A construct that is valid but probably wrong, found by one of the lints.
+///
+/// Unlike a `SemanticError`, a warning does not stop the program from running unless
+/// its lint is denied (see `LintConfig::report`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SemanticWarning {
+    /// The lint that found the construct
+    pub lint: Lint,
+
+    /// Description of the problem, without its position or lint name
+    pub message: String,
+
+    /// The offending source text
+    pub span: Span,
 }
 
 /// Semantic analyzer for the Significance language.
@@ -110,6 +198,7 @@ impl std::fmt::Display for SemanticError {
 /// - All variables are declared before use
 /// - No duplicate declarations exist
 /// - All function calls reference valid functions
+/// - The uncertainty operand of every `+/-` is exact
 ///
 /// The analyzer uses a symbol table (HashMap) to track all declared identifiers
 /// (variables and functions) along with their types and declaration positions.
@@ -135,7 +224,6 @@ impl std::fmt::Display for SemanticError {
 ///     // Handle semantic errors
 /// }
 /// ```
-
 pub struct SemanticAnalyzer {
     /// Symbol table mapping identifier names to their metadata.
     ///
@@ -148,12 +236,20 @@ pub struct SemanticAnalyzer {
     /// Errors are collected rather than immediately failing, allowing the
     /// analyzer to report multiple problems in a single pass.
     errors: Vec<SemanticError>,
+
+    /// Accumulated lint warnings, reported or not according to a `LintConfig`.
+    warnings: Vec<SemanticWarning>,
+
+    /// Variables declared by the analyzed program (not imported), with their
+    /// declarations, checked for being unused by `check_unused_variables`.
+    declared: Vec<(String, Span)>,
 }
 
 /// Information about a declared variable or function.
 ///
 /// Stored in the symbol table to track metadata about each identifier,
 /// including its type and where it was declared (for error reporting).
+#[derive(Clone)]
 pub struct VarInfo {
     /// The type of this variable or function
     var_type: VarType,
@@ -163,6 +259,15 @@ pub struct VarInfo {
 
     /// Whether this variable has been assigned a value
     assigned: bool,
+
+    /// Whether this variable's value is known to have no uncertainty
+    exact: bool,
+
+    /// Whether this variable has been read
+    used: bool,
+
+    /// Value of a variable assigned a constant exact expression (e.g. `n := 2 * 3`)
+    value: Option<f64>,
 }
 
 impl VarInfo {
@@ -200,22 +305,76 @@ impl VarInfo {
     pub fn is_assigned(&self) -> bool {
         self.assigned
     }
+
+    /// Returns whether this variable's value is known to have no uncertainty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the variable was assigned an exact expression (or not assigned yet)
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
+
+    /// Returns whether this variable has been read since it was declared.
+    ///
+    /// # Returns
+    ///
+    /// `true` if an expression has referenced the variable
+    pub fn is_used(&self) -> bool {
+        self.used
+    }
 }
 
 /// Creates a HashMap of standard library functions.
 /// 
+/// Every function in `STD_LIB` (plus the executor intrinsics such as `budget`)
+/// is registered, so adding a function to the
+/// standard library table is enough to make it visible to the analyzer.
+/// 
 /// # Returns
 /// 
 /// A HashMap mapping standard libary function names to their metadata
 pub fn build_standard_library() -> HashMap<String, VarInfo> {
     let mut std_symbol_table = HashMap::new();
-    std_symbol_table.insert("sin".to_string(), VarInfo { var_type: VarType::RealFunction, declared_at: Position { line: 0, column: 0 }, assigned:true });
-    std_symbol_table.insert("cos".to_string(), VarInfo { var_type: VarType::RealFunction, declared_at: Position { line: 0, column: 0 }, assigned:true });
-    std_symbol_table.insert("sqrt".to_string(), VarInfo { var_type: VarType::RealFunction, declared_at: Position { line: 0, column: 0 }, assigned:true });
+    let names = STD_LIB.iter().map(|function| function.name).chain(INTRINSICS.iter().copied());
+    for name in names {
+        std_symbol_table.insert(name.to_string(), VarInfo { var_type: VarType::RealFunction, declared_at: Position { line: 0, column: 0 }, assigned:true, exact: true, used: false, value: None });
+    }
 
     std_symbol_table
 }
 
+/// Creates a HashMap of the built-in constants.
+///
+/// Every constant in `CONSTANTS` is registered as an already assigned `real`, so it can
+/// be read immediately but not reassigned. Constants with no uncertainty
+/// are marked exact and may be used as the uncertainty operand of `+/-`.
+///
+/// # Returns
+///
+/// A HashMap mapping constant names to their metadata
+pub fn build_constants_library() -> HashMap<String, VarInfo> {
+    let mut constants_table = HashMap::new();
+    for constant in CONSTANTS {
+        constants_table.insert(constant.name.to_string(), VarInfo {
+            var_type: VarType::Real,
+            declared_at: Position { line: 0, column: 0 },
+            assigned: true,
+            exact: constant.error == 0.0,
+            used: false,
+            value: constant.value.parse().ok().filter(|_| constant.error == 0.0),
+        });
+    }
+
+    constants_table
+}
+
+impl Default for SemanticAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SemanticAnalyzer {
     /// Creates a new semantic analyzer with an empty symbol table.
     ///
@@ -229,6 +388,8 @@ impl SemanticAnalyzer {
         Self {
             symbol_table: HashMap::new(),
             errors: Vec::new(),
+            warnings: Vec::new(),
+            declared: Vec::new(),
         }
     }
 
@@ -248,22 +409,35 @@ impl SemanticAnalyzer {
         self.errors.clear();
     }
 
+    /// Returns all lint warnings found during analysis, whatever their lint's level.
+    pub fn get_warnings(&self) -> &Vec<SemanticWarning> {
+        &self.warnings
+    }
+
+    pub fn clear_warnings(&mut self) {
+        self.warnings.clear();
+    }
+
     /// Resets the analyzer to its initial state.
     ///
-    /// Clears both the symbol table and accumulated errors. Useful for
+    /// Clears the symbol table and accumulated errors and warnings. Useful for
     /// analyzing multiple programs with the same analyzer instance, or
     /// for REPL implementations that need to preserve state between inputs.
     pub fn reset(&mut self) {
         self.symbol_table.clear();
         self.import_standard_library();
+        self.import_constants_library();
         self.errors.clear();
+        self.warnings.clear();
+        self.declared.clear();
     }
 
     /// Analyzes a complete program.
     ///
     /// Performs semantic analysis on all statements in the program sequentially.
     /// Each statement is validated against the current symbol table state, with
-    /// declarations updating the table for subsequent statements.
+    /// declarations updating the table for subsequent statements. Unused variables
+    /// are checked for once every statement has been analyzed.
     ///
     /// # Arguments
     ///
@@ -277,6 +451,29 @@ impl SemanticAnalyzer {
         for statement in &program.statements {
             self.analyze_statement(statement);
         }
+        self.check_unused_variables();
+    }
+
+    /// Records a warning for every variable the program declared but never assigned,
+    /// or assigned but never read.
+    ///
+    /// Only meaningful once the whole program has been analyzed, so it is not used in
+    /// the REPL. Nothing is recorded if there were errors, since a failed assignment
+    /// would otherwise also show up as an unassigned variable.
+    pub fn check_unused_variables(&mut self) {
+        if !self.errors.is_empty() {
+            return;
+        }
+
+        for (name, span) in &self.declared {
+            let Some(var_info) = self.symbol_table.get(name) else { continue };
+            let (lint, problem) = match (var_info.is_assigned(), var_info.is_used()) {
+                (false, false) => (Lint::UnassignedVariable, "is declared but never assigned"),
+                (true, false) => (Lint::UnusedVariable, "is assigned but never used"),
+                _ => continue,
+            };
+            self.warnings.push(SemanticWarning { lint, message: format!("Variable '{}' {}", name, problem), span: *span });
+        }
     }
 
     /// Imports the standard library functions into the symbol table.
@@ -288,9 +485,13 @@ impl SemanticAnalyzer {
     ///
     /// # Standard Library Functions
     ///
-    /// - `sin(x)` - Sine function
-    /// - `cos(x)` - Cosine function  
+    /// Every function listed in `STD_LIB`, for example:
+    /// - `sin(x)`, `cos(x)`, `tan(x)` - Trigonometric functions
+    /// - `exp(x)`, `ln(x)`, `log10(x)` - Exponential and logarithms
     /// - `sqrt(x)` - Square root function
+    /// - `atan2(y, x)`, `hypot(x, y)` - Two-argument functions
+    /// - `budget(expr)` - Prints the error budget of an expression
+    /// - `formula(expr)` - Prints the symbolic uncertainty formula of an expression
     ///
     /// # Example
     ///
@@ -305,6 +506,25 @@ impl SemanticAnalyzer {
         self
     }
 
+    /// Imports the built-in constants into the symbol table.
+    ///
+    /// Adds every constant listed in `CONSTANTS`, such as `pi`, `e`, `c`, `G`, `m_e`
+    /// and `k_B`, as a variable that is already assigned. Reassigning a constant is
+    /// reported like any other immutable variable, but declaring a variable of the same
+    /// name shadows it.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut analyzer = SemanticAnalyzer::new();
+    /// analyzer.import_standard_library().import_constants_library();
+    /// // Now pi and G can be read without declaring them
+    /// ```
+    pub fn import_constants_library(&mut self) -> &mut Self {
+        self.import_library(build_constants_library());
+        self
+    }
+
     /// Imports a custom library (set of functions) into the symbol table.
     ///
     /// Allows extending the analyzer with additional built-in or library functions
@@ -322,7 +542,10 @@ impl SemanticAnalyzer {
     /// custom_lib.insert("log".to_string(), VarInfo { 
     ///     var_type: VarType::RealFunction,
     ///     declared_at: Position { line: 0, column: 0 },
-    ///     assigned: true
+    ///     assigned: true,
+    ///     exact: true,
+    ///     used: false,
+    ///     value: None
     /// });
     /// analyzer.import_library(custom_lib);
     /// ```
@@ -330,6 +553,56 @@ impl SemanticAnalyzer {
         self.symbol_table.extend(library);
     }
 
+    /// Imports the variables of another source file into the symbol table.
+    ///
+    /// Unlike `import_library`, a name that is already in use is reported rather than
+    /// silently replaced, so an import can never shadow an existing variable. Like a
+    /// declaration, it may shadow a built-in constant.
+    ///
+    /// # Arguments
+    ///
+    /// * `variables` - Imported names (already qualified by any namespace) and their information
+    /// * `pos` - Source position of the `import` statement
+    ///
+    /// # Errors Detected
+    ///
+    /// Records `VariableAlreadyDeclared` for every imported name that already exists,
+    /// other than a built-in constant.
+    pub fn import_variables(&mut self, variables: HashMap<String, VarInfo>, pos: &Position) {
+        for (name, var_info) in variables {
+            if self.is_constant(&name) {
+                self.symbol_table.insert(name, var_info);
+                continue;
+            }
+            match self.symbol_table.entry(name) {
+                Entry::Occupied(entry) => self.errors.push(SemanticError::VariableAlreadyDeclared(entry.key().clone(), *pos)),
+                Entry::Vacant(entry) => { entry.insert(var_info); },
+            }
+        }
+    }
+
+    /// Looks up an identifier in the symbol table.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&VarInfo)` - The identifier's information
+    /// * `None` - The identifier is not declared
+    pub fn get_symbol(&self, name: &str) -> Option<&VarInfo> {
+        self.symbol_table.get(name)
+    }
+
+    /// Returns whether `name` refers to a built-in constant the program has not shadowed.
+    pub fn is_constant(&self, name: &str) -> bool {
+        lookup_constant(name).is_some()
+            && self.symbol_table.get(name).is_some_and(|info| info.declared_at.line == 0)
+    }
+
+    /// Iterates over every identifier in the symbol table (in no particular order),
+    /// including the standard library, constants and imported variables.
+    pub fn symbols(&self) -> impl Iterator<Item = (&str, &VarInfo)> {
+        self.symbol_table.iter().map(|(name, info)| (name.as_str(), info))
+    }
+
     /// Analyzes a single statement.
     ///
     /// Dispatches to the appropriate analysis method based on statement type:
@@ -342,14 +615,18 @@ impl SemanticAnalyzer {
     /// * `statement` - The statement to analyze
     pub fn analyze_statement(&mut self, statement: &Statement) {
         match statement {
-            Statement::VarDeclaration { name, var_type, pos } => {
+            Statement::VarDeclaration { name, var_type, pos, span } => {
                 self.declare_variable(name, var_type, pos);
+                self.declared.push((name.clone(), *span));
             }
-            Statement::Assignment { name, value, pos } => {
+            Statement::Assignment { name, value, pos, .. } => {
                 self.analyze_assignment(name, value, pos);
             }
             Statement::Expression(expression) => {
-                self.analyze_expression(expression, &Position { line: 0, column: 0 });
+                self.analyze_expression(expression);
+            }
+            Statement::Import { .. } => {
+                // Imported files are loaded by the interpreter, which calls `import_variables`
             }
         }
     }
@@ -358,7 +635,8 @@ impl SemanticAnalyzer {
     ///
     /// Checks if a variable with the same name already exists. If so, records
     /// a `VariableAlreadyDeclared` error but still adds the new declaration to
-    /// the symbol table (allowing analysis to continue).
+    /// the symbol table (allowing analysis to continue). A built-in constant is
+    /// not an error: the declaration shadows it, so `{c : real}` is a new variable.
     ///
     /// # Arguments
     ///
@@ -371,16 +649,19 @@ impl SemanticAnalyzer {
     /// Records `VariableAlreadyDeclared` if a variable with this name already exists.
     pub fn declare_variable(&mut self, name: &str, var_type: &VarType, pos: &Position) {
 
-        if self.symbol_table.contains_key(name) {
-            self.errors.push(SemanticError::VariableAlreadyDeclared(name.to_string(), pos.clone()));
+        if self.symbol_table.contains_key(name) && !self.is_constant(name) {
+            self.errors.push(SemanticError::VariableAlreadyDeclared(name.to_string(), *pos));
         }
 
         self.symbol_table.insert(
             name.to_string(),
             VarInfo {
                 var_type: var_type.clone(),
-                declared_at: pos.clone(),
-                assigned: false
+                declared_at: *pos,
+                assigned: false,
+                exact: true,
+                used: false,
+                value: None,
             }
         );
     }
@@ -410,12 +691,12 @@ impl SemanticAnalyzer {
         // First: check variable state (immutable borrow, released at end of match)
         let should_mark = match self.symbol_table.get(name) {
             None => {
-                self.errors.push(SemanticError::VariableNotDeclared(name.to_string(), pos.clone()));
+                self.errors.push(SemanticError::VariableNotDeclared(name.to_string(), *pos));
                 false
             }
             Some(var_info) => {
                 if var_info.is_assigned() {
-                    self.errors.push(SemanticError::VariableAlreadyAssigned(name.to_string(), pos.clone()));
+                    self.errors.push(SemanticError::VariableAlreadyAssigned(name.to_string(), *pos));
                     false
                 } else {
                     true
@@ -425,12 +706,16 @@ impl SemanticAnalyzer {
 
         // Analyze expression (no borrow held)
         let n_err = self.errors.len();
-        self.analyze_expression(value, pos);
+        self.analyze_expression(value);
 
         // Now mutate if everything was valid
         if should_mark && self.errors.len() == n_err {
+            let exact = self.is_exact(value);
+            let constant = self.constant_value(value);
             if let Some(var_info) = self.symbol_table.get_mut(name) {
                 var_info.mark_assigned();
+                var_info.exact = exact;
+                var_info.value = constant;
             }
         }
     }
@@ -445,49 +730,177 @@ impl SemanticAnalyzer {
     /// # Arguments
     ///
     /// * `expression` - The expression to analyze
-    /// * `pos` - Source position (used for error reporting when expression doesn't have its own position)
+    ///
+    /// Errors are reported at the position of the offending variable, call or operator.
     ///
     /// # Errors Detected
     ///
     /// - `VariableNotDeclared` for references to undeclared variables
     /// - `FunctionNotDeclared` for calls to undeclared functions
-    pub fn analyze_expression(&mut self, expression: &Expression, pos: &Position) {
+    /// - `UncertainErrorOperand` for an uncertain operand on the right of `+/-`
+    ///
+    /// # Warnings Recorded
+    ///
+    /// - `NonPositiveUncertainty` for a zero or negative constant on the right of `+/-`
+    /// - `DivisionByZero` for a divisor that is exactly zero
+    /// - the literal lints (see `lint_literal`)
+    pub fn analyze_expression(&mut self, expression: &Expression) {
         match expression {
-            Expression::Binary { left, op: _, right, pos } => {
-                self.analyze_expression(left, pos);
-                self.analyze_expression(right, pos);
+            Expression::Binary { left, op: BinaryOp::PlusMinus, right, pos, .. } => {
+                self.analyze_expression(left);
+                self.analyze_expression(right);
+                if !self.is_exact(right) {
+                    self.errors.push(SemanticError::UncertainErrorOperand(*pos));
+                }
+                match self.constant_value(right) {
+                    Some(error) if error < 0.0 => self.warn(Lint::NonPositiveUncertainty, format!("Negative uncertainty {} is used as {}", error, -error), right.span()),
+                    Some(0.0) => self.warn(Lint::NonPositiveUncertainty, "Zero uncertainty has no effect", right.span()),
+                    _ => {},
+                }
+            },
+            Expression::Binary { left, op, right, .. } => {
+                self.analyze_expression(left);
+                self.analyze_expression(right);
+                if matches!(op, BinaryOp::Div | BinaryOp::Mod) && self.constant_value(right) == Some(0.0) {
+                    let operation = if op == &BinaryOp::Div { "Division" } else { "Modulus" };
+                    self.warn(Lint::DivisionByZero, format!("{} by zero", operation), right.span());
+                }
             },
-            Expression::Unary { op: _, operand, pos } => {
-                self.analyze_expression(operand, pos);
+            Expression::Unary { op: _, operand, .. } => {
+                self.analyze_expression(operand);
             },
-            Expression::NumberWithUncertainty { value: _, error: _ , pos:_} => {
-                // Literals are always valid, no analysis needed
+            Expression::NumberWithUncertainty { value, error, notation, literal, span, .. } => {
+                self.lint_literal(*value, *error, notation, literal.as_deref(), *span);
             },
-            Expression::FunctionCall { name, args, pos } => {
+            Expression::FunctionCall { name, args, pos, .. } => {
                 self.analyze_function_call(name, args, pos); 
             },
-            Expression::Variable(name) => {
-                if self.symbol_table.get(name).is_none() {
-                    self.errors.push(SemanticError::VariableNotDeclared(name.to_string(), pos.clone()));
+            Expression::Variable { name, pos, span } => {
+                if !self.symbol_table.contains_key(name) {
+                    self.errors.push(SemanticError::VariableNotDeclared(name.to_string(), *pos));
                 }
                 else{
-                    let var_info = self.symbol_table.get(name).unwrap();
+                    let var_info = self.symbol_table.get_mut(name).unwrap();
+                    var_info.used = true;
                     if !var_info.is_assigned() {
-                        self.errors.push(SemanticError::VariableNotAssigned(name.to_string(), pos.clone()));
+                        self.errors.push(SemanticError::VariableNotAssigned(name.to_string(), *pos));
+                    }
+                    if name.chars().count() == 1 && self.is_constant(name) {
+                        let description = lookup_constant(name).map_or("", |constant| constant.description);
+                        self.warn(Lint::OneLetterConstant, format!(
+                            "'{}' refers to the built-in constant '{}'; declare '{}' to use a variable of that name",
+                            name, description, name), *span);
                     }
                 }
             }
         }
     }
 
+    /// Determines whether an expression is known to evaluate without uncertainty.
+    ///
+    /// Exact literals, exactly assigned variables and `nominal(...)` are exact, and
+    /// operators and functions preserve exactness. `+/-` never gives an exact result, and
+    /// comparisons, `&&`, `||` and `!` always do.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if the expression has no uncertainty
+    pub fn is_exact(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::NumberWithUncertainty { error, .. } => *error == 0.0,
+            Expression::Variable { name, .. } => self.symbol_table.get(name).is_none_or(|var_info| var_info.is_exact()),
+            Expression::Binary { op: BinaryOp::PlusMinus, .. } => false,
+            Expression::Binary { op, .. } if op.is_boolean() => true,
+            Expression::Binary { left, right, .. } => self.is_exact(left) && self.is_exact(right),
+            Expression::Unary { op: UnaryOp::Not, .. } => true,
+            Expression::Unary { operand, .. } => self.is_exact(operand),
+            Expression::FunctionCall { name, .. } if name == NOMINAL_FUNCTION => true,
+            Expression::FunctionCall { args, .. } => args.iter().all(|arg| self.is_exact(arg)),
+        }
+    }
+
+    /// Records the warnings for a numeric literal.
+    ///
+    /// # Warnings Recorded
+    ///
+    /// - `NonPositiveUncertainty` for a zero relative (`2 +/- 0%`) or asymmetric (`2(+0/-0)`) uncertainty
+    /// - `LargeUncertainty` if the uncertainty is larger than a non-zero value
+    /// - `ExcessPrecision` if the value's last digit is more than one place below the
+    ///   uncertainty's first significant digit (`12.345 +/- 0.5`, but not `12.35 +/- 0.5`)
+    fn lint_literal(&mut self, value: f64, error: f64, notation: &Option<UncertaintyNotation>, literal: Option<&str>, span: Span) {
+        if error == 0.0 {
+            if notation.is_some() {
+                self.warn(Lint::NonPositiveUncertainty, "Zero uncertainty has no effect", span);
+            }
+            return;
+        }
+
+        if value != 0.0 && error > value.abs() {
+            self.warn(Lint::LargeUncertainty, "Uncertainty is larger than the value itself", span);
+        }
+
+        // The small offset keeps e.g. log10(0.1) from rounding down a whole place
+        let uncertainty_place = (error.log10() + 1e-9).floor() as i32;
+        if let Some((literal, place)) = literal.and_then(|literal| last_digit_place(literal).map(|place| (literal, place))) {
+            if place < uncertainty_place - 1 {
+                self.warn(Lint::ExcessPrecision, format!("'{}' has more digits than its uncertainty justifies", literal), span);
+            }
+        }
+    }
+
+    fn warn(&mut self, lint: Lint, message: impl Into<String>, span: Span) {
+        self.warnings.push(SemanticWarning { lint, message: message.into(), span });
+    }
+
+    /// Computes the value of an exact expression made of literals, constants and
+    /// variables assigned such expressions.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The finite value the expression will have when executed
+    /// * `None` - The expression is uncertain, calls a function, or its value is not finite
+    pub fn constant_value(&self, expression: &Expression) -> Option<f64> {
+        let value = match expression {
+            Expression::NumberWithUncertainty { value, error: 0.0, notation: None, .. } => *value,
+            Expression::NumberWithUncertainty { .. } => return None,
+            Expression::Variable { name, .. } => self.symbol_table.get(name)?.value?,
+            Expression::Unary { op: UnaryOp::Plus, operand, .. } => self.constant_value(operand)?,
+            Expression::Unary { op: UnaryOp::Minus, operand, .. } => -self.constant_value(operand)?,
+            Expression::Unary { op: UnaryOp::Not, operand, .. } => if is_true(self.constant_value(operand)?) { 0.0 } else { 1.0 },
+            Expression::Binary { op: BinaryOp::PlusMinus, .. } => return None,
+            Expression::Binary { left, op, right, .. } => {
+                let (left, right) = (self.constant_value(left)?, self.constant_value(right)?);
+                if let Some(result) = op.apply_boolean(left, right) {
+                    return Some(result);
+                }
+                match op {
+                    BinaryOp::Add => left + right,
+                    BinaryOp::Sub => left - right,
+                    BinaryOp::Mul => left * right,
+                    BinaryOp::Div => left / right,
+                    BinaryOp::Mod => left % right,
+                    BinaryOp::Power => left.powf(right),
+                    BinaryOp::Root => left.powf(1.0 / right),
+                    _ => return None,
+                }
+            },
+            Expression::FunctionCall { .. } => return None,
+        };
+        value.is_finite().then_some(value)
+    }
+
     /// Analyzes a function call expression.
     ///
     /// Validates that:
     /// 1. The function being called exists in the symbol table
-    /// 2. All argument expressions are semantically valid
+    /// 2. A built-in function is given as many arguments as it takes
+    /// 3. All argument expressions are semantically valid
     ///
-    /// Note: This analyzer does not perform type checking or validate argument counts.
-    /// Those checks are deferred to runtime or could be added in future versions.
+    /// Note: This analyzer does not perform type checking.
     ///
     /// # Arguments
     ///
@@ -497,14 +910,31 @@ impl SemanticAnalyzer {
     ///
     /// # Errors Detected
     ///
-    /// Records `FunctionNotDeclared` if the function doesn't exist in the symbol table.
+    /// Records `FunctionNotDeclared` if the function doesn't exist in the symbol table, and
+    /// `WrongArgumentCount` if it is given the wrong number of arguments.
     pub fn analyze_function_call(&mut self, name: &str, args: &Vec<Expression>, pos: &Position) {
-        if self.symbol_table.get(name).is_none() {
-            self.errors.push(SemanticError::FunctionNotDeclared(name.to_string(), pos.clone()));
+        if !self.symbol_table.contains_key(name) {
+            self.errors.push(SemanticError::FunctionNotDeclared(name.to_string(), *pos));
+        } else if let Some(arity) = function_arity(name).filter(|arity| *arity != args.len()) {
+            self.errors.push(SemanticError::WrongArgumentCount(name.to_string(), arity, args.len(), *pos));
         }
         for arg in args {
-            self.analyze_expression(arg, pos);
+            self.analyze_expression(arg);
         }
     }
 
-}
\ No newline at end of file
+}
+
+/// Returns the decimal place of the last digit of a numeric literal: `-2` for `12.34`,
+/// `0` for `12`, `2` for `1200` (whose trailing zeros are not significant) and `1` for `1.2e2`.
+fn last_digit_place(literal: &str) -> Option<i32> {
+    let (mantissa, exponent) = match literal.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().ok()?),
+        None => (literal, 0),
+    };
+    let place = match mantissa.split_once('.') {
+        Some((_, decimals)) => -(decimals.len() as i32),
+        None => (mantissa.len() - mantissa.trim_end_matches('0').len()) as i32,
+    };
+    Some(place + exponent)
+}
@@ -0,0 +1,110 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Source files and spans.
+//!
+//! Every piece of source text the interpreter reads (a file, an imported file, or a
+//! line typed into the REPL) is registered in a `SourceMap` and identified by a
+//! `FileId`. A `Span` locates a token or AST node by file and start/end position, so
+//! that diagnostics can quote the offending source line.
+
+use serde::{Serialize, Deserialize};
+use crate::significance::tokenizer::Position;
+
+/// Identifies one source text registered in a `SourceMap`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FileId(pub usize);
+
+/// A range of source text: from `start` up to (but not including) `end`.
+///
+/// Positions are 1-indexed lines and columns, with columns counting characters.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Span {
+    /// Source text the range is in
+    pub file: FileId,
+
+    /// First character of the range
+    pub start: Position,
+
+    /// Character just past the end of the range
+    pub end: Position,
+}
+
+impl Span {
+    /// Creates a span between two positions in a file.
+    pub fn new(file: FileId, start: Position, end: Position) -> Self {
+        Self { file, start, end }
+    }
+
+    /// Creates a span covering `length` characters on one line, starting at `start`.
+    pub fn on_line(file: FileId, start: Position, length: usize) -> Self {
+        Self::new(file, start, Position { line: start.line, column: start.column + length })
+    }
+
+    /// Returns the smallest span covering both `self` and `other`.
+    ///
+    /// Both spans are expected to be in the same file.
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.file, self.start, other.end)
+    }
+}
+
+impl Default for Span {
+    fn default() -> Self {
+        let start = Position { line: 1, column: 1 };
+        Self::new(FileId::default(), start, start)
+    }
+}
+
+/// One registered source text.
+struct SourceFile {
+    /// Name shown in diagnostics (a path, or e.g. `<repl>`)
+    name: String,
+
+    /// The complete text
+    text: String,
+}
+
+/// All source texts read by an interpreter, indexed by `FileId`.
+#[derive(Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    /// Creates an empty source map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a source text and returns its id.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name shown in diagnostics
+    /// * `text` - The complete source text
+    pub fn add(&mut self, name: &str, text: &str) -> FileId {
+        self.files.push(SourceFile { name: name.to_string(), text: text.to_string() });
+        FileId(self.files.len() - 1)
+    }
+
+    /// Returns the name of a registered source text.
+    pub fn name(&self, file: FileId) -> Option<&str> {
+        self.files.get(file.0).map(|source| source.name.as_str())
+    }
+
+    /// Returns the complete text of a registered source.
+    pub fn text(&self, file: FileId) -> Option<&str> {
+        self.files.get(file.0).map(|source| source.text.as_str())
+    }
+
+    /// Returns a line (1-indexed) of a registered source text, without its line ending.
+    pub fn line(&self, file: FileId, line: usize) -> Option<&str> {
+        let source = self.files.get(file.0)?;
+        source.text.lines().nth(line.checked_sub(1)?)
+    }
+}
@@ -5,28 +5,241 @@
 // Final Project
 //------------------
 
+use crate::significance::ast_parser::is_true;
 use crate::significance::tokenizer::Position;
-use crate::{Number, Real};
+use crate::significance::propagation::PropagationOrder;
+use crate::Number;
 
-pub fn std_lib_call(name: &str, args: &Vec<Real>, pos: &Position) -> Real {
+/// A built-in function of one or more real arguments.
+///
+/// Only the plain `f64` implementation is supplied; uncertainty is propagated
+/// generically by the propagation engine, so no derivative has to be written by hand.
+/// Backends that cannot use the engine (such as `Interval`) recognize functions by name.
+pub struct StdFunction {
+    /// Name the function is called by in Significance source
+    pub name: &'static str,
 
+    /// Number of arguments the function takes
+    pub arity: usize,
+
+    /// The function itself, evaluated on argument values
+    pub eval: fn(&[f64]) -> f64,
+}
+
+/// Every function in the standard library.
+///
+/// Adding a function here makes it visible to both the semantic analyzer and the executor.
+pub const STD_LIB: &[StdFunction] = &[
+    StdFunction { name: "sin",   arity: 1, eval: |x| x[0].sin() },
+    StdFunction { name: "cos",   arity: 1, eval: |x| x[0].cos() },
+    StdFunction { name: "tan",   arity: 1, eval: |x| x[0].tan() },
+    StdFunction { name: "asin",  arity: 1, eval: |x| x[0].asin() },
+    StdFunction { name: "acos",  arity: 1, eval: |x| x[0].acos() },
+    StdFunction { name: "atan",  arity: 1, eval: |x| x[0].atan() },
+    StdFunction { name: "sinh",  arity: 1, eval: |x| x[0].sinh() },
+    StdFunction { name: "cosh",  arity: 1, eval: |x| x[0].cosh() },
+    StdFunction { name: "tanh",  arity: 1, eval: |x| x[0].tanh() },
+    StdFunction { name: "exp",   arity: 1, eval: |x| x[0].exp() },
+    StdFunction { name: "ln",    arity: 1, eval: |x| x[0].ln() },
+    StdFunction { name: "log10", arity: 1, eval: |x| x[0].log10() },
+    StdFunction { name: "sqrt",  arity: 1, eval: |x| x[0].sqrt() },
+    StdFunction { name: "abs",   arity: 1, eval: |x| x[0].abs() },
+    StdFunction { name: "atan2", arity: 2, eval: |x| x[0].atan2(x[1]) },
+    StdFunction { name: "hypot", arity: 2, eval: |x| x[0].hypot(x[1]) },
+];
+
+/// Name of the built-in that prints an error budget for its argument.
+///
+/// Unlike the numeric functions in `STD_LIB`, it is evaluated by the executor itself.
+pub const BUDGET_FUNCTION: &str = "budget";
+
+/// Name of the built-in that prints the symbolic uncertainty formula of its argument.
+///
+/// Like `budget`, it is evaluated by the executor itself.
+pub const FORMULA_FUNCTION: &str = "formula";
+
+/// Name of the built-in that drops the uncertainty of its argument, keeping the value.
+///
+/// Used to make an uncertain value exact on purpose, e.g. `x +/- nominal(dx)`.
+pub const NOMINAL_FUNCTION: &str = "nominal";
+
+/// Name of the built-in that fails if its argument is zero: `assert(n)`.
+pub const ASSERT_FUNCTION: &str = "assert";
+
+/// Name of the built-in that fails if two values differ by more than a tolerance:
+/// `assert_close(a, b, tol)`.
+pub const ASSERT_CLOSE_FUNCTION: &str = "assert_close";
+
+/// Name of the built-in that fails if two values differ by more than a number of their
+/// combined standard uncertainties: `assert_consistent(a, b, nsigma)`.
+pub const ASSERT_CONSISTENT_FUNCTION: &str = "assert_consistent";
+
+/// Built-in functions handled directly by the executor rather than through `STD_LIB`.
+pub const INTRINSICS: &[&str] = &[BUDGET_FUNCTION, FORMULA_FUNCTION, NOMINAL_FUNCTION, ASSERT_FUNCTION, ASSERT_CLOSE_FUNCTION, ASSERT_CONSISTENT_FUNCTION];
+
+/// Returns the number of arguments a built-in function takes, whether it is in `STD_LIB`
+/// or is an intrinsic.
+///
+/// # Returns
+///
+/// * `Some(usize)` - The function's arity
+/// * `None` - No built-in function has this name
+pub fn function_arity(name: &str) -> Option<usize> {
     match name {
+        BUDGET_FUNCTION | FORMULA_FUNCTION | NOMINAL_FUNCTION | ASSERT_FUNCTION => Some(1),
+        ASSERT_CLOSE_FUNCTION | ASSERT_CONSISTENT_FUNCTION => Some(3),
+        _ => lookup_std_function(name).map(|function| function.arity),
+    }
+}
+
+/// The assertions, which check their arguments rather than compute a result.
+pub const ASSERTIONS: &[&str] = &[ASSERT_FUNCTION, ASSERT_CLOSE_FUNCTION, ASSERT_CONSISTENT_FUNCTION];
+
+/// Returns whether a function is one of the `ASSERTIONS`.
+pub fn is_assertion(name: &str) -> bool {
+    ASSERTIONS.contains(&name)
+}
+
+/// What a call to a built-in function does, as resolved by `resolve_builtin`.
+///
+/// The executor and the bytecode compiler both dispatch calls on it, so the two cannot
+/// disagree about which functions exist or how many arguments they take.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Builtin {
+    /// `budget(x)`: prints the error budget of its argument and evaluates to it
+    Budget,
+
+    /// `formula(x)`: prints the symbolic uncertainty formula of its argument and
+    /// evaluates to it
+    Formula,
+
+    /// `nominal(x)`: the value of its argument without uncertainty
+    Nominal,
+
+    /// One of the `ASSERTIONS`
+    Assertion(&'static str),
 
-        "sin" => {
-            if args.len() != 1 {panic!("Std lib function <sin> takes 1 argument @ {}", pos.line)};
-            let error = args[0].value().cos().abs() * args[0].error();
-            Real::with_error(args[0].value().sin(), error)
+    /// The function of `STD_LIB` at this index
+    Std(usize),
+}
+
+/// Resolves a call to the built-in function `name` with `argc` arguments.
+///
+/// # Panics
+///
+/// Panics if no built-in function has this name or it takes a different number of
+/// arguments. Both are expected to be caught by semantic analysis.
+pub fn resolve_builtin(name: &str, argc: usize, pos: &Position) -> Builtin {
+    let builtin = match name {
+        BUDGET_FUNCTION => Builtin::Budget,
+        FORMULA_FUNCTION => Builtin::Formula,
+        NOMINAL_FUNCTION => Builtin::Nominal,
+        _ => match (ASSERTIONS.iter().find(|assertion| **assertion == name), STD_LIB.iter().position(|function| function.name == name)) {
+            (Some(assertion), _) => Builtin::Assertion(assertion),
+            (None, Some(function)) => Builtin::Std(function),
+            (None, None) => panic!("Unknown built-in function {}", name),
         },
-        "cos" => {
-            if args.len() != 1 {panic!("Std lib function <cos> takes 1 argument @ {}", pos.line)};
-            let error = args[0].value().sin().abs() * args[0].error();
-            Real::with_error(args[0].value().cos(), error)
+    };
+    match function_arity(name) {
+        Some(arity) if arity != argc => panic!("Function <{}> takes {} argument(s) @ {}", name, arity, pos.line),
+        _ => builtin,
+    }
+}
+
+/// Looks up a standard library function by name.
+///
+/// # Returns
+///
+/// * `Some(&StdFunction)` - The function definition
+/// * `None` - No standard library function has this name
+pub fn lookup_std_function(name: &str) -> Option<&'static StdFunction> {
+    STD_LIB.iter().find(|function| function.name == name)
+}
+
+pub fn std_lib_call<N: Number>(name: &str, args: &[N], pos: &Position) -> N {
+    std_lib_call_with_order(name, args, PropagationOrder::First, pos)
+}
+
+/// Calls a standard library function, propagating uncertainty to the given order.
+///
+/// # Panics
+///
+/// Panics if the function doesn't exist or is called with the wrong number of
+/// arguments. Both are expected to be caught before execution.
+pub fn std_lib_call_with_order<N: Number>(name: &str, args: &[N], order: PropagationOrder, pos: &Position) -> N {
+
+    let function = match lookup_std_function(name) {
+        Some(function) => function,
+        None => panic!("Unknown std lib function {}", name),
+    };
+
+    if args.len() != function.arity {
+        panic!("Std lib function <{}> takes {} argument(s) @ {}", name, function.arity, pos.line);
+    }
+
+    N::std_function(function, args, order)
+
+}
+
+/// Checks the arguments of an assertion.
+///
+/// - `assert(n)` holds if the value of `n` is not zero (nor NaN).
+/// - `assert_close(a, b, tol)` holds if the values of `a` and `b` differ by at most the
+///   value of `tol`.
+/// - `assert_consistent(a, b, nsigma)` holds if the values of `a` and `b` differ by at
+///   most `nsigma` times their combined uncertainty, `sqrt(da² + db²)`.
+///
+/// # Returns
+///
+/// * `Ok(N)` - The assertion holds; its first argument, which the call evaluates to
+/// * `Err(String)` - The assertion fails; a description of why
+///
+/// # Panics
+///
+/// Panics if the function is not an assertion or is called with the wrong number of
+/// arguments. Both are expected to be caught before execution.
+pub fn assertion_call<N: Number>(name: &str, args: &[N], pos: &Position) -> Result<N, String> {
+    if function_arity(name) != Some(args.len()) || !is_assertion(name) {
+        panic!("Assertion <{}> called with {} argument(s) @ {}", name, args.len(), pos.line);
+    }
+
+    let difference = || (args[0].value() - args[1].value()).abs();
+    match name {
+        ASSERT_FUNCTION if !is_true(args[0].value()) => {
+            Err(format!("Assertion failed: argument is {}", args[0]))
         },
-        "sqrt" => {
-            if args.len() != 1 {panic!("Std lib function <sqrt> takes 1 argument @ {}", pos.line)};
-            args[0].root(Real::new(2.0))
+        ASSERT_CLOSE_FUNCTION if difference() > args[2].value() || difference().is_nan() => Err(format!(
+            "Assertion failed: {} and {} differ by {}, more than the tolerance {}",
+            args[0], args[1], rounded(difference()), args[2].value()
+        )),
+        ASSERT_CONSISTENT_FUNCTION => {
+            let sigma = args[0].error().hypot(args[1].error());
+            let sigmas = match difference() {
+                0.0 => 0.0,
+                difference => difference / sigma,
+            };
+            match sigmas <= args[2].value() {
+                true => Ok(args[0].clone()),
+                false => Err(format!(
+                    "Assertion failed: {} and {} differ by {:.2} standard uncertainties, more than {}",
+                    args[0], args[1], sigmas, args[2].value()
+                )),
+            }
         },
-        _ => panic!("Unknown std lib function {}", name)
+        _ => Ok(args[0].clone()),
     }
+}
 
-}
\ No newline at end of file
+/// Writes a number to six significant digits, so that a difference such as
+/// `0.020000000000000018` reads as `0.02`.
+fn rounded(x: f64) -> String {
+    if x == 0.0 || !x.is_finite() {
+        return x.to_string();
+    }
+    let decimals = (5 - x.abs().log10().floor() as i32).max(0) as usize;
+    let text = format!("{:.*}", decimals, x);
+    match text.contains('.') {
+        true => text.trim_end_matches('0').trim_end_matches('.').to_string(),
+        false => text,
+    }
+}
@@ -0,0 +1,754 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Symbolic uncertainty formulas (`formula(expr)`).
+//!
+//! The executor propagates uncertainty numerically. For a review, the closed form behind a
+//! result is more useful: `formula(expr)` walks the expression (following variable
+//! definitions, like an error budget), differentiates it symbolically with respect to each
+//! source measurement, and prints the partial derivatives and the first-order
+//! uncertainty
+//!
+//! ```text
+//! σ_f = sqrt((∂f/∂q1 * σ_q1)**2 + (∂f/∂q2 * σ_q2)**2 + ...)
+//! ```
+//!
+//! as plain text (in Significance syntax) and as LaTeX. This is the rule `numbers.rs`
+//! applies operator by operator (and `propagation.rs` through finite differences), applied
+//! to the whole expression at once, so a source used more than once is treated as a single
+//! correlated input, as in `budget`.
+//!
+//! The inputs are the sources of an error budget: uncertain literals, uncertainties
+//! attached with `+/-`, and variables assigned directly from an uncertain literal (or with
+//! no recorded definition, such as constants and imported variables). Variables keep their
+//! names; the other inputs are named `q1`, `q2`, ... and listed with the budget label of
+//! the literal or `+/-` they stand for. Variables assigned an exact literal and exact
+//! constants stay symbols, but are not differentiated.
+//!
+//! The expressions are simplified as they are built (`0 * x` is `0`, `x * x` is `x**2`,
+//! numbers are combined), but no further: the formulas are meant to be read, not proven.
+//! Comparisons and logical operators are kept as written; their result is exact, so
+//! their derivatives are `0`.
+//!
+//! `formula(expr)` evaluates to its argument as the executor computes it, operator by
+//! operator, and prints that value with the formula (`Formula::report`). Its uncertainty
+//! is `σ_f` unless an input is used more than once: `x - x` evaluates to `0 +/- 0.14`
+//! for `x = 1 +/- 0.1`, while its formula correctly gives `σ_f = 0`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Not, Sub};
+
+use crate::significance::ast_parser::{is_true, BinaryOp, Expression, UnaryOp};
+use crate::significance::budget::source_name;
+use crate::significance::executor::VarRunTime;
+use crate::significance::numbers::Number;
+use crate::significance::standard_lib::{lookup_std_function, NOMINAL_FUNCTION};
+use Symbolic::Number as Num;
+
+/// Name of the function the derivative of `%` uses (rounds toward zero).
+const TRUNC_FUNCTION: &str = "trunc";
+
+/// A symbolic real-valued expression.
+///
+/// The operators (`+`, `-`, `*`, `/`, unary `-`, `!`) and `pow`, `modulo`, `compare`
+/// and `call` build simplified expressions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Symbolic {
+    /// An exact number
+    Number(f64),
+
+    /// A named value: an input, or an exact variable or constant
+    Symbol(String),
+
+    /// The standard uncertainty of an input: `σ_x`
+    Uncertainty(String),
+
+    Add(Box<Symbolic>, Box<Symbolic>),
+    Sub(Box<Symbolic>, Box<Symbolic>),
+    Mul(Box<Symbolic>, Box<Symbolic>),
+    Div(Box<Symbolic>, Box<Symbolic>),
+
+    /// `a ** b`
+    Pow(Box<Symbolic>, Box<Symbolic>),
+
+    /// `a % b`
+    Mod(Box<Symbolic>, Box<Symbolic>),
+
+    Neg(Box<Symbolic>),
+
+    /// A comparison or logical operator (`<`, `==`, `&&`, ...), which is 1 or 0
+    Compare(BinaryOp, Box<Symbolic>, Box<Symbolic>),
+
+    /// `!a`, which is 1 or 0
+    Not(Box<Symbolic>),
+
+    /// A call of a `STD_LIB` function, of `nominal`, or of `trunc`
+    Call(String, Vec<Symbolic>),
+}
+
+impl Symbolic {
+    /// Returns `self ** exponent`.
+    pub fn pow(self, exponent: Symbolic) -> Symbolic {
+        match (self, exponent) {
+            (Num(a), Num(b)) => Num(a.powf(b)),
+            (_, Num(0.0)) => Num(1.0),
+            (base, Num(1.0)) => base,
+            (Num(1.0), _) => Num(1.0),
+            // An even power drops the sign of its base
+            (Symbolic::Neg(base), Num(b)) if b % 2.0 == 0.0 => base.pow(Num(b)),
+            (base, exponent) => Symbolic::Pow(Box::new(base), Box::new(exponent)),
+        }
+    }
+
+    /// Returns `self % divisor`.
+    pub fn modulo(self, divisor: Symbolic) -> Symbolic {
+        match (self, divisor) {
+            (Num(a), Num(b)) if b != 0.0 => Num(a % b),
+            (a, b) => Symbolic::Mod(Box::new(a), Box::new(b)),
+        }
+    }
+
+    /// Returns a comparison or logical operator, evaluated if both operands are numbers.
+    pub fn compare(op: BinaryOp, a: Symbolic, b: Symbolic) -> Symbolic {
+        match (&a, &b) {
+            (Num(x), Num(y)) => Num(op.apply_boolean(*x, *y).unwrap_or(f64::NAN)),
+            _ => Symbolic::Compare(op, Box::new(a), Box::new(b)),
+        }
+    }
+
+    /// Returns a call of a built-in function. Calls of the exact functions (`abs`,
+    /// `nominal`, `trunc`) on numbers are evaluated; other functions are kept, so that
+    /// `sqrt(2)` reads as written.
+    pub fn call(name: &str, args: Vec<Symbolic>) -> Symbolic {
+        let exact = matches!(name, "abs" | TRUNC_FUNCTION | NOMINAL_FUNCTION);
+        let call = Symbolic::Call(name.to_string(), args);
+        match &call {
+            Symbolic::Call(_, args) if exact && matches!(args.as_slice(), [Num(_)]) => Num(call.evaluate(&HashMap::new())),
+            _ => call,
+        }
+    }
+
+    /// Returns whether the expression is the number zero.
+    pub fn is_zero(&self) -> bool {
+        matches!(self, Num(n) if *n == 0.0)
+    }
+
+    /// Evaluates the expression, given the values of its symbols (unknown symbols and
+    /// uncertainties are `NaN`).
+    pub fn evaluate(&self, values: &HashMap<String, f64>) -> f64 {
+        let eval = |expression: &Symbolic| expression.evaluate(values);
+        match self {
+            Num(n) => *n,
+            Symbolic::Symbol(name) => values.get(name).copied().unwrap_or(f64::NAN),
+            Symbolic::Uncertainty(_) => f64::NAN,
+            Symbolic::Add(a, b) => eval(a) + eval(b),
+            Symbolic::Sub(a, b) => eval(a) - eval(b),
+            Symbolic::Mul(a, b) => eval(a) * eval(b),
+            Symbolic::Div(a, b) => eval(a) / eval(b),
+            Symbolic::Pow(a, b) => eval(a).powf(eval(b)),
+            Symbolic::Mod(a, b) => eval(a) % eval(b),
+            Symbolic::Neg(a) => -eval(a),
+            Symbolic::Compare(op, a, b) => op.apply_boolean(eval(a), eval(b)).unwrap_or(f64::NAN),
+            Symbolic::Not(a) => if is_true(eval(a)) { 0.0 } else { 1.0 },
+            Symbolic::Call(name, args) => {
+                let args: Vec<f64> = args.iter().map(eval).collect();
+                match (name.as_str(), lookup_std_function(name)) {
+                    (_, Some(function)) => (function.eval)(&args),
+                    (TRUNC_FUNCTION, None) => args[0].trunc(),
+                    _ => args[0],
+                }
+            },
+        }
+    }
+
+    /// Differentiates the expression with respect to a symbol.
+    ///
+    /// # Panics
+    ///
+    /// Panics on a call of a function without a derivative rule, which means a function
+    /// was added to `STD_LIB` without one.
+    pub fn derivative(&self, symbol: &str) -> Symbolic {
+        let d = |expression: &Symbolic| expression.derivative(symbol);
+        match self {
+            Num(_) | Symbolic::Uncertainty(_) | Symbolic::Compare(..) | Symbolic::Not(_) => Num(0.0),
+            Symbolic::Symbol(name) => Num(if name == symbol { 1.0 } else { 0.0 }),
+            Symbolic::Add(a, b) => d(a) + d(b),
+            Symbolic::Sub(a, b) => d(a) - d(b),
+            Symbolic::Mul(a, b) => d(a) * *b.clone() + *a.clone() * d(b),
+            Symbolic::Div(a, b) => {
+                let (da, db) = (d(a), d(b));
+                if db.is_zero() {
+                    return da / *b.clone();
+                }
+                (da * *b.clone() - *a.clone() * db) / b.clone().pow(Num(2.0))
+            },
+            Symbolic::Pow(base, exponent) => {
+                let (base, exponent) = (*base.clone(), *exponent.clone());
+                let (db, de) = (d(&base), d(&exponent));
+                if de.is_zero() {
+                    // d(u**n) = n * u**(n - 1) * du
+                    exponent.clone() * base.pow(exponent - Num(1.0)) * db
+                } else if db.is_zero() {
+                    // d(a**v) = a**v * ln(a) * dv
+                    base.clone().pow(exponent) * Symbolic::call("ln", vec![base]) * de
+                } else {
+                    // d(u**v) = u**v * (dv * ln(u) + v * du / u)
+                    let rate = de * Symbolic::call("ln", vec![base.clone()]) + exponent.clone() * db / base.clone();
+                    base.pow(exponent) * rate
+                }
+            },
+            Symbolic::Mod(a, b) => {
+                // a % b = a - trunc(a / b) * b, and trunc is flat
+                let quotient = Symbolic::call(TRUNC_FUNCTION, vec![*a.clone() / *b.clone()]);
+                d(a) - quotient * d(b)
+            },
+            Symbolic::Neg(a) => -d(a),
+            Symbolic::Call(name, args) => call_derivative(name, args, symbol),
+        }
+    }
+
+    /// Renders the expression in Significance syntax.
+    pub fn to_text(&self) -> String {
+        self.text(0)
+    }
+
+    /// Renders the expression as LaTeX math.
+    pub fn to_latex(&self) -> String {
+        self.latex(0)
+    }
+
+    /// Binding strength in Significance syntax (`unary = [op] uncertain`, and `**` takes
+    /// unary operands on both sides).
+    fn precedence(&self) -> u8 {
+        match self {
+            Symbolic::Compare(..) => 0,
+            Symbolic::Add(..) | Symbolic::Sub(..) => 1,
+            Symbolic::Mul(..) | Symbolic::Div(..) | Symbolic::Mod(..) => 2,
+            Symbolic::Pow(..) => 3,
+            Symbolic::Neg(_) | Symbolic::Not(_) => 4,
+            Num(n) if *n < 0.0 => 4,
+            _ => 5,
+        }
+    }
+
+    fn text(&self, min: u8) -> String {
+        let text = match self {
+            Num(n) => n.to_string(),
+            Symbolic::Symbol(name) => name.clone(),
+            Symbolic::Uncertainty(name) => format!("σ_{}", name),
+            Symbolic::Add(a, b) => format!("{} + {}", a.text(1), b.text(1)),
+            Symbolic::Sub(a, b) => format!("{} - {}", a.text(1), b.text(2)),
+            Symbolic::Mul(a, b) => format!("{} * {}", a.text(2), b.text(3)),
+            Symbolic::Div(a, b) => format!("{} / {}", a.text(2), b.text(3)),
+            Symbolic::Mod(a, b) => format!("{} % {}", a.text(2), b.text(3)),
+            // A negative base is parenthesized even though unary minus binds tighter
+            Symbolic::Pow(a, b) => format!("{}**{}", a.text(5), b.text(4)),
+            Symbolic::Neg(a) => format!("-{}", a.text(5)),
+            // Comparisons do not chain, and `&&` and `||` keep their operands in parentheses
+            Symbolic::Compare(op, a, b) => format!("{} {} {}", a.text(1), op.symbol(), b.text(1)),
+            Symbolic::Not(a) => format!("!{}", a.text(5)),
+            Symbolic::Call(name, args) => {
+                let args: Vec<String> = args.iter().map(|arg| arg.text(0)).collect();
+                format!("{}({})", name, args.join(", "))
+            },
+        };
+        if self.precedence() < min { format!("({})", text) } else { text }
+    }
+
+    /// Binding strength in LaTeX, where a fraction is a single term.
+    fn latex_precedence(&self) -> u8 {
+        match self {
+            Symbolic::Compare(..) => 0,
+            Symbolic::Add(..) | Symbolic::Sub(..) => 1,
+            Symbolic::Mul(..) | Symbolic::Mod(..) => 2,
+            Symbolic::Neg(_) | Symbolic::Not(_) => 3,
+            Num(n) if *n < 0.0 => 3,
+            Symbolic::Pow(..) => 4,
+            _ => 5,
+        }
+    }
+
+    fn latex(&self, min: u8) -> String {
+        let latex = match self {
+            Num(n) => n.to_string(),
+            Symbolic::Symbol(name) => latex_name(name),
+            Symbolic::Uncertainty(name) => format!("\\sigma_{{{}}}", latex_name(name)),
+            Symbolic::Add(a, b) => format!("{} + {}", a.latex(1), b.latex(1)),
+            Symbolic::Sub(a, b) => format!("{} - {}", a.latex(1), b.latex(2)),
+            // A numeric coefficient is written next to what it multiplies: `2 x`
+            Symbolic::Mul(a, b) if matches!(**a, Num(_)) && !matches!(**b, Num(_)) => {
+                format!("{} {}", a.latex(2), b.latex(3))
+            },
+            Symbolic::Mul(a, b) => format!("{} \\cdot {}", a.latex(2), b.latex(3)),
+            Symbolic::Mod(a, b) => format!("{} \\bmod {}", a.latex(2), b.latex(3)),
+            Symbolic::Div(a, b) => format!("\\frac{{{}}}{{{}}}", a.latex(0), b.latex(0)),
+            Symbolic::Pow(a, b) => {
+                let base = match **a {
+                    Symbolic::Div(..) => format!("\\left({}\\right)", a.latex(0)),
+                    _ => a.latex(5),
+                };
+                format!("{}^{{{}}}", base, b.latex(0))
+            },
+            Symbolic::Neg(a) => format!("-{}", a.latex(2)),
+            Symbolic::Compare(op, a, b) => {
+                let symbol = match op {
+                    BinaryOp::LessEqual => "\\le",
+                    BinaryOp::GreaterEqual => "\\ge",
+                    BinaryOp::Equal => "=",
+                    BinaryOp::NotEqual => "\\ne",
+                    BinaryOp::And => "\\land",
+                    BinaryOp::Or => "\\lor",
+                    op => op.symbol(),
+                };
+                format!("{} {} {}", a.latex(1), symbol, b.latex(1))
+            },
+            Symbolic::Not(a) => format!("\\lnot {}", a.latex(4)),
+            Symbolic::Call(name, args) => {
+                let args: Vec<String> = args.iter().map(|arg| arg.latex(0)).collect();
+                let args = args.join(", ");
+                match name.as_str() {
+                    "sqrt" => format!("\\sqrt{{{}}}", args),
+                    "abs" => format!("\\left|{}\\right|", args),
+                    "log10" => format!("\\log_{{10}}\\left({}\\right)", args),
+                    "asin" | "acos" | "atan" => format!("\\arc{}\\left({}\\right)", &name[1..], args),
+                    "sin" | "cos" | "tan" | "sinh" | "cosh" | "tanh" | "exp" | "ln" => format!("\\{}\\left({}\\right)", name, args),
+                    _ => format!("\\operatorname{{{}}}\\left({}\\right)", name, args),
+                }
+            },
+        };
+        if self.latex_precedence() < min { format!("\\left({}\\right)", latex) } else { latex }
+    }
+}
+
+impl fmt::Display for Symbolic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
+
+impl Add for Symbolic {
+    type Output = Symbolic;
+
+    fn add(self, other: Symbolic) -> Symbolic {
+        match (self, other) {
+            (Num(a), Num(b)) => Num(a + b),
+            (Num(0.0), x) | (x, Num(0.0)) => x,
+            (x, Symbolic::Neg(y)) => x - *y,
+            (x, Num(n)) if n < 0.0 => x - Num(-n),
+            (a, b) if a == b => Num(2.0) * a,
+            (a, b) => Symbolic::Add(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Sub for Symbolic {
+    type Output = Symbolic;
+
+    fn sub(self, other: Symbolic) -> Symbolic {
+        match (self, other) {
+            (Num(a), Num(b)) => Num(a - b),
+            (x, Num(0.0)) => x,
+            (Num(0.0), x) => -x,
+            (x, Symbolic::Neg(y)) => x + *y,
+            (x, Num(n)) if n < 0.0 => x + Num(-n),
+            (a, b) if a == b => Num(0.0),
+            (a, b) => Symbolic::Sub(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Mul for Symbolic {
+    type Output = Symbolic;
+
+    fn mul(self, other: Symbolic) -> Symbolic {
+        match (self, other) {
+            (Num(a), Num(b)) => Num(a * b),
+            (Num(0.0), _) | (_, Num(0.0)) => Num(0.0),
+            (Num(1.0), x) | (x, Num(1.0)) => x,
+            (Num(-1.0), x) | (x, Num(-1.0)) => -x,
+            // Numeric coefficients go first, and are combined
+            (x, Num(n)) => Num(n) * x,
+            (Num(n), Symbolic::Mul(a, b)) => match *a {
+                Num(m) => Num(n * m) * *b,
+                a => Symbolic::Mul(Box::new(Num(n)), Box::new(Symbolic::Mul(Box::new(a), b))),
+            },
+            (Symbolic::Neg(a), b) => -(*a * b),
+            (a, Symbolic::Neg(b)) => -(a * *b),
+            (Symbolic::Div(one, a), b) | (b, Symbolic::Div(one, a)) if *one == Num(1.0) => b / *a,
+            (a, b) if a == b => a.pow(Num(2.0)),
+            (a, b) => Symbolic::Mul(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Div for Symbolic {
+    type Output = Symbolic;
+
+    fn div(self, other: Symbolic) -> Symbolic {
+        match (self, other) {
+            (Num(a), Num(b)) if b != 0.0 => Num(a / b),
+            (Num(0.0), _) => Num(0.0),
+            (x, Num(1.0)) => x,
+            (x, Num(-1.0)) => -x,
+            (Symbolic::Neg(a), b) => -(*a / b),
+            (a, b) if a == b => Num(1.0),
+            (a, b) => Symbolic::Div(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Neg for Symbolic {
+    type Output = Symbolic;
+
+    fn neg(self) -> Symbolic {
+        match self {
+            Num(n) => Num(-n),
+            Symbolic::Neg(a) => *a,
+            a => Symbolic::Neg(Box::new(a)),
+        }
+    }
+}
+
+impl Not for Symbolic {
+    type Output = Symbolic;
+
+    fn not(self) -> Symbolic {
+        match self {
+            Num(n) => Num(if is_true(n) { 0.0 } else { 1.0 }),
+            a => Symbolic::Not(Box::new(a)),
+        }
+    }
+}
+
+/// Differentiates a call of a built-in function by the chain rule.
+fn call_derivative(name: &str, args: &[Symbolic], symbol: &str) -> Symbolic {
+    let call = |name: &str, args: &[Symbolic]| Symbolic::call(name, args.to_vec());
+    let one = || Num(1.0);
+
+    if let [a, b] = args {
+        let (da, db) = (a.derivative(symbol), b.derivative(symbol));
+        let squares = || a.clone().pow(Num(2.0)) + b.clone().pow(Num(2.0));
+        return match name {
+            // atan2(a, b) is the angle of the point (b, a)
+            "atan2" => (b.clone() * da - a.clone() * db) / squares(),
+            "hypot" => (a.clone() * da + b.clone() * db) / call(name, args),
+            _ => panic!("No derivative rule for function <{}>", name),
+        };
+    }
+
+    let u = &args[0];
+    let du = u.derivative(symbol);
+    if name == NOMINAL_FUNCTION || name == TRUNC_FUNCTION || du.is_zero() {
+        return Num(0.0);
+    }
+    let square = || u.clone().pow(Num(2.0));
+    let outer = match name {
+        "sin" => call("cos", args),
+        "cos" => -call("sin", args),
+        "tan" => one() / call("cos", args).pow(Num(2.0)),
+        "asin" => one() / call("sqrt", &[one() - square()]),
+        "acos" => -(one() / call("sqrt", &[one() - square()])),
+        "atan" => one() / (one() + square()),
+        "sinh" => call("cosh", args),
+        "cosh" => call("sinh", args),
+        "tanh" => one() - call("tanh", args).pow(Num(2.0)),
+        "exp" => call("exp", args),
+        "ln" => one() / u.clone(),
+        "log10" => one() / (u.clone() * call("ln", &[Num(10.0)])),
+        "sqrt" => one() / (Num(2.0) * call("sqrt", args)),
+        "abs" => u.clone() / call("abs", args),
+        _ => panic!("No derivative rule for function <{}>", name),
+    };
+    outer * du
+}
+
+/// Renders a name as a LaTeX symbol: `q1` as `q_{1}`, `m_e` as `m_{e}`, `pi` as `\pi`,
+/// and longer names as one italic word.
+fn latex_name(name: &str) -> String {
+    if name == "pi" {
+        return "\\pi".to_string();
+    }
+    let (base, subscript) = match name.split_once('_') {
+        Some((base, subscript)) => (base, subscript),
+        None => name.split_at(name.trim_end_matches(|c: char| c.is_ascii_digit()).len()),
+    };
+    let base = match base.chars().count() {
+        0 | 1 => base.to_string(),
+        _ => format!("\\mathit{{{}}}", base),
+    };
+    match subscript {
+        "" => base,
+        subscript => format!("{}_{{{}}}", base, subscript.replace('_', "\\_")),
+    }
+}
+
+/// An input of a formula: a source measurement it is differentiated with respect to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormulaInput {
+    /// Symbol the input appears as: a variable's name, or `q1`, `q2`, ...
+    pub name: String,
+
+    /// The budget label of the source (the variable's name, or e.g. `y: literal at 3:8`)
+    pub source: String,
+
+    /// Value of the input
+    pub value: f64,
+
+    /// Standard uncertainty of the input
+    pub error: f64,
+}
+
+/// The closed-form first-order uncertainty of an expression.
+#[derive(Debug, Clone)]
+pub struct Formula {
+    /// Name of the result: the variable, or `f` for any other expression
+    pub name: String,
+
+    /// The expression, with variable definitions substituted
+    pub expression: Symbolic,
+
+    /// The source measurements, in the order the expression uses them
+    pub inputs: Vec<FormulaInput>,
+
+    /// The partial derivative with respect to each input
+    pub partials: Vec<Symbolic>,
+
+    /// Values of the inputs and exact symbols
+    values: HashMap<String, f64>,
+}
+
+impl Formula {
+    /// Returns the value of the expression.
+    pub fn value(&self) -> f64 {
+        self.expression.evaluate(&self.values)
+    }
+
+    /// Returns the value of the partial derivative with respect to an input.
+    pub fn sensitivity(&self, input: usize) -> f64 {
+        self.partials[input].evaluate(&self.values)
+    }
+
+    /// Returns the symbolic uncertainty `σ_f = sqrt(Σ (∂f/∂qᵢ · σ_qᵢ)²)`, leaving out
+    /// inputs the result does not depend on. With a single input this is `|∂f/∂q| · σ_q`.
+    pub fn sigma(&self) -> Symbolic {
+        let terms: Vec<(Symbolic, Symbolic)> = self.inputs.iter().zip(&self.partials)
+            .filter(|(_, partial)| !partial.is_zero())
+            .map(|(input, partial)| (partial.clone(), Symbolic::Uncertainty(input.name.clone())))
+            .collect();
+        match terms.as_slice() {
+            [] => Num(0.0),
+            [(partial, sigma)] => Symbolic::call("abs", vec![partial.clone()]) * sigma.clone(),
+            _ => {
+                let sum = terms.into_iter()
+                    .map(|(partial, sigma)| (partial * sigma).pow(Num(2.0)))
+                    .reduce(|a, b| a + b)
+                    .unwrap();
+                Symbolic::call("sqrt", vec![sum])
+            },
+        }
+    }
+
+    /// Returns the value of the uncertainty.
+    pub fn error(&self) -> f64 {
+        (0..self.inputs.len())
+            .map(|i| (self.sensitivity(i) * self.inputs[i].error).powi(2))
+            .fold(0.0, |sum, variance| sum + variance)
+            .sqrt()
+    }
+
+    /// Returns the formulas in Significance syntax, one line each: the expression, each
+    /// partial derivative (with its value) and the uncertainty (with its value).
+    pub fn to_text(&self) -> Vec<String> {
+        let mut lines = vec![format!("{} = {}", self.name, self.expression)];
+        for (i, (input, partial)) in self.inputs.iter().zip(&self.partials).enumerate() {
+            let line = format!("∂{}/∂{} = {}", self.name, input.name, partial);
+            match partial {
+                Num(_) => lines.push(line),
+                _ => lines.push(format!("{} = {}", line, self.sensitivity(i))),
+            }
+        }
+        match self.sigma() {
+            sigma @ Num(_) => lines.push(format!("σ_{} = {}", self.name, sigma)),
+            sigma => lines.push(format!("σ_{} = {} = {}", self.name, sigma, self.error())),
+        }
+        lines
+    }
+
+    /// Returns the formulas as LaTeX math, one line each: the expression, each partial
+    /// derivative and the uncertainty.
+    pub fn to_latex(&self) -> Vec<String> {
+        let name = latex_name(&self.name);
+        let mut lines = vec![format!("{} = {}", name, self.expression.to_latex())];
+        for (input, partial) in self.inputs.iter().zip(&self.partials) {
+            lines.push(format!("\\frac{{\\partial {}}}{{\\partial {}}} = {}", name, latex_name(&input.name), partial.to_latex()));
+        }
+        lines.push(format!("\\sigma_{{{}}} = {}", name, self.sigma().to_latex()));
+        lines
+    }
+}
+
+impl Formula {
+    /// Returns what `formula(expr)` prints: the formula as `Display` shows it, with the
+    /// value `expr` evaluated to (`result`) after `σ_f`. That value is propagated operator
+    /// by operator, so its uncertainty differs from `σ_f` when an input is repeated.
+    pub fn report(&self, result: &impl fmt::Display) -> String {
+        self.lines(Some(result.to_string())).join("\n")
+    }
+
+    fn lines(&self, result: Option<String>) -> Vec<String> {
+        let mut lines = vec![format!("Uncertainty formula for {}", self.name)];
+        lines.extend(self.to_text().into_iter().map(|line| format!("  {}", line)));
+        if let Some(result) = result {
+            lines.push(format!("  evaluates to {} (propagated operator by operator)", result));
+        }
+        if !self.inputs.is_empty() {
+            lines.push("  inputs:".to_string());
+        }
+        for input in &self.inputs {
+            let line = format!("    {} = {} +/- {}", input.name, input.value, input.error);
+            match input.source != input.name {
+                true => lines.push(format!("{} ({})", line, input.source)),
+                false => lines.push(line),
+            }
+        }
+        lines.push("  LaTeX:".to_string());
+        lines.extend(self.to_latex().into_iter().map(|line| format!("    {}", line)));
+        lines
+    }
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.lines(None).join("\n"))
+    }
+}
+
+/// Derives the uncertainty formula of an expression.
+///
+/// # Arguments
+///
+/// * `expression` - The expression to analyze
+/// * `vars` - Runtime variables, used to follow variable definitions
+///
+/// # Returns
+///
+/// The formula, with a partial derivative for every source measurement
+pub fn formula<N: Number>(expression: &Expression, vars: &HashMap<String, VarRunTime<N>>) -> Formula {
+    let mut builder = Builder { vars, inputs: Vec::new(), values: HashMap::new(), next: 0 };
+    let symbolic = builder.build(expression, "", true);
+    let name = match expression {
+        Expression::Variable { name, .. } => name.clone(),
+        _ => "f".to_string(),
+    };
+    let partials = builder.inputs.iter().map(|input| symbolic.derivative(&input.name)).collect();
+    Formula { name, expression: symbolic, inputs: builder.inputs, partials, values: builder.values }
+}
+
+/// Builds the symbolic form of an expression, collecting its inputs.
+struct Builder<'a, N> {
+    vars: &'a HashMap<String, VarRunTime<N>>,
+    inputs: Vec<FormulaInput>,
+    values: HashMap<String, f64>,
+
+    /// Number of the last `q` input
+    next: usize,
+}
+
+impl<N: Number> Builder<'_, N> {
+    /// Builds an expression inside the definition of `owner` ("" at top level). With
+    /// `record` off (for the operands of `nominal` and the right side of `+/-`, whose
+    /// uncertainty is dropped), no inputs are recorded.
+    fn build(&mut self, expression: &Expression, owner: &str, record: bool) -> Symbolic {
+        match expression {
+            Expression::NumberWithUncertainty { value, error, .. } => {
+                if *error == 0.0 || !record {
+                    return Num(*value);
+                }
+                let source = source_name(expression, owner).unwrap_or_default();
+                self.input(None, source, *value, *error)
+            },
+            Expression::Variable { name, .. } => self.variable(name, record),
+            Expression::Binary { left, op: BinaryOp::PlusMinus, right, .. } => {
+                let left = self.build(left, owner, record);
+                let error = self.build(right, owner, false).evaluate(&self.values).abs();
+                if error == 0.0 || !record {
+                    return left;
+                }
+                let source = source_name(expression, owner).unwrap_or_default();
+                left + self.input(None, source, 0.0, error)
+            },
+            Expression::Binary { left, op, right, .. } => {
+                let (a, b) = (self.build(left, owner, record), self.build(right, owner, record));
+                match op {
+                    BinaryOp::Add => a + b,
+                    BinaryOp::Sub => a - b,
+                    BinaryOp::Mul => a * b,
+                    BinaryOp::Div => a / b,
+                    BinaryOp::Mod => a.modulo(b),
+                    BinaryOp::Power => a.pow(b),
+                    BinaryOp::Root => a.pow(Num(1.0) / b),
+                    BinaryOp::PlusMinus => unreachable!(),
+                    op => Symbolic::compare(op.clone(), a, b),
+                }
+            },
+            Expression::Unary { op: UnaryOp::Plus, operand, .. } => self.build(operand, owner, record),
+            Expression::Unary { op: UnaryOp::Minus, operand, .. } => -self.build(operand, owner, record),
+            Expression::Unary { op: UnaryOp::Not, operand, .. } => !self.build(operand, owner, record),
+            Expression::FunctionCall { name, args, .. } if name == NOMINAL_FUNCTION => {
+                Symbolic::call(name, vec![self.build(&args[0], owner, false)])
+            },
+            Expression::FunctionCall { name, args, .. } if lookup_std_function(name).is_some() => {
+                let args = args.iter().map(|arg| self.build(arg, owner, record)).collect();
+                Symbolic::call(name, args)
+            },
+            // `budget`, `formula` and the assertions evaluate to their first argument
+            Expression::FunctionCall { args, .. } => self.build(&args[0], owner, record),
+        }
+    }
+
+    /// Builds a variable: its definition if it was computed, otherwise a symbol (which is
+    /// an input if it is uncertain).
+    fn variable(&mut self, name: &str, record: bool) -> Symbolic {
+        let Some(var) = self.vars.get(name) else { return Symbolic::Symbol(name.to_string()) };
+        let (value, error) = match var.get_definition() {
+            Some(Expression::NumberWithUncertainty { value, error, .. }) => (*value, *error),
+            Some(definition) => return self.build(definition, name, record),
+            None => (var.get_value().value(), var.get_value().error()),
+        };
+        self.values.insert(name.to_string(), value);
+        if error == 0.0 || !record {
+            return Symbolic::Symbol(name.to_string());
+        }
+        self.input(Some(name), name.to_string(), value, error)
+    }
+
+    /// Returns the symbol of an input, adding it the first time its source is seen.
+    fn input(&mut self, name: Option<&str>, source: String, value: f64, error: f64) -> Symbolic {
+        if let Some(input) = self.inputs.iter().find(|input| input.source == source) {
+            return Symbolic::Symbol(input.name.clone());
+        }
+        let name = match name {
+            Some(name) => name.to_string(),
+            None => self.fresh_name(),
+        };
+        self.values.insert(name.clone(), value);
+        self.inputs.push(FormulaInput { name: name.clone(), source, value, error });
+        Symbolic::Symbol(name)
+    }
+
+    /// Returns the next `q` name that is not a variable.
+    fn fresh_name(&mut self) -> String {
+        loop {
+            self.next += 1;
+            let name = format!("q{}", self.next);
+            if !self.vars.contains_key(&name) && !self.values.contains_key(&name) {
+                return name;
+            }
+        }
+    }
+}
@@ -14,10 +14,7 @@ use super::*;
 /// Helper function to create tokens with dummy position info for testing
 fn create_tokens(tokens: Vec<Token>) -> Vec<TokenWithPos> {
     tokens.into_iter().enumerate().map(|(i, token)| {
-        TokenWithPos {
-            token,
-            position: Position { line: 1, column: i + 1 },
-        }
+        TokenWithPos::new(token, Position { line: 1, column: i + 1 })
     }).collect()
 }
 
@@ -49,29 +46,30 @@ fn assert_position(actual: &Position, expected: &Position) {
 fn assert_expression(actual: &Expression, expected: &Expression) {
     match (actual, expected) {
         (
-            Expression::NumberWithUncertainty { value: av, error: ae, pos: apos },
-            Expression::NumberWithUncertainty { value: ev, error: ee, pos: epos }
+            Expression::NumberWithUncertainty { value: av, error: ae, pos: apos, .. },
+            Expression::NumberWithUncertainty { value: ev, error: ee, pos: epos, .. }
         ) => {
             assert_expression_number_with_uncertainty(av, ae, apos, ev, ee, epos);
         }
-        (Expression::Variable(aname), Expression::Variable(ename)) => {
+        (Expression::Variable { name: aname, pos: apos, .. }, Expression::Variable { name: ename, pos: epos, .. }) => {
             assert_expression_variable(aname, ename);
+            assert_position(apos, epos);
         }
         (
-            Expression::Binary { left: al, op: aop, right: ar, pos: apos },
-            Expression::Binary { left: el, op: eop, right: er, pos: epos }
+            Expression::Binary { left: al, op: aop, right: ar, pos: apos, .. },
+            Expression::Binary { left: el, op: eop, right: er, pos: epos, .. }
         ) => {
             assert_expression_binary(al, aop, ar, apos, el, eop, er, epos);
         }
         (
-            Expression::Unary { op: aop, operand: aoperand, pos: apos },
-            Expression::Unary { op: eop, operand: eoperand, pos: epos }
+            Expression::Unary { op: aop, operand: aoperand, pos: apos, .. },
+            Expression::Unary { op: eop, operand: eoperand, pos: epos, .. }
         ) => {
             assert_expression_unary(aop, aoperand, apos, eop, eoperand, epos);
         }
         (
-            Expression::FunctionCall { name: aname, args: aargs, pos: apos },
-            Expression::FunctionCall { name: ename, args: eargs, pos: epos }
+            Expression::FunctionCall { name: aname, args: aargs, pos: apos, .. },
+            Expression::FunctionCall { name: ename, args: eargs, pos: epos, .. }
         ) => {
             assert_expression_function_call(aname, aargs, apos, ename, eargs, epos);
         }
@@ -194,14 +192,14 @@ fn assert_expression_function_call(
 fn assert_statement(actual: &Statement, expected: &Statement) {
     match (actual, expected) {
         (
-            Statement::VarDeclaration { name: an, var_type: at, pos: ap },
-            Statement::VarDeclaration { name: en, var_type: et, pos: ep }
+            Statement::VarDeclaration { name: an, var_type: at, pos: ap, .. },
+            Statement::VarDeclaration { name: en, var_type: et, pos: ep, .. }
         ) => {
             assert_statement_var_declaration(an, at, ap, en, et, ep);
         }
         (
-            Statement::Assignment { name: an, value: av, pos: ap },
-            Statement::Assignment { name: en, value: ev, pos: ep }
+            Statement::Assignment { name: an, value: av, pos: ap, .. },
+            Statement::Assignment { name: en, value: ev, pos: ep, .. }
         ) => {
             assert_statement_assignment(an, av, ap, en, ev, ep);
         }
@@ -285,7 +283,10 @@ fn test_simple_number() {
         Expression::NumberWithUncertainty {
             value: 42.5,
             error: 0.0,
-            pos: Position { line: 1, column: 1 }
+            pos: Position { line: 1, column: 1 },
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }
     );
     
@@ -310,13 +311,174 @@ fn test_number_with_uncertainty() {
         Expression::NumberWithUncertainty {
             value: 12.3,
             error: 0.5,
-            pos: Position { line: 1, column: 1 }
+            pos: Position { line: 1, column: 1 },
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }
     );
     
     assert_statement(&program.statements[0], &expected);
 }
 
+#[test]
+fn test_number_with_relative_uncertainty() {
+    let tokens = vec![
+        Token::Number(12.3),
+        Token::PlusMinus,
+        Token::RelativeNumber(2.0, RelativeUnit::Percent),
+        Token::EOF,
+    ];
+
+    let program = parse_tokens(tokens).unwrap();
+    match &program.statements[0] {
+        Statement::Expression(Expression::NumberWithUncertainty { value, error, notation, .. }) => {
+            assert!((value - 12.3).abs() < 1e-10);
+            assert!((error - 0.246).abs() < 1e-10, "expected error 0.246, got {}", error);
+            assert_eq!(notation, &Some(UncertaintyNotation::Relative { amount: 2.0, unit: RelativeUnit::Percent }));
+        }
+        other => panic!("Expected NumberWithUncertainty, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_plus_minus_variables() {
+    let tokens = vec![
+        Token::Identifier("x".to_string()),
+        Token::PlusMinus,
+        Token::Identifier("dx".to_string()),
+        Token::EOF,
+    ];
+
+    let program = parse_tokens(tokens).unwrap();
+
+    let expected = Statement::Expression(
+        Expression::Binary {
+            left: Box::new(Expression::Variable { name: "x".to_string(), pos: Position { line: 1, column: 1 }, span: Span::default() }),
+            op: BinaryOp::PlusMinus,
+            right: Box::new(Expression::Variable { name: "dx".to_string(), pos: Position { line: 1, column: 3 }, span: Span::default() }),
+            pos: Position { line: 1, column: 2 },
+            span: Span::default(),
+        }
+    );
+
+    assert_statement(&program.statements[0], &expected);
+}
+
+#[test]
+fn test_plus_minus_binds_tighter_than_multiplication() {
+    // a * x +/- (b / 2)  parses as  a * (x +/- (b / 2))
+    let tokens = vec![
+        Token::Identifier("a".to_string()),
+        Token::Multiply,
+        Token::Identifier("x".to_string()),
+        Token::PlusMinus,
+        Token::LeftParen,
+        Token::Identifier("b".to_string()),
+        Token::Divide,
+        Token::Number(2.0),
+        Token::RightParen,
+        Token::EOF,
+    ];
+
+    let program = parse_tokens(tokens).unwrap();
+
+    let expected = Statement::Expression(
+        Expression::Binary {
+            left: Box::new(Expression::Variable { name: "a".to_string(), pos: Position { line: 1, column: 1 }, span: Span::default() }),
+            op: BinaryOp::Mul,
+            right: Box::new(Expression::Binary {
+                left: Box::new(Expression::Variable { name: "x".to_string(), pos: Position { line: 1, column: 3 }, span: Span::default() }),
+                op: BinaryOp::PlusMinus,
+                right: Box::new(Expression::Binary {
+                    left: Box::new(Expression::Variable { name: "b".to_string(), pos: Position { line: 1, column: 6 }, span: Span::default() }),
+                    op: BinaryOp::Div,
+                    right: Box::new(Expression::NumberWithUncertainty {
+                        value: 2.0,
+                        error: 0.0,
+                        pos: Position { line: 1, column: 8 },
+                        notation: None,
+                        literal: None,
+                        span: Span::default(),
+                    }),
+                    pos: Position { line: 1, column: 6 },
+                    span: Span::default(),
+                }),
+                pos: Position { line: 1, column: 4 },
+                span: Span::default(),
+            }),
+            pos: Position { line: 1, column: 1 },
+            span: Span::default(),
+        }
+    );
+
+    assert_statement(&program.statements[0], &expected);
+}
+
+#[test]
+fn test_plus_minus_chain_is_left_associative() {
+    let tokens = vec![
+        Token::Number(12.3),
+        Token::PlusMinus,
+        Token::Number(0.5),
+        Token::PlusMinus,
+        Token::Identifier("dx".to_string()),
+        Token::EOF,
+    ];
+
+    let program = parse_tokens(tokens).unwrap();
+
+    let expected = Statement::Expression(
+        Expression::Binary {
+            left: Box::new(Expression::NumberWithUncertainty {
+                value: 12.3,
+                error: 0.5,
+                pos: Position { line: 1, column: 1 },
+                notation: None,
+                literal: None,
+                span: Span::default(),
+            }),
+            op: BinaryOp::PlusMinus,
+            right: Box::new(Expression::Variable { name: "dx".to_string(), pos: Position { line: 1, column: 5 }, span: Span::default() }),
+            pos: Position { line: 1, column: 4 },
+            span: Span::default(),
+        }
+    );
+
+    assert_statement(&program.statements[0], &expected);
+}
+
+#[test]
+fn test_relative_uncertainty_requires_literal() {
+    let tokens = vec![
+        Token::Identifier("x".to_string()),
+        Token::PlusMinus,
+        Token::RelativeNumber(2.0, RelativeUnit::Percent),
+        Token::EOF,
+    ];
+
+    assert!(parse_tokens(tokens).is_err());
+}
+
+#[test]
+fn test_number_with_asymmetric_uncertainty() {
+    let tokens = vec![
+        Token::Number(12.3),
+        Token::AsymmetricError(0.4, 0.2),
+        Token::EOF,
+    ];
+
+    let program = parse_tokens(tokens).unwrap();
+    match &program.statements[0] {
+        Statement::Expression(Expression::NumberWithUncertainty { value, error, notation, .. }) => {
+            assert!((value - 12.3).abs() < 1e-10);
+            assert!((error - 0.4).abs() < 1e-10);
+            assert_eq!(notation, &Some(UncertaintyNotation::Asymmetric { upper: 0.4, lower: 0.2 }));
+        }
+        other => panic!("Expected NumberWithUncertainty, got {:?}", other),
+    }
+}
+
 #[test]
 fn test_variable_declaration() {
     let tokens = vec![
@@ -336,7 +498,8 @@ fn test_variable_declaration() {
     let expected = Statement::VarDeclaration {
         name: "x".to_string(),
         var_type: VarType::Real,
-        pos: Position { line: 1, column: 1 }
+        pos: Position { line: 1, column: 1 },
+        span: Span::default(),
     };
     
     assert_statement(&program.statements[0], &expected);
@@ -363,9 +526,13 @@ fn test_assignment() {
         value: Expression::NumberWithUncertainty {
             value: 5.0,
             error: 0.1,
-            pos: Position { line: 1, column: 3 }
+            pos: Position { line: 1, column: 3 },
+            notation: None,
+            literal: None,
+            span: Span::default(),
         },
-        pos: Position { line: 1, column: 1 }
+        pos: Position { line: 1, column: 1 },
+        span: Span::default(),
     };
     
     assert_statement(&program.statements[0], &expected);
@@ -384,7 +551,7 @@ fn test_variable_reference() {
     assert_eq!(program.statements.len(), 1);
     
     let expected = Statement::Expression(
-        Expression::Variable("x".to_string())
+        Expression::Variable { name: "x".to_string(), pos: Position { line: 1, column: 1 }, span: Span::default() }
     );
     
     assert_statement(&program.statements[0], &expected);
@@ -406,10 +573,11 @@ fn test_binary_addition() {
     
     let expected = Statement::Expression(
         Expression::Binary {
-            left: Box::new(Expression::Variable("x".to_string())),
+            left: Box::new(Expression::Variable { name: "x".to_string(), pos: Position { line: 1, column: 1 }, span: Span::default() }),
             op: BinaryOp::Add,
-            right: Box::new(Expression::Variable("y".to_string())),
-            pos: Position { line: 1, column: 1 }
+            right: Box::new(Expression::Variable { name: "y".to_string(), pos: Position { line: 1, column: 3 }, span: Span::default() }),
+            pos: Position { line: 1, column: 1 },
+            span: Span::default(),
         }
     );
     
@@ -435,9 +603,13 @@ fn test_unary_minus() {
             operand: Box::new(Expression::NumberWithUncertainty {
                 value: 5.0,
                 error: 0.0,
-                pos: Position { line: 1, column: 2 }
+                pos: Position { line: 1, column: 2 },
+                notation: None,
+                literal: None,
+                span: Span::default(),
             }),
-            pos: Position { line: 1, column: 1 }
+            pos: Position { line: 1, column: 1 },
+            span: Span::default(),
         }
     );
     
@@ -462,7 +634,10 @@ fn test_parenthesized_expression() {
         Expression::NumberWithUncertainty {
             value: 42.0,
             error: 0.0,
-            pos: Position { line: 1, column: 2 }
+            pos: Position { line: 1, column: 2 },
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }
     );
     
@@ -487,7 +662,8 @@ fn test_function_call_no_args() {
         Expression::FunctionCall {
             name: "sqrt".to_string(),
             args: vec![],
-            pos: Position { line: 1, column: 1 }
+            pos: Position { line: 1, column: 1 },
+            span: Span::default(),
         }
     );
     
@@ -518,15 +694,22 @@ fn test_function_call_with_args() {
                 Expression::NumberWithUncertainty {
                     value: 2.0,
                     error: 0.0,
-                    pos: Position { line: 1, column: 3 }
+                    pos: Position { line: 1, column: 3 },
+                    notation: None,
+                    literal: None,
+                    span: Span::default(),
                 },
                 Expression::NumberWithUncertainty {
                     value: 3.0,
                     error: 0.0,
-                    pos: Position { line: 1, column: 5 }
+                    pos: Position { line: 1, column: 5 },
+                    notation: None,
+                    literal: None,
+                    span: Span::default(),
                 }
             ],
-            pos: Position { line: 1, column: 1 }
+            pos: Position { line: 1, column: 1 },
+            span: Span::default(),
         }
     );
     
@@ -559,7 +742,8 @@ fn test_multiple_statements() {
     let expected_decl = Statement::VarDeclaration {
         name: "x".to_string(),
         var_type: VarType::Real,
-        pos: Position { line: 1, column: 1 }
+        pos: Position { line: 1, column: 1 },
+        span: Span::default(),
     };
     assert_statement(&program.statements[0], &expected_decl);
     
@@ -569,15 +753,19 @@ fn test_multiple_statements() {
         value: Expression::NumberWithUncertainty {
             value: 5.0,
             error: 0.0,
-            pos: Position { line: 1, column: 9 }
+            pos: Position { line: 1, column: 9 },
+            notation: None,
+            literal: None,
+            span: Span::default(),
         },
-        pos: Position { line: 1, column: 7 }
+        pos: Position { line: 1, column: 7 },
+        span: Span::default(),
     };
     assert_statement(&program.statements[1], &expected_assign);
     
     // Check expression
     let expected_expr = Statement::Expression(
-        Expression::Variable("x".to_string())
+        Expression::Variable { name: "x".to_string(), pos: Position { line: 1, column: 11 }, span: Span::default() }
     );
     assert_statement(&program.statements[2], &expected_expr);
 }
@@ -604,24 +792,35 @@ fn test_operator_precedence() {
             left: Box::new(Expression::NumberWithUncertainty {
                 value: 2.0,
                 error: 0.0,
-                pos: Position { line: 1, column: 1 }
+                pos: Position { line: 1, column: 1 },
+                notation: None,
+                literal: None,
+                span: Span::default(),
             }),
             op: BinaryOp::Add,
             right: Box::new(Expression::Binary {
                 left: Box::new(Expression::NumberWithUncertainty {
                     value: 3.0,
                     error: 0.0,
-                    pos: Position { line: 1, column: 3 }
+                    pos: Position { line: 1, column: 3 },
+                    notation: None,
+                    literal: None,
+                    span: Span::default(),
                 }),
                 op: BinaryOp::Mul,
                 right: Box::new(Expression::NumberWithUncertainty {
                     value: 4.0,
                     error: 0.0,
-                    pos: Position { line: 1, column: 5 }
+                    pos: Position { line: 1, column: 5 },
+                    notation: None,
+                    literal: None,
+                    span: Span::default(),
                 }),
-                pos: Position { line: 1, column: 3 }
+                pos: Position { line: 1, column: 3 },
+                span: Span::default(),
             }),
-            pos: Position { line: 1, column: 1 }
+            pos: Position { line: 1, column: 1 },
+            span: Span::default(),
         }
     );
     
@@ -660,9 +859,132 @@ fn test_skip_comments_and_newlines() {
         Expression::NumberWithUncertainty {
             value: 42.0,
             error: 0.0,
-            pos: Position { line: 1, column: 3 }
+            pos: Position { line: 1, column: 3 },
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }
     );
     
     assert_statement(&program.statements[0], &expected);
-}
\ No newline at end of file
+}
+#[test]
+fn test_import_statements() {
+    let path = |p: &str| Token::StringLiteral(p.to_string());
+    let id = |name: &str| Token::Identifier(name.to_string());
+    let tokens = vec![
+        Token::Import, path("lib/calibration.sig"), Token::Newline,
+        Token::Import, path("lib/calibration.sig"), Token::As, id("cal"), Token::Newline,
+        Token::Import, path("shared.sig"), Token::LeftParen, id("offset"), Token::Comma, id("gain"), Token::RightParen,
+        Token::EOF,
+    ];
+
+    let program = parse_tokens(tokens).unwrap();
+    let names: Vec<&ImportNames> = program.statements.iter().map(|statement| match statement {
+        Statement::Import { names, .. } => names,
+        other => panic!("Expected import, got {:?}", other),
+    }).collect();
+
+    assert_eq!(names, vec![
+        &ImportNames::Namespace("calibration".to_string()),
+        &ImportNames::Namespace("cal".to_string()),
+        &ImportNames::Selected(vec!["offset".to_string(), "gain".to_string()]),
+    ]);
+}
+
+#[test]
+fn test_import_needs_identifier_namespace() {
+    let tokens = vec![Token::Import, Token::StringLiteral("my-calibration.sig".to_string()), Token::EOF];
+    let error = parse_tokens(tokens).unwrap_err();
+    assert!(error.message.contains("name one with 'as'"));
+
+    let tokens = vec![Token::Import, Token::Identifier("calibration".to_string()), Token::EOF];
+    assert!(parse_tokens(tokens).is_err());
+}
+
+#[test]
+fn test_recovery_reports_every_syntax_error() {
+    let id = |name: &str| Token::Identifier(name.to_string());
+    let tokens = vec![
+        Token::LeftBrace, id("x"), Token::Colon, Token::Real, Token::RightBrace, Token::Newline,
+        id("x"), Token::Assign, Token::Multiply, Token::Number(2.0), Token::Newline,
+        Token::LeftBrace, id("y"), Token::Real, Token::RightBrace, Token::Newline,
+        id("x"), Token::Assign, Token::Number(1.0), Token::Newline,
+        Token::LeftParen, Token::Number(3.0), Token::EOF,
+    ];
+
+    let (program, errors) = AstParser::new().parse_program_recovering(create_tokens(tokens.clone()));
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].position, Position { line: 1, column: 9 });
+    assert_eq!(errors[1].position, Position { line: 1, column: 14 });
+    assert_eq!(errors[2].position, Position { line: 1, column: 23 });
+
+    assert_eq!(program.statements.len(), 2);
+    assert!(matches!(&program.statements[0], Statement::VarDeclaration { name, .. } if name == "x"));
+    assert!(matches!(&program.statements[1], Statement::Assignment { name, .. } if name == "x"));
+
+    let error = parse_tokens(tokens).unwrap_err();
+    assert_eq!(error.position, Position { line: 1, column: 9 });
+}
+
+#[test]
+fn test_recovery_after_error_at_end_of_line() {
+    // `x := 1 +` runs into the newline, which must still end the statement
+    let id = |name: &str| Token::Identifier(name.to_string());
+    let tokens = vec![
+        id("x"), Token::Assign, Token::Number(1.0), Token::Plus, Token::Newline,
+        Token::LeftBrace, id("y"), Token::Colon, Token::Real, Token::RightBrace, Token::EOF,
+    ];
+
+    let (program, errors) = AstParser::new().parse_program_recovering(create_tokens(tokens));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "Expected expression");
+    assert_eq!(errors[0].position, Position { line: 1, column: 5 });
+
+    assert_eq!(program.statements.len(), 1);
+    assert!(matches!(&program.statements[0], Statement::VarDeclaration { name, .. } if name == "y"));
+}
+
+#[test]
+fn test_recovery_without_errors() {
+    let tokens = vec![Token::Number(1.0), Token::Newline, Token::Number(2.0), Token::EOF];
+    let (program, errors) = AstParser::new().parse_program_recovering(create_tokens(tokens));
+    assert!(errors.is_empty());
+    assert_eq!(program.statements.len(), 2);
+}
+
+/// Parses a single expression statement from source text
+fn parse_source_expression(source: &str) -> Result<Expression, ParseError> {
+    let tokens = crate::significance::tokenizer::Tokenizer::new(source).tokenize().unwrap();
+    match AstParser::new().parse_program(tokens)?.statements.remove(0) {
+        Statement::Expression(expression) => Ok(expression),
+        other => panic!("Expected an expression statement, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_comparison_and_logical_precedence() {
+    // a + 1 > b && !c || d == 2  is  ((a + 1 > b) && !c) || (d == 2)
+    let expression = parse_source_expression("a + 1 > b && !c || d == 2").unwrap();
+    let Expression::Binary { left: or_left, op: BinaryOp::Or, right: or_right, .. } = expression else {
+        panic!("Expected ||, got {:?}", expression);
+    };
+    assert!(matches!(*or_right, Expression::Binary { op: BinaryOp::Equal, .. }));
+    let Expression::Binary { left: and_left, op: BinaryOp::And, right: and_right, .. } = *or_left else {
+        panic!("Expected &&, got {:?}", or_left);
+    };
+    assert!(matches!(*and_right, Expression::Unary { op: UnaryOp::Not, .. }));
+    let Expression::Binary { left, op: BinaryOp::Greater, .. } = *and_left else {
+        panic!("Expected >, got {:?}", and_left);
+    };
+    assert!(matches!(*left, Expression::Binary { op: BinaryOp::Add, .. }));
+}
+
+#[test]
+fn test_comparisons_do_not_chain() {
+    let error = parse_source_expression("1 < x <= 3").unwrap_err();
+    assert_eq!(error.message, "Comparisons cannot be chained; combine them with '&&'");
+    assert_eq!(error.position, Position { line: 1, column: 7 });
+
+    assert!(parse_source_expression("(1 < x) == (x <= 3)").is_ok());
+}
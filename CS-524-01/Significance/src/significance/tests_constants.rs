@@ -0,0 +1,145 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use crate::significance::constants::{lookup_constant, CONSTANTS};
+use crate::significance::semantic_analyzer::{SemanticAnalyzer, SemanticError, SemanticWarning};
+use crate::significance::executor::Executor;
+use crate::significance::lints::Lint;
+use crate::significance::tokenizer::Tokenizer;
+use crate::significance::ast_parser::{AstParser, Program, Statement};
+use crate::significance::numbers::{Number, Real};
+use crate::significance::decimal::Decimal;
+use crate::significance::standard_lib::lookup_std_function;
+
+fn parse(source: &str) -> Program {
+    let tokens = Tokenizer::new(source).tokenize().unwrap();
+    AstParser::new().parse_program(tokens).unwrap()
+}
+
+fn analyze(source: &str) -> Vec<SemanticError> {
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.import_standard_library().import_constants_library();
+    analyzer.analyze_program(&parse(source));
+    analyzer.get_errors().clone()
+}
+
+fn warnings(source: &str) -> Vec<SemanticWarning> {
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.import_standard_library().import_constants_library();
+    analyzer.analyze_program(&parse(source));
+    analyzer.get_warnings().clone()
+}
+
+fn evaluate<N: Number>(source: &str) -> N {
+    let mut executor = Executor::<N>::with_backend();
+    let mut result = N::new(f64::NAN);
+    for statement in &parse(source).statements {
+        match statement {
+            Statement::Expression(expression) => result = executor.evaluate_expression(expression),
+            _ => executor.execute_statement(statement),
+        }
+    }
+    result
+}
+
+#[test]
+fn test_constant_table_is_consistent() {
+    for constant in CONSTANTS {
+        let value: f64 = constant.value.parse().unwrap();
+        assert!(value.is_finite() && value > 0.0, "{}", constant.name);
+        assert!(constant.error >= 0.0 && constant.error < value, "{}", constant.name);
+        assert!(lookup_std_function(constant.name).is_none(), "{} shadows a function", constant.name);
+        assert_eq!(CONSTANTS.iter().filter(|c| c.name == constant.name).count(), 1, "{}", constant.name);
+    }
+    assert!(lookup_constant("tau").is_some());
+    assert!(lookup_constant("x").is_none());
+}
+
+#[test]
+fn test_constants_are_assigned() {
+    let errors = analyze("{r : real}\nr := 2 +/- 0.1\npi * r ** 2\nG * m_e / r ** 2\n1 +/- k_B");
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test_constants_are_immutable() {
+    let errors = analyze("G := 6.7e-11\n{x : real}\n{x : real}");
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], SemanticError::VariableAlreadyAssigned(ref name, _) if name == "G"));
+    assert!(matches!(errors[1], SemanticError::VariableAlreadyDeclared(ref name, _) if name == "x"));
+}
+
+#[test]
+fn test_declaration_shadows_constant() {
+    assert!(analyze("{c : real}\nc := 0.1 +/- 0.01\nc * 2").is_empty());
+
+    // Once shadowed, `c` is an ordinary variable: it must be assigned and cannot be redeclared
+    let errors = analyze("{c : real}\nc * 2\n{c : real}");
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], SemanticError::VariableNotAssigned(ref name, _) if name == "c"));
+    assert!(matches!(errors[1], SemanticError::VariableAlreadyDeclared(ref name, _) if name == "c"));
+
+    let result: Real = evaluate("{c : real}\nc := 0.1 +/- 0.01\nc * 2");
+    assert_eq!(result.value(), 0.2);
+}
+
+#[test]
+fn test_one_letter_constant_warns() {
+    let found = warnings("{y : real}\ny := 2 * c\ny");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].lint, Lint::OneLetterConstant);
+    assert!(found[0].message.contains("speed of light"));
+
+    assert!(warnings("pi * k_B").is_empty());
+    assert!(warnings("{c : real}\nc := 0.1 +/- 0.01\nc * 2").is_empty());
+}
+
+#[test]
+fn test_uncertain_constant_is_not_exact() {
+    let errors = analyze("1 +/- G");
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], SemanticError::UncertainErrorOperand(..)));
+}
+
+#[test]
+fn test_constant_values() {
+    let result: Real = evaluate("pi");
+    assert_eq!(result.value(), std::f64::consts::PI);
+    assert_eq!(result.error(), 0.0);
+
+    let result: Real = evaluate("ln(e)");
+    assert_eq!(result.value(), 1.0);
+
+    let result: Real = evaluate("G");
+    assert_eq!(result.value(), 6.67430e-11);
+    assert_eq!(result.error(), 1.5e-15);
+}
+
+#[test]
+fn test_constants_survive_reset() {
+    let mut executor = Executor::new();
+    executor.reset();
+    assert_eq!(executor.get_var("c").unwrap().get_value().value(), 299792458.0);
+}
+
+#[test]
+fn test_constants_keep_their_digits_in_decimal() {
+    let result: Decimal = evaluate("pi");
+    assert_eq!(result.to_string(), "3.1415926535897932384626433832795028841971693993751");
+
+    let result: Decimal = evaluate("N_A * q_e - F");
+    assert_eq!(result.to_string(), "0");
+}
+
+#[test]
+fn test_uncertain_constant_in_budget() {
+    let program = parse("G * (5.972e24 +/- 0.0006e24)");
+    let Statement::Expression(expression) = &program.statements[0] else { panic!("expected an expression") };
+    let budget = Executor::new().error_budget(expression);
+    assert_eq!(budget.entries.len(), 2);
+    assert!(budget.entries.iter().any(|entry| entry.source == "G"));
+}
@@ -0,0 +1,165 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use crate::significance::ast_parser::{AstParser, BinaryOp, Program, UnaryOp};
+use crate::significance::debugger::{debug, Debugger};
+use crate::significance::executor::{Executor, TraceOperation};
+use crate::significance::language_parser::Significance;
+use crate::significance::numbers::Number;
+use crate::significance::tokenizer::Tokenizer;
+
+const PROGRAM: &str = "{x : real}\n{y : real}\n\nx := 12.3 +/- 0.5\ny := x * 2\ny\n";
+
+fn parse(source: &str) -> Program {
+    let tokens = Tokenizer::new(source).tokenize().unwrap();
+    AstParser::new().parse_program(tokens).unwrap()
+}
+
+fn debugger(source: &str) -> Debugger {
+    let mut interpreter = Significance::new();
+    interpreter.set_constant_folding(false);
+    Debugger::new(interpreter, "main.sig", source, None).unwrap()
+}
+
+/// Runs a scripted session, returning everything it wrote
+fn session(source: &str, commands: &str) -> String {
+    let mut output = Vec::new();
+    debug(debugger(source), commands.as_bytes(), &mut output).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn test_trace_records_each_sub_expression() {
+    let mut executor = Executor::new();
+    executor.set_quiet(true);
+    executor.execute_program(&parse("{x : real}\nx := 12.3 +/- 0.5\n"));
+    executor.set_trace(true);
+    executor.execute_program(&parse("-x * sqrt(4)\n"));
+
+    let trace = executor.take_trace();
+    let operations: Vec<(usize, &TraceOperation)> = trace.iter().map(|entry| (entry.depth, &entry.operation)).collect();
+    assert_eq!(operations, vec![
+        (2, &TraceOperation::Variable("x".to_string())),
+        (1, &TraceOperation::Unary(UnaryOp::Minus)),
+        (2, &TraceOperation::Literal),
+        (1, &TraceOperation::Call("sqrt".to_string())),
+        (0, &TraceOperation::Binary(BinaryOp::Mul)),
+    ]);
+
+    let product = &trace[4];
+    assert_eq!((product.pos.line, product.pos.column), (1, 1));
+    assert_eq!(product.operands.iter().map(|operand| operand.value()).collect::<Vec<_>>(), vec![-12.3, 2.0]);
+    assert_eq!(product.operands[0].error(), 0.5);
+    assert_eq!(product.result.value(), -24.6);
+    assert_eq!(product.to_string(), "  1:1  (-12.3 +/- 0.5) * (2) = -24.6 +/- 1");
+    assert_eq!(trace[3].to_string(), "  1:6    sqrt(4) = 2");
+
+    // Taking the trace empties it, and nothing is recorded once trace mode is off
+    assert!(executor.take_trace().is_empty());
+    executor.set_trace(false);
+    executor.execute_program(&parse("x + 1\n"));
+    assert!(executor.take_trace().is_empty());
+}
+
+#[test]
+fn test_trace_of_logical_operators() {
+    let mut executor = Executor::new();
+    executor.set_quiet(true);
+    executor.set_trace(true);
+    executor.execute_program(&parse("!1 && 1 / 0\n"));
+
+    let trace = executor.take_trace();
+    let operations: Vec<&TraceOperation> = trace.iter().map(|entry| &entry.operation).collect();
+    assert_eq!(operations, vec![
+        &TraceOperation::Literal,
+        &TraceOperation::Unary(UnaryOp::Not),
+        &TraceOperation::Binary(BinaryOp::And),
+    ]);
+    assert_eq!(trace[1].to_string(), "  1:1    !(1) = 0");
+    assert_eq!(trace[2].to_string(), "  1:1  (0) && ... = 0");
+}
+
+#[test]
+fn test_step_over_statements() {
+    let mut debugger = debugger(PROGRAM);
+    assert_eq!(debugger.location(), "Stopped at line 1: {x : real}");
+    debugger.handle("next");
+    debugger.handle("n");
+    assert_eq!(debugger.handle("next"), vec!["Stopped at line 5: y := x * 2"]);
+    assert_eq!(debugger.handle("p x"), vec!["x = 12.3 +/- 0.5 (from 12.3 +/- 0.5)"]);
+    assert_eq!(debugger.handle("print z"), vec!["No variable 'z'"]);
+
+    debugger.handle("next");
+    assert_eq!(debugger.handle("next"), vec!["24.6 +/- 1", "Program finished"]);
+    assert!(debugger.is_finished());
+    assert_eq!(debugger.handle("next"), vec!["The program has finished"]);
+}
+
+#[test]
+fn test_breakpoints() {
+    let mut debugger = debugger(PROGRAM);
+    assert_eq!(debugger.handle("break 3"), vec!["No statement on line 3"]);
+    assert_eq!(debugger.handle("b 5"), vec!["Breakpoint at line 5"]);
+    assert_eq!(debugger.handle("continue"), vec!["Stopped at line 5: y := x * 2"]);
+    assert_eq!(debugger.handle("vars"), vec!["x = 12.3 +/- 0.5 (from 12.3 +/- 0.5)", "y = 0"]);
+
+    // Continuing from a breakpoint runs past it
+    assert_eq!(debugger.handle("c"), vec!["24.6 +/- 1", "Program finished"]);
+
+    let mut debugger = self::debugger(PROGRAM);
+    debugger.handle("b 5");
+    assert_eq!(debugger.handle("delete 5"), vec!["Deleted breakpoint at line 5"]);
+    assert_eq!(debugger.handle("d 5"), vec!["No breakpoint at line 5"]);
+    assert_eq!(debugger.handle("c"), vec!["24.6 +/- 1", "Program finished"]);
+}
+
+#[test]
+fn test_trace_while_stepping() {
+    let mut debugger = debugger(PROGRAM);
+    debugger.handle("b 5");
+    debugger.handle("c");
+    assert_eq!(debugger.handle("trace on"), vec!["Trace on"]);
+    assert_eq!(debugger.handle("n"), vec![
+        "  5:6    x = 12.3 +/- 0.5",
+        " 5:10    2 = 2",
+        "  5:6  (12.3 +/- 0.5) * (2) = 24.6 +/- 1",
+        "Stopped at line 6: y",
+    ]);
+    debugger.handle("trace off");
+    assert_eq!(debugger.handle("n"), vec!["24.6 +/- 1", "Program finished"]);
+}
+
+#[test]
+fn test_errors_are_reported() {
+    let source = "{x : real}\nx := 1 / 0\nassert(0)\n";
+    let mut debugger = debugger(source);
+    debugger.handle("n");
+    let lines = debugger.handle("n");
+    assert!(lines[0].starts_with("error: Division by zero"), "{:?}", lines);
+    assert_eq!(lines.last().unwrap(), "Stopped at line 3: assert(0)");
+
+    let lines = debugger.handle("c");
+    assert!(lines[0].starts_with("error: Assertion failed"), "{:?}", lines);
+    assert!(lines.iter().any(|line| line.starts_with("warning: Division by zero")), "{:?}", lines);
+
+    let errors = Debugger::new(Significance::new(), "main.sig", "x := 1\n", None).err().unwrap();
+    assert!(errors[0].starts_with("error: "), "{:?}", errors);
+}
+
+#[test]
+fn test_session() {
+    let output = session(PROGRAM, "b 6\nc\np y\nbogus\nq\nn\n");
+    assert_eq!(output, "Stopped at line 1: {x : real}\n\
+        (sig) Breakpoint at line 6\n\
+        (sig) Stopped at line 6: y\n\
+        (sig) y = 24.6 +/- 1 (from x * 2)\n\
+        (sig) Unknown command 'bogus' (enter 'help' for the commands)\n\
+        (sig) ");
+
+    // The session also ends with the input
+    assert!(session(PROGRAM, "help\n").contains("next (n)"));
+}
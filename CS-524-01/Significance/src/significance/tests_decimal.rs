@@ -0,0 +1,124 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use crate::significance::decimal::{BigDecimal, Decimal, PRECISION};
+use crate::significance::numbers::{Number, Real};
+use crate::significance::executor::Executor;
+use crate::significance::standard_lib::std_lib_call;
+use crate::significance::tokenizer::{Position, Tokenizer};
+use crate::significance::ast_parser::{AstParser, Statement};
+
+fn decimal(text: &str) -> BigDecimal {
+    BigDecimal::parse(text).unwrap()
+}
+
+fn exact(text: &str) -> Decimal {
+    Decimal::from_decimal(decimal(text), 0.0)
+}
+
+fn evaluate(source: &str) -> Decimal {
+    let tokens = Tokenizer::new(source).tokenize().unwrap();
+    let program = AstParser::new().parse_program(tokens).unwrap();
+    let mut executor = Executor::<Decimal>::with_backend();
+    let mut result = Decimal::new(f64::NAN);
+    for statement in &program.statements {
+        match statement {
+            Statement::Expression(expression) => result = executor.evaluate_expression(expression),
+            _ => executor.execute_statement(statement),
+        }
+    }
+    result
+}
+
+#[test]
+fn test_big_decimal_parse_and_display() {
+    assert_eq!(decimal("6.02214076e23").to_string(), "6.02214076e23");
+    assert_eq!(decimal("602214076e15"), decimal("6.02214076e23"));
+    assert_eq!(decimal("1.602176634e-19").to_string(), "1.602176634e-19");
+    assert_eq!(decimal("-0.00120").to_string(), "-0.0012");
+    assert_eq!(decimal("12.50e1").to_string(), "125");
+    assert_eq!(decimal("0.000").to_string(), "0");
+    assert!(BigDecimal::parse("1.2.3").is_none());
+    assert!(BigDecimal::parse("e5").is_none());
+}
+
+#[test]
+fn test_big_decimal_exact_arithmetic() {
+    assert_eq!(&decimal("0.1") + &decimal("0.2"), decimal("0.3"));
+    assert_eq!(&decimal("1") - &decimal("1.000000000000000000001"), decimal("-1e-21"));
+    assert_eq!(&decimal("6.02214076e23") * &decimal("1.602176634e-19"), decimal("96485.3321233100184"));
+    assert_eq!(decimal("7.5").remainder(&decimal("2")), Some(decimal("1.5")));
+    assert_eq!(decimal("-7.5").remainder(&decimal("2")), Some(decimal("-1.5")));
+}
+
+#[test]
+fn test_big_decimal_division_is_rounded_to_precision() {
+    let third = decimal("1").div_rounded(&decimal("3"), PRECISION).unwrap();
+    assert_eq!(third.to_string(), format!("0.{}", "3".repeat(PRECISION)));
+    assert_eq!(decimal("1").div_rounded(&decimal("8"), PRECISION), Some(decimal("0.125")));
+    assert!(decimal("1").div_rounded(&BigDecimal::zero(), PRECISION).is_none());
+}
+
+#[test]
+fn test_big_decimal_roots_and_powers() {
+    assert_eq!(decimal("2.25").nth_root(2, PRECISION), Some(decimal("1.5")));
+    assert_eq!(decimal("1e300").nth_root(3, PRECISION), Some(decimal("1e100")));
+    let root2 = decimal("2").nth_root(2, PRECISION).unwrap();
+    assert!(root2.to_string().starts_with("1.41421356237309504880168872420969807856967187537"));
+
+    assert_eq!(decimal("1.1").powi(3, PRECISION), Some(decimal("1.331")));
+    assert_eq!(decimal("2").powi(-2, PRECISION), Some(decimal("0.25")));
+}
+
+#[test]
+fn test_decimal_keeps_literal_digits() {
+    let result = evaluate("1.000000000000000000001 - 1");
+    assert_eq!(result.to_string(), "1e-21");
+
+    let result = evaluate("0.1 + 0.2");
+    assert_eq!(result.to_string(), "0.3");
+}
+
+#[test]
+fn test_decimal_propagates_uncertainty_like_real() {
+    let result = evaluate("{x : real}\nx := 6.02214076e23 +/- 1e15\nx * 2\n");
+    assert_eq!(result.decimal(), Some(&decimal("1.204428152e24")));
+    let expected = Real::with_error(6.02214076e23, 1e15) * Real::new(2.0);
+    assert_eq!(result.error(), expected.error());
+}
+
+#[test]
+fn test_decimal_std_functions() {
+    let pos = Position { line: 1, column: 1 };
+    let root = std_lib_call("sqrt", &[exact("0.0625")], &pos);
+    assert_eq!(root.decimal(), Some(&decimal("0.25")));
+
+    let sine = std_lib_call("sin", &[exact("1")], &pos);
+    assert_eq!(sine.value(), 1.0_f64.sin());
+}
+
+#[test]
+fn test_decimal_division_by_zero_falls_back_to_f64() {
+    let result = exact("1") / exact("0");
+    assert!(result.decimal().is_none());
+    assert!(result.value().is_infinite());
+}
+
+#[test]
+fn test_big_decimal_far_apart_operands_are_rounded() {
+    // The exact sums would have a hundred million digits
+    assert_eq!(&decimal("1e100000000") + &decimal("1"), decimal("1e100000000"));
+    assert_eq!(&decimal("1") - &decimal("1e-100000000"), decimal("1"));
+    assert_eq!(&decimal("1e1000") + &decimal("1"), decimal(&format!("1{}1", "0".repeat(999))));
+
+    assert!(decimal("1e100000000") > decimal("1"));
+    assert_eq!(decimal("1").remainder(&decimal("1e100000000")), Some(decimal("1")));
+    assert!(decimal("1e100000000").remainder(&decimal("3")).is_none());
+
+    let result = evaluate("1e100000000 + 1");
+    assert_eq!(result.to_string(), "1e100000000");
+}
@@ -0,0 +1,136 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use crate::significance::source_map::{SourceMap, Span, FileId};
+use crate::significance::diagnostic::{Diagnostic, Stage};
+use crate::significance::tokenizer::{Tokenizer, Token, Position};
+use crate::significance::ast_parser::{AstParser, Expression, Statement};
+use crate::significance::language_parser::Significance;
+
+fn pos(line: usize, column: usize) -> Position {
+    Position { line, column }
+}
+
+#[test]
+fn test_source_map_lines() {
+    let mut sources = SourceMap::new();
+    let first = sources.add("a.sig", "x := 1\ny := 2\n");
+    let second = sources.add("<repl>", "1 + 2");
+
+    assert_eq!(first, FileId(0));
+    assert_eq!(second, FileId(1));
+    assert_eq!(sources.name(second), Some("<repl>"));
+    assert_eq!(sources.line(first, 2), Some("y := 2"));
+    assert_eq!(sources.line(first, 0), None);
+    assert_eq!(sources.line(FileId(5), 1), None);
+}
+
+#[test]
+fn test_render_underlines_span() {
+    let mut sources = SourceMap::new();
+    let file = sources.add("lib/calibration.sig", "{y : real}\ny := gain * 2\n");
+    let diagnostic = Diagnostic::new(Stage::Semantic, "Variable 'gain' not declared", Span::on_line(file, pos(2, 6), 4));
+
+    assert_eq!(
+        diagnostic.render(&sources),
+        "error: Variable 'gain' not declared\n --> lib/calibration.sig:2:6\n  |\n2 | y := gain * 2\n  |      ^^^^"
+    );
+    assert_eq!(diagnostic.to_string(), "Error at 2:6: Variable 'gain' not declared");
+}
+
+#[test]
+fn test_render_without_source() {
+    let diagnostic = Diagnostic::new(Stage::Runtime, "Division by zero", Span::default());
+    assert_eq!(diagnostic.render(&SourceMap::new()), "error: Division by zero\n --> <unknown>:1:1");
+}
+
+#[test]
+fn test_token_spans() {
+    let tokens = Tokenizer::with_file("rate := 12.5 +/- 0.3", FileId(3)).tokenize().unwrap();
+
+    assert_eq!(tokens[0].token, Token::Identifier("rate".to_string()));
+    assert_eq!(tokens[0].span, Span::new(FileId(3), pos(1, 1), pos(1, 5)));
+    assert_eq!(tokens[1].span, Span::new(FileId(3), pos(1, 6), pos(1, 8)));
+    assert_eq!(tokens[2].span, Span::new(FileId(3), pos(1, 9), pos(1, 13)));
+    assert_eq!(tokens[3].span, Span::new(FileId(3), pos(1, 14), pos(1, 17)));
+}
+
+#[test]
+fn test_tokenizer_error_span() {
+    let error = Tokenizer::with_file("x := 1\ny := @", FileId(1)).tokenize().unwrap_err();
+
+    assert_eq!(error.stage, Stage::Tokenizer);
+    assert_eq!(error.span, Span::new(FileId(1), pos(2, 6), pos(2, 7)));
+}
+
+#[test]
+fn test_ast_spans() {
+    let tokens = Tokenizer::with_file("{x : real}\nx := sqrt(4) * 2", FileId(2)).tokenize().unwrap();
+    let program = AstParser::new().parse_program(tokens).unwrap();
+
+    let Statement::VarDeclaration { span, .. } = &program.statements[0] else { panic!("expected a declaration") };
+    assert_eq!(*span, Span::new(FileId(2), pos(1, 1), pos(1, 11)));
+
+    let Statement::Assignment { value, span, .. } = &program.statements[1] else { panic!("expected an assignment") };
+    assert_eq!(*span, Span::new(FileId(2), pos(2, 1), pos(2, 17)));
+
+    let Expression::Binary { left, span, .. } = value else { panic!("expected a binary expression") };
+    assert_eq!(*span, Span::new(FileId(2), pos(2, 6), pos(2, 17)));
+    let Expression::FunctionCall { span, .. } = left.as_ref() else { panic!("expected a function call") };
+    assert_eq!(*span, Span::new(FileId(2), pos(2, 6), pos(2, 13)));
+}
+
+#[test]
+fn test_parse_error_diagnostic() {
+    let tokens = Tokenizer::new("x := (1 + 2").tokenize().unwrap();
+    let error = AstParser::new().parse_program(tokens).unwrap_err().to_diagnostic();
+
+    assert_eq!(error.stage, Stage::Parser);
+    assert_eq!(error.span.start, pos(1, 12));
+}
+
+#[test]
+fn test_repl_errors_are_rendered() {
+    let mut sig = Significance::new();
+    let errors = sig.parse_repl("y := 1").unwrap();
+
+    assert_eq!(errors, vec!["error: Variable 'y' not declared\n --> <repl>:1:1\n  |\n1 | y := 1\n  | ^"]);
+}
+
+#[test]
+fn test_warnings_are_rendered() {
+    let mut sig = Significance::new();
+    assert!(sig.parse_repl("2 * c").unwrap().is_empty());
+
+    assert_eq!(sig.warnings().len(), 1);
+    assert!(sig.warnings()[0].starts_with("warning: 'c' refers to the built-in constant"), "{}", sig.warnings()[0]);
+    assert!(sig.warnings()[0].ends_with("\n --> <repl>:1:5\n  |\n1 | 2 * c\n  |     ^"), "{}", sig.warnings()[0]);
+}
+
+#[test]
+fn test_variable_and_group_spans() {
+    let tokens = Tokenizer::with_file("2 * (rate + 1)", FileId(1)).tokenize().unwrap();
+    let program = AstParser::new().parse_program(tokens).unwrap();
+
+    let Statement::Expression(Expression::Binary { right, .. }) = &program.statements[0] else { panic!("expected a product") };
+    assert_eq!(right.span(), Span::new(FileId(1), pos(1, 5), pos(1, 15)));
+    let Expression::Binary { left, .. } = right.as_ref() else { panic!("expected a sum") };
+    assert_eq!(left.position(), pos(1, 6));
+    assert_eq!(left.span(), Span::new(FileId(1), pos(1, 6), pos(1, 10)));
+}
+
+#[test]
+fn test_ast_json_has_variable_positions() {
+    let tokens = Tokenizer::new("rate").tokenize().unwrap();
+    let program = AstParser::new().parse_program(tokens).unwrap();
+    let json = serde_json::to_value(&program).unwrap();
+
+    let variable = &json["statements"][0]["Expression"]["Variable"];
+    assert_eq!(variable["name"], "rate");
+    assert_eq!(variable["pos"]["column"], 1);
+    assert_eq!(variable["span"]["end"]["column"], 5);
+}
@@ -5,10 +5,14 @@
 // Final Project
 //------------------
 
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
 use super::*;
-use crate::significance::ast_parser::{Program, Statement, VarType, Expression, BinaryOp, UnaryOp};
+use crate::significance::ast_parser::{Program, Statement, VarType, Expression, BinaryOp, UnaryOp, UncertaintyNotation};
 use crate::significance::tokenizer::Position;
-use crate::significance::numbers::{Real, assert_real};
+use crate::significance::numbers::{Number, Real, assert_real};
+use crate::significance::propagation::PropagationOrder;
 
 // Helper function to create a dummy position for testing
 fn dummy_pos() -> Position {
@@ -34,6 +38,7 @@ fn test_variable_declaration() {
         name: "x".to_string(),
         var_type: VarType::Real,
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     executor.execute_statement(&declaration);
@@ -54,6 +59,7 @@ fn test_variable_assignment() {
         name: "x".to_string(),
         var_type: VarType::Real,
         pos: dummy_pos(),
+        span: Span::default(),
     };
     executor.execute_statement(&declaration);
     
@@ -64,8 +70,12 @@ fn test_variable_assignment() {
             value: 5.5,
             error: 0.1,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         },
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     executor.execute_statement(&assignment);
@@ -84,6 +94,9 @@ fn test_evaluate_number() {
         value: 42.5,
         error: 0.5,
         pos: dummy_pos(),
+        notation: None,
+        literal: None,
+        span: Span::default(),
     };
     
     let actual = executor.evaluate_expression(&number_expr);
@@ -100,6 +113,7 @@ fn test_evaluate_variable() {
         name: "test_var".to_string(),
         var_type: VarType::Real,
         pos: dummy_pos(),
+        span: Span::default(),
     };
     let assignment = Statement::Assignment {
         name: "test_var".to_string(),
@@ -107,15 +121,19 @@ fn test_evaluate_variable() {
             value: 3.14,
             error: 0.01,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         },
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     executor.execute_statement(&declaration);
     executor.execute_statement(&assignment);
     
     // Now evaluate a variable expression
-    let var_expr = Expression::Variable("test_var".to_string());
+    let var_expr = Expression::Variable { name: "test_var".to_string(), pos: dummy_pos(), span: Span::default() };
     let actual = executor.evaluate_expression(&var_expr);
     let expected = Real::with_error(3.14, 0.01);
     assert_real(&actual, expected);
@@ -131,14 +149,21 @@ fn test_binary_operations() {
             value: 10.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         op: BinaryOp::Add,
         right: Box::new(Expression::NumberWithUncertainty {
             value: 5.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     let actual = executor.evaluate_expression(&add_expr);
@@ -151,14 +176,21 @@ fn test_binary_operations() {
             value: 10.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         op: BinaryOp::Sub,
         right: Box::new(Expression::NumberWithUncertainty {
             value: 3.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     let actual = executor.evaluate_expression(&sub_expr);
@@ -171,14 +203,21 @@ fn test_binary_operations() {
             value: 4.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         op: BinaryOp::Mul,
         right: Box::new(Expression::NumberWithUncertainty {
             value: 3.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     let actual = executor.evaluate_expression(&mul_expr);
@@ -191,14 +230,21 @@ fn test_binary_operations() {
             value: 15.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         op: BinaryOp::Div,
         right: Box::new(Expression::NumberWithUncertainty {
             value: 3.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     let actual = executor.evaluate_expression(&div_expr);
@@ -216,14 +262,21 @@ fn test_power_and_root_operations() {
             value: 2.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         op: BinaryOp::Power,
         right: Box::new(Expression::NumberWithUncertainty {
             value: 3.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     let actual = executor.evaluate_expression(&power_expr);
@@ -236,14 +289,21 @@ fn test_power_and_root_operations() {
             value: 8.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         op: BinaryOp::Root,
         right: Box::new(Expression::NumberWithUncertainty {
             value: 3.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     let actual = executor.evaluate_expression(&root_expr);
@@ -260,14 +320,21 @@ fn test_modulus_operation() {
             value: 17.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         op: BinaryOp::Mod,
         right: Box::new(Expression::NumberWithUncertainty {
             value: 5.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     let actual = executor.evaluate_expression(&mod_expr);
@@ -284,14 +351,21 @@ fn test_division_by_zero() {
             value: 10.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         op: BinaryOp::Div,
         right: Box::new(Expression::NumberWithUncertainty {
             value: 0.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     let result = executor.evaluate_expression(&div_by_zero);
@@ -316,39 +390,48 @@ fn test_complex_expression_with_variables() {
         name: "x".to_string(),
         var_type: VarType::Real,
         pos: dummy_pos(),
+        span: Span::default(),
     });
     executor.execute_statement(&Statement::Assignment {
         name: "x".to_string(),
-        value: Expression::NumberWithUncertainty { value: 5.0, error: 0.0, pos: dummy_pos() },
+        value: Expression::NumberWithUncertainty { value: 5.0, error: 0.0, pos: dummy_pos(), notation: None, literal: None, span: Span::default() },
         pos: dummy_pos(),
+        span: Span::default(),
     });
     
     executor.execute_statement(&Statement::VarDeclaration {
         name: "y".to_string(),
         var_type: VarType::Real,
         pos: dummy_pos(),
+        span: Span::default(),
     });
     executor.execute_statement(&Statement::Assignment {
         name: "y".to_string(),
-        value: Expression::NumberWithUncertainty { value: 3.0, error: 0.0, pos: dummy_pos() },
+        value: Expression::NumberWithUncertainty { value: 3.0, error: 0.0, pos: dummy_pos(), notation: None, literal: None, span: Span::default() },
         pos: dummy_pos(),
+        span: Span::default(),
     });
     
     // Test expression: x * y + 2 = 5 * 3 + 2 = 17
     let complex_expr = Expression::Binary {
         left: Box::new(Expression::Binary {
-            left: Box::new(Expression::Variable("x".to_string())),
+            left: Box::new(Expression::Variable { name: "x".to_string(), pos: dummy_pos(), span: Span::default() }),
             op: BinaryOp::Mul,
-            right: Box::new(Expression::Variable("y".to_string())),
+            right: Box::new(Expression::Variable { name: "y".to_string(), pos: dummy_pos(), span: Span::default() }),
             pos: dummy_pos(),
+            span: Span::default(),
         }),
         op: BinaryOp::Add,
         right: Box::new(Expression::NumberWithUncertainty {
             value: 2.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     let actual = executor.evaluate_expression(&complex_expr);
@@ -365,6 +448,7 @@ fn test_execute_program() {
             name: "result".to_string(),
             var_type: VarType::Real,
             pos: dummy_pos(),
+            span: Span::default(),
         },
         Statement::Assignment {
             name: "result".to_string(),
@@ -373,16 +457,24 @@ fn test_execute_program() {
                     value: 10.0,
                     error: 0.0,
                     pos: dummy_pos(),
+                    notation: None,
+                    literal: None,
+                    span: Span::default(),
                 }),
                 op: BinaryOp::Mul,
                 right: Box::new(Expression::NumberWithUncertainty {
                     value: 2.0,
                     error: 0.0,
                     pos: dummy_pos(),
+                    notation: None,
+                    literal: None,
+                    span: Span::default(),
                 }),
                 pos: dummy_pos(),
+                span: Span::default(),
             },
             pos: dummy_pos(),
+            span: Span::default(),
         },
     ]);
     
@@ -403,6 +495,7 @@ fn test_executor_reset() {
         name: "temp".to_string(),
         var_type: VarType::Real,
         pos: dummy_pos(),
+        span: Span::default(),
     });
     
     assert!(executor.get_var("temp").is_some());
@@ -422,8 +515,12 @@ fn test_unary_operations() {
             value: 5.0,
             error: 0.0,
             pos: dummy_pos(),
+            notation: None,
+            literal: None,
+            span: Span::default(),
         }),
         pos: dummy_pos(),
+        span: Span::default(),
     };
     
     let actual = executor.evaluate_expression(&unary_expr);
@@ -435,13 +532,439 @@ fn test_unary_operations() {
 fn test_undefined_variable_access() {
     let mut executor = Executor::new();
     
-    let var_expr = Expression::Variable("undefined_var".to_string());
+    let var_expr = Expression::Variable { name: "undefined_var".to_string(), pos: Position { line: 3, column: 7 }, span: Span::default() };
     executor.evaluate_expression(&var_expr);
     
     let errors = executor.get_errors();
     assert_eq!(errors.len(), 1);
     match &errors[0] {
-        RunTimeError::UndefinedVariable(_, _) => {}, // Expected
+        RunTimeError::UndefinedVariable(_, pos) => assert_eq!(*pos, Position { line: 3, column: 7 }),
         _ => panic!("Expected UndefinedVariable error"),
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_second_order_propagation() {
+    let mut executor = Executor::new();
+
+    // x * x with x = 0 +/- 1 is a χ² variable: mean 1, variance 2
+    let x = Expression::NumberWithUncertainty { value: 0.0, error: 1.0, pos: dummy_pos(), notation: None, literal: None, span: Span::default() };
+    let square = Expression::Binary {
+        left: Box::new(x.clone()),
+        op: BinaryOp::Power,
+        right: Box::new(Expression::NumberWithUncertainty { value: 2.0, error: 0.0, pos: dummy_pos(), notation: None, literal: None, span: Span::default() }),
+        pos: dummy_pos(),
+        span: Span::default(),
+    };
+
+    let first = executor.evaluate_expression(&square);
+    assert_real(&first, Real::new(0.0));
+
+    let second = executor.evaluate_expression_with_order(&square, PropagationOrder::Second);
+    assert_real(&second, Real::with_error(1.0, 2.0f64.sqrt()));
+
+    // The per-evaluation order does not leak into later evaluations
+    assert_eq!(executor.get_propagation_order(), PropagationOrder::First);
+
+    executor.set_propagation_order(PropagationOrder::Second);
+    let sine = Expression::FunctionCall {
+        name: "sin".to_string(),
+        args: vec![Expression::NumberWithUncertainty { value: std::f64::consts::FRAC_PI_2, error: 0.1, pos: dummy_pos(), notation: None, literal: None, span: Span::default() }],
+        pos: dummy_pos(),
+        span: Span::default(),
+    };
+    let actual = executor.evaluate_expression(&sine);
+    assert!(actual.error() > 0.007 && actual.error() < 0.0072);
+}
+
+// Helper to tokenize and parse a single expression statement
+fn parse_expression(source: &str) -> Expression {
+    let tokens = Tokenizer::new(source).tokenize().unwrap();
+    match AstParser::new().parse_program(tokens).unwrap().statements.remove(0) {
+        Statement::Expression(expression) => expression,
+        other => panic!("Expected an expression statement, got {:?}", other),
+    }
+}
+
+// Helper to declare and assign a variable from an expression
+fn define(executor: &mut Executor, name: &str, value: Expression) {
+    executor.execute_statement(&Statement::VarDeclaration {
+        name: name.to_string(),
+        var_type: VarType::Real,
+        pos: dummy_pos(),
+        span: Span::default(),
+    });
+    executor.execute_statement(&Statement::Assignment {
+        name: name.to_string(),
+        value,
+        pos: dummy_pos(),
+        span: Span::default(),
+    });
+}
+
+#[test]
+fn test_error_budget_by_variable() {
+    let mut executor = Executor::new();
+    define(&mut executor, "x", Expression::NumberWithUncertainty { value: 12.3, error: 0.5, pos: dummy_pos(), notation: None, literal: None, span: Span::default() });
+    define(&mut executor, "y", Expression::NumberWithUncertainty { value: 2.6, error: 0.2, pos: dummy_pos(), notation: None, literal: None, span: Span::default() });
+    define(&mut executor, "z", Expression::Binary {
+        left: Box::new(Expression::Variable { name: "x".to_string(), pos: dummy_pos(), span: Span::default() }),
+        op: BinaryOp::Add,
+        right: Box::new(Expression::Variable { name: "y".to_string(), pos: dummy_pos(), span: Span::default() }),
+        pos: dummy_pos(),
+        span: Span::default(),
+    });
+
+    // Budget of z follows its definition back to x and y
+    let budget = executor.error_budget(&Expression::Variable { name: "z".to_string(), pos: dummy_pos(), span: Span::default() });
+    assert_eq!(budget.entries.len(), 2);
+    assert_eq!(budget.entries[0].source, "x");
+    assert_eq!(budget.entries[1].source, "y");
+    assert!((budget.entries[0].percent - 100.0 * 0.25 / 0.29).abs() < 1e-4);
+    assert!((budget.entries[1].percent - 100.0 * 0.04 / 0.29).abs() < 1e-4);
+    assert_real(&budget.result, Real::with_error(14.9, 0.29f64.sqrt()));
+}
+
+#[test]
+fn test_error_budget_literal_and_correlation() {
+    let mut executor = Executor::new();
+    define(&mut executor, "x", Expression::NumberWithUncertainty { value: 3.0, error: 0.1, pos: dummy_pos(), notation: None, literal: None, span: Span::default() });
+
+    // x * x uses one source twice: sensitivity is 2x, not split across two inputs
+    let expression = Expression::Binary {
+        left: Box::new(Expression::Binary {
+            left: Box::new(Expression::Variable { name: "x".to_string(), pos: dummy_pos(), span: Span::default() }),
+            op: BinaryOp::Mul,
+            right: Box::new(Expression::Variable { name: "x".to_string(), pos: dummy_pos(), span: Span::default() }),
+            pos: dummy_pos(),
+            span: Span::default(),
+        }),
+        op: BinaryOp::Add,
+        right: Box::new(Expression::NumberWithUncertainty { value: 1.0, error: 0.3, pos: Position { line: 4, column: 9 }, notation: None, literal: None, span: Span::default() }),
+        pos: dummy_pos(),
+        span: Span::default(),
+    };
+
+    let budget = executor.error_budget(&expression);
+    assert_eq!(budget.entries.len(), 2);
+    assert_eq!(budget.entries[0].source, "x");
+    assert!((budget.entries[0].sensitivity - 6.0).abs() < 1e-6);
+    assert_eq!(budget.entries[1].source, "literal at 4:9");
+    assert!((budget.total_variance() - (0.36 + 0.09)).abs() < 1e-8);
+}
+
+#[test]
+fn test_formula_by_variable() {
+    let mut executor = Executor::new();
+    define(&mut executor, "x", Expression::NumberWithUncertainty { value: 12.3, error: 0.5, pos: dummy_pos(), notation: None, literal: None, span: Span::default() });
+    define(&mut executor, "y", Expression::NumberWithUncertainty { value: 2.6, error: 0.2, pos: dummy_pos(), notation: None, literal: None, span: Span::default() });
+    define(&mut executor, "z", Expression::Binary {
+        left: Box::new(Expression::Variable { name: "x".to_string(), pos: dummy_pos(), span: Span::default() }),
+        op: BinaryOp::Mul,
+        right: Box::new(Expression::Variable { name: "y".to_string(), pos: dummy_pos(), span: Span::default() }),
+        pos: dummy_pos(),
+        span: Span::default(),
+    });
+
+    // The formula of z follows its definition back to x and y
+    let formula = executor.formula(&Expression::Variable { name: "z".to_string(), pos: dummy_pos(), span: Span::default() });
+    assert_eq!(formula.expression.to_text(), "x * y");
+    let inputs: Vec<&str> = formula.inputs.iter().map(|input| input.name.as_str()).collect();
+    assert_eq!(inputs, vec!["x", "y"]);
+    assert_eq!(formula.partials[0].to_text(), "y");
+    assert_eq!(formula.partials[1].to_text(), "x");
+    assert!((formula.error() - ((2.6f64 * 0.5).powi(2) + (12.3f64 * 0.2).powi(2)).sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn test_budget_function_returns_argument() {
+    let mut executor = Executor::new();
+    let call = Expression::FunctionCall {
+        name: "budget".to_string(),
+        args: vec![Expression::NumberWithUncertainty { value: 2.0, error: 0.5, pos: dummy_pos(), notation: None, literal: None, span: Span::default() }],
+        pos: dummy_pos(),
+        span: Span::default(),
+    };
+    let actual = executor.evaluate_expression(&call);
+    assert_real(&actual, Real::with_error(2.0, 0.5));
+}
+
+#[test]
+fn test_plus_minus_operator() {
+    let mut executor = Executor::new();
+    define(&mut executor, "x", Expression::NumberWithUncertainty { value: 12.3, error: 0.3, pos: dummy_pos(), notation: None, literal: None, span: Span::default() });
+    define(&mut executor, "dx", Expression::NumberWithUncertainty { value: 0.8, error: 0.0, pos: dummy_pos(), notation: None, literal: None, span: Span::default() });
+
+    // x +/- (dx / 2) adds 0.4 to the 0.3 already on x
+    let expression = parse_expression("x +/- (dx / 2)");
+
+    let actual = executor.evaluate_expression(&expression);
+    assert_real(&actual, Real::with_error(12.3, 0.5));
+
+    let budget = executor.error_budget(&expression);
+    assert_eq!(budget.entries.len(), 2);
+    assert_eq!(budget.entries[0].source, "+/- at 1:3");
+    assert_eq!(budget.entries[1].source, "x");
+    assert_real(&budget.result, Real::with_error(12.3, 0.5));
+}
+
+#[test]
+fn test_nominal_drops_uncertainty() {
+    let mut executor = Executor::new();
+    let call = Expression::FunctionCall {
+        name: "nominal".to_string(),
+        args: vec![Expression::NumberWithUncertainty { value: 2.0, error: 0.5, pos: dummy_pos(), notation: None, literal: None, span: Span::default() }],
+        pos: dummy_pos(),
+        span: Span::default(),
+    };
+    let actual = executor.evaluate_expression(&call);
+    assert_real(&actual, Real::new(2.0));
+    assert!(executor.error_budget(&call).entries.is_empty());
+}
+
+#[test]
+fn test_asymmetric_literal() {
+    let mut executor = Executor::new();
+    let literal = Expression::NumberWithUncertainty {
+        value: 12.3,
+        error: 0.4,
+        pos: dummy_pos(),
+        notation: Some(UncertaintyNotation::Asymmetric { upper: 0.4, lower: 0.2 }),
+        literal: None,
+        span: Span::default(),
+    };
+    let actual = executor.evaluate_expression(&literal);
+    assert_eq!(actual, Real::with_asymmetric_error(12.3, 0.4, 0.2));
+}
+
+#[test]
+fn test_asymmetric_lookalike_is_arithmetic() {
+    let mut executor = Executor::new();
+
+    let literal = executor.evaluate_expression(&parse_expression("12.3(+0.4/-0.2)"));
+    assert_eq!(literal, Real::with_asymmetric_error(12.3, 0.4, 0.2));
+
+    // 1 + (2 / -3), as it always was
+    let arithmetic = executor.evaluate_expression(&parse_expression("1+2/-3"));
+    assert_real(&arithmetic, Real::new(1.0 / 3.0));
+}
+
+/// Forward-mode dual number: the uncertainty of a literal seeds the derivative, so a
+/// program computes `df/dx · dx`. Defined here to check that a backend written outside
+/// the executor plugs in without changes to it.
+#[derive(Debug, Clone, Copy)]
+struct Dual {
+    value: f64,
+    derivative: f64,
+}
+
+impl fmt::Display for Dual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}ε", self.value, self.derivative)
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Dual { value: self.value + other.value, derivative: self.derivative + other.derivative }
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Dual { value: self.value - other.value, derivative: self.derivative - other.derivative }
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Dual { value: self.value * other.value, derivative: self.derivative * other.value + self.value * other.derivative }
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        let derivative = (self.derivative * other.value - self.value * other.derivative) / other.value.powi(2);
+        Dual { value: self.value / other.value, derivative }
+    }
+}
+
+impl Rem for Dual {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        Dual { value: self.value % other.value, derivative: self.derivative }
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Dual { value: -self.value, derivative: -self.derivative }
+    }
+}
+
+impl Number for Dual {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn error(&self) -> f64 {
+        self.derivative.abs()
+    }
+
+    fn with_error(value: f64, error: f64) -> Self {
+        Dual { value, derivative: error }
+    }
+
+    fn power(&self, other: Self) -> Self {
+        let value = self.value.powf(other.value);
+        let derivative = other.value * self.value.powf(other.value - 1.0) * self.derivative
+            + value * self.value.ln() * other.derivative;
+        Dual { value, derivative }
+    }
+
+    fn root(&self, other: Self) -> Self {
+        self.power(Dual::new(1.0 / other.value))
+    }
+
+    fn plus_minus(&self, other: Self) -> Self {
+        Dual { value: self.value, derivative: self.derivative + other.value.abs() }
+    }
+
+    fn std_function(function: &StdFunction, args: &[Self], _order: PropagationOrder) -> Self {
+        let point: Vec<f64> = args.iter().map(|a| a.value).collect();
+        let derivative = args.iter()
+            .enumerate()
+            .map(|(i, a)| partial_derivative(&function.eval, &point, i) * a.derivative)
+            .sum();
+        Dual { value: (function.eval)(&point), derivative }
+    }
+}
+
+#[test]
+fn test_custom_backend() {
+    let mut executor = Executor::<Dual>::with_backend();
+    executor.declare_variable("x", &VarType::Real, &dummy_pos());
+    executor.execute_statement(&Statement::Assignment {
+        name: "x".to_string(),
+        value: Expression::NumberWithUncertainty { value: 2.0, error: 1.0, pos: dummy_pos(), notation: None, literal: None, span: Span::default() },
+        pos: dummy_pos(),
+        span: Span::default(),
+    });
+
+    // x * x + sin(x), whose derivative is 2x + cos(x)
+    let expression = Expression::Binary {
+        left: Box::new(Expression::Binary {
+            left: Box::new(Expression::Variable { name: "x".to_string(), pos: dummy_pos(), span: Span::default() }),
+            op: BinaryOp::Mul,
+            right: Box::new(Expression::Variable { name: "x".to_string(), pos: dummy_pos(), span: Span::default() }),
+            pos: dummy_pos(),
+            span: Span::default(),
+        }),
+        op: BinaryOp::Add,
+        right: Box::new(Expression::FunctionCall {
+            name: "sin".to_string(),
+            args: vec![Expression::Variable { name: "x".to_string(), pos: dummy_pos(), span: Span::default() }],
+            pos: dummy_pos(),
+            span: Span::default(),
+        }),
+        pos: dummy_pos(),
+        span: Span::default(),
+    };
+
+    let actual = executor.evaluate_expression(&expression);
+    assert!((actual.value - (4.0 + 2.0_f64.sin())).abs() < 1e-12);
+    assert!((actual.derivative - (4.0 + 2.0_f64.cos())).abs() < 1e-6);
+}
+
+fn assertion(name: &str, args: Vec<Expression>) -> Expression {
+    Expression::FunctionCall { name: name.to_string(), args, pos: dummy_pos(), span: Span::on_line(FileId(0), Position { line: 2, column: 1 }, 12) }
+}
+
+fn number(value: f64, error: f64) -> Expression {
+    Expression::NumberWithUncertainty { value, error, pos: dummy_pos(), notation: None, literal: None, span: Span::default() }
+}
+
+#[test]
+fn test_assertions_that_hold() {
+    let mut executor = Executor::new();
+    let calls = [
+        assertion("assert", vec![number(2.0, 0.5)]),
+        assertion("assert_close", vec![number(1.52, 0.01), number(1.5, 0.0), number(0.025, 0.0)]),
+        assertion("assert_consistent", vec![number(1.52, 0.01), number(1.5, 0.005), number(2.0, 0.0)]),
+        assertion("assert_consistent", vec![number(1.5, 0.0), number(1.5, 0.0), number(1.0, 0.0)]),
+    ];
+    for call in &calls {
+        executor.evaluate_expression(call);
+    }
+    assert!(executor.get_errors().is_empty());
+
+    // An assertion evaluates to its first argument
+    assert_real(&executor.evaluate_expression(&calls[1]), Real::with_error(1.52, 0.01));
+}
+
+#[test]
+fn test_failed_assertions_are_runtime_errors() {
+    let mut executor = Executor::new();
+    executor.evaluate_expression(&assertion("assert", vec![number(0.0, 0.1)]));
+    executor.evaluate_expression(&assertion("assert_close", vec![number(1.52, 0.01), number(1.5, 0.0), number(0.01, 0.0)]));
+    executor.evaluate_expression(&assertion("assert_consistent", vec![number(1.52, 0.01), number(1.5, 0.005), number(1.0, 0.0)]));
+    executor.evaluate_expression(&assertion("assert_consistent", vec![number(1.52, 0.0), number(1.5, 0.0), number(3.0, 0.0)]));
+
+    let messages: Vec<String> = executor.get_errors().iter().map(|error| error.to_diagnostic(FileId(0)).message).collect();
+    assert_eq!(messages, vec![
+        "Assertion failed: argument is 0 +/- 0.1",
+        "Assertion failed: 1.52 +/- 0.01 and 1.5 differ by 0.02, more than the tolerance 0.01",
+        "Assertion failed: 1.52 +/- 0.01 and 1.5 +/- 0.005 differ by 1.79 standard uncertainties, more than 1",
+        "Assertion failed: 1.52 and 1.5 differ by inf standard uncertainties, more than 3",
+    ]);
+
+    let diagnostic = executor.get_errors()[0].to_diagnostic(FileId(0));
+    assert_eq!((diagnostic.span.start.line, diagnostic.span.start.column, diagnostic.span.end.column), (2, 1, 13));
+}
+
+#[test]
+fn test_assertion_statements_print_nothing() {
+    let mut executor = Executor::new();
+    executor.set_capture(true);
+    executor.execute_statement(&Statement::Expression(assertion("assert", vec![number(1.0, 0.0)])));
+    executor.execute_statement(&Statement::Expression(number(1.0, 0.0)));
+    assert_eq!(executor.take_output(), vec!["1"]);
+}
+
+#[test]
+fn test_comparison_and_logical_operators() {
+    let mut executor = Executor::new();
+    define(&mut executor, "x", parse_expression("2 +/- 0.5"));
+
+    let cases = [
+        ("x > 1", 1.0), ("x < 1", 0.0), ("x >= 2", 1.0), ("x <= 1.9", 0.0),
+        ("x == 2", 1.0), ("x != 2", 0.0),
+        ("x > 1 && x < 3", 1.0), ("x > 3 || x < 1", 0.0), ("!x", 0.0), ("!(x - 2)", 1.0),
+        // Only the nominal values are compared
+        ("x == 2 +/- 0.1", 1.0),
+    ];
+    for (source, expected) in cases {
+        let actual = executor.evaluate_expression(&parse_expression(source));
+        assert_eq!((actual.value(), actual.error()), (expected, 0.0), "{}", source);
+    }
+
+    // The right operand is skipped once the left one decides the result
+    let actual = executor.evaluate_expression(&parse_expression("x < 0 && 1 / 0 > 1"));
+    assert_eq!(actual.value(), 0.0);
+    let actual = executor.evaluate_expression(&parse_expression("x > 0 || 1 / 0 > 1"));
+    assert_eq!(actual.value(), 1.0);
+    assert!(executor.get_errors().is_empty());
+}
+
+#[test]
+fn test_comparisons_are_exact_in_second_order() {
+    let mut executor = Executor::new();
+    executor.set_propagation_order(PropagationOrder::Second);
+    let actual = executor.evaluate_expression(&parse_expression("(3 +/- 0.5) * 2 > 5"));
+    assert_eq!((actual.value(), actual.error()), (1.0, 0.0));
+}
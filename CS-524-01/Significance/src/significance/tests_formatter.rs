@@ -0,0 +1,165 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use crate::significance::formatter::format_source;
+use crate::significance::tokenizer::Tokenizer;
+use crate::significance::ast_parser::{AstParser, Program};
+
+fn format(text: &str) -> String {
+    format_source(text).unwrap_or_else(|errors| panic!("{:?}", errors))
+}
+
+/// Parses a source, ignoring positions, so programs can be compared
+fn parse(text: &str) -> String {
+    let tokens = Tokenizer::new(text).tokenize().unwrap();
+    let program: Program = AstParser::new().parse_program(tokens).unwrap();
+    let mut json = serde_json::to_value(&program).unwrap();
+    strip_positions(&mut json);
+    json.to_string()
+}
+
+fn strip_positions(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.remove("pos");
+            map.remove("span");
+            map.values_mut().for_each(strip_positions);
+        },
+        serde_json::Value::Array(items) => items.iter_mut().for_each(strip_positions),
+        _ => {},
+    }
+}
+
+#[test]
+fn test_operator_spacing() {
+    assert_eq!(format("x:=1+2*y**2//3"), "x := 1 + 2 * y ** 2 // 3\n");
+    assert_eq!(format("y   :=  - x%4"), "y := -x % 4\n");
+    assert_eq!(format("atan2( y,x )"), "atan2(y, x)\n");
+    assert_eq!(format("12.3±0.5"), "12.3 +/- 0.5\n");
+    assert_eq!(format("x+/-0.2"), "x +/- 0.2\n");
+}
+
+#[test]
+fn test_literals_keep_their_spelling() {
+    assert_eq!(format("1.5e-3 * 6.02214076e23"), "1.5e-3 * 6.02214076e23\n");
+    assert_eq!(format("12.3(5)+1"), "12.3(5) + 1\n");
+    assert_eq!(format("12.3(+0.4/-0.2)+1"), "12.3(+0.4/-0.2) + 1\n");
+    assert_eq!(format("12.3+/-2%"), "12.3 +/- 2%\n");
+    assert_eq!(format("5+/-3ppm"), "5 +/- 3ppm\n");
+}
+
+#[test]
+fn test_minimal_parentheses() {
+    assert_eq!(format("((1 + 2)) * 3"), "(1 + 2) * 3\n");
+    assert_eq!(format("(1 * 2) + (3)"), "1 * 2 + 3\n");
+    assert_eq!(format("a - (b - c) - (d + e)"), "a - (b - c) - (d + e)\n");
+    assert_eq!(format("(a - b) - c"), "a - b - c\n");
+    assert_eq!(format("a / (b * c)"), "a / (b * c)\n");
+    assert_eq!(format("a ** (b ** c)"), "a ** b ** c\n");
+    assert_eq!(format("(a ** b) ** c"), "(a ** b) ** c\n");
+    assert_eq!(format("(-a) ** 2"), "-a ** 2\n");
+    assert_eq!(format("-(a + b)"), "-(a + b)\n");
+    assert_eq!(format("(x +/- 1) * 2"), "x +/- 1 * 2\n");
+    assert_eq!(format("x +/- (y + 1)"), "x +/- (y + 1)\n");
+    assert_eq!(format("x +/- (2 +/- 1)"), "x +/- (2 +/- 1)\n");
+    assert_eq!(format("(a + b) +/- 1"), "(a + b) +/- 1\n");
+}
+
+#[test]
+fn test_formatting_preserves_meaning() {
+    let sources = [
+        "x := ((1 + 2)) * -(3 - 4) ** (2 // 3)",
+        "a - (b - c) / (d % (e * f)) ** -(g)",
+        "(12.3 +/- 0.5) +/- 0.2 + 12.3(5) * (2 +0.1/-0.3)",
+        "sin((x + 1) * 2) +/- (y) - +(-z)",
+    ];
+    for source in sources {
+        let formatted = format(source);
+        assert_eq!(parse(&formatted), parse(source), "{}", formatted);
+        assert_eq!(format(&formatted), formatted);
+    }
+}
+
+#[test]
+fn test_declarations_are_aligned() {
+    let source = "{x:real}\n{reading : real}\n{dx: real}\n\n{gain : real}\n";
+    assert_eq!(format(source), "{x       : real}\n{reading : real}\n{dx      : real}\n\n{gain : real}\n");
+}
+
+#[test]
+fn test_comments_are_preserved() {
+    let source = "\
+#This is a comment
+
+{x : real} # the `x` variable
+{total : real}   # sum
+
+# inputs
+x := 12.3 +/- 0.5    # assign `x`
+total := x*x + 1     # the square of `x`
+                     #     plus one
+total  #print it
+";
+    let expected = "\
+#This is a comment
+
+{x     : real} # the `x` variable
+{total : real} # sum
+
+# inputs
+x := 12.3 +/- 0.5  # assign `x`
+total := x * x + 1 # the square of `x`
+                   #     plus one
+total #print it
+";
+    assert_eq!(format(source), expected);
+    assert_eq!(format(expected), expected);
+}
+
+#[test]
+fn test_blank_lines_and_statement_layout() {
+    assert_eq!(format("\n\n{x : real}\n\n\n\nx := 1 2\n\n\n"), "{x : real}\n\nx := 1\n2\n");
+    assert_eq!(format(""), "");
+    assert_eq!(format("# only a comment"), "# only a comment\n");
+}
+
+#[test]
+fn test_comparison_and_logical_operators() {
+    assert_eq!(format("x>0&&x<1||!y"), "x > 0 && x < 1 || !y\n");
+    assert_eq!(format("(x>0||y)&&z"), "(x > 0 || y) && z\n");
+    assert_eq!(format("(x<1)==(y<1)"), "(x < 1) == (y < 1)\n");
+    assert_eq!(format("!(x==1)+(x>=1)*2"), "!(x == 1) + (x >= 1) * 2\n");
+    assert_eq!(format("((x+1)!=2)"), "x + 1 != 2\n");
+}
+
+#[test]
+fn test_imports() {
+    assert_eq!(format("import \"lib/cal.sig\" as cal"), "import \"lib/cal.sig\"\n");
+    assert_eq!(format("import \"lib/cal.sig\" as c"), "import \"lib/cal.sig\" as c\n");
+    assert_eq!(format("import \"lib/cal.sig\"(a,b)\ncal.a + b"), "import \"lib/cal.sig\" (a, b)\ncal.a + b\n");
+}
+
+#[test]
+fn test_check_mode_detects_unformatted_source() {
+    let formatted = "{x : real}\nx := 1 + 2\n";
+    assert_eq!(format(formatted), formatted);
+    assert_ne!(format("{x : real}\nx := 1+2\n"), "{x : real}\nx := 1+2\n");
+}
+
+#[test]
+fn test_syntax_errors_are_not_formatted() {
+    let errors = format_source("x := (1\ny := *\n").unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].message, "Expected RightParen");
+
+    let errors = format_source("x := @").unwrap_err();
+    assert_eq!(errors.len(), 1);
+
+    let errors = format_source("x := @\ny := *\n").unwrap_err();
+    let messages: Vec<&str> = errors.iter().map(|error| error.message.as_str()).collect();
+    assert_eq!(messages, vec!["Unexpected character '@'", "Expected expression"]);
+}
@@ -0,0 +1,131 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use std::fs;
+use std::path::Path;
+
+use crate::significance::golden::{run_test, bless, read_expectations, rewrite_expectations, lines_match, Expectations};
+use crate::significance::language_parser::Significance;
+use crate::significance::interval::Interval;
+use crate::significance::tests_support::{scratch_dir, write};
+
+#[test]
+fn test_numbers_match_at_their_written_precision() {
+    assert!(lines_match("14.9 +/- 0.54", "14.9 +/- 0.5385164807134505"));
+    assert!(lines_match("14.9 +/- 0.5", "14.9 +/- 0.5385164807134505"));
+    assert!(!lines_match("14.9 +/- 0.540", "14.9 +/- 0.5385164807134505"));
+    assert!(!lines_match("14.9 +/- 0.53", "14.9 +/- 0.5385164807134505"));
+    assert!(lines_match("158.1", "158.05"));
+    assert!(lines_match("1.5e-3", "0.00152"));
+    assert!(!lines_match("1.5e-3", "0.0016"));
+    assert!(lines_match("-0.25", "-0.2500000001"));
+    assert!(lines_match("0.1", "0.1000000000000001"));
+    assert!(lines_match("1.5e2", "152"));
+}
+
+#[test]
+fn test_integers_are_exact() {
+    assert!(!lines_match("1", "1.4"));
+    assert!(!lines_match("158", "158.05"));
+    assert!(!lines_match("-3", "-3.2"));
+    assert!(!lines_match("1e2", "140"));
+    assert!(!lines_match("2e-3", "0.0024"));
+    assert!(lines_match("1", "1.0000000001"));
+    assert!(lines_match("1e2", "100"));
+}
+
+#[test]
+fn test_text_must_match() {
+    assert!(lines_match("Error at 2:6: Division by zero", "Error  at 2:6:  Division by zero"));
+    assert!(!lines_match("Error at 2:6: Division by zero", "Error at 2:7: Division by zero"));
+    assert!(!lines_match("x2 +/- 1", "x3 +/- 1"));
+    assert!(!lines_match("14.9 +/- 0.5", "14.9"));
+    assert!(!lines_match("14.9 +/- 0.5", "14.9 - 0.5"));
+    assert!(lines_match("  source  error  share", "source error share"));
+}
+
+#[test]
+fn test_embedded_expectations() {
+    let dir = scratch_dir("golden-embedded");
+    let path = write(&dir, "sum.sig", "{x : real}\nx := 12.3 +/- 0.5\nx + 2.6 # expect: 14.9 +/- 0.5\n# expect: 1\n1\n");
+    let case = run_test(Significance::new(), Path::new(&path)).unwrap();
+    assert!(matches!(&case.expectations, Expectations::Embedded(positions) if positions.len() == 2));
+    assert_eq!(case.expected, vec!["14.9 +/- 0.5", "1"]);
+    assert!(case.passed(), "{:?}", case.mismatches());
+}
+
+#[test]
+fn test_expected_file() {
+    let dir = scratch_dir("golden-file");
+    let path = write(&dir, "error.sig", "{x : real}\nx := 1 / 0\n1 / x\n");
+    write(&dir, "error.expected", "0 +/- NaN\nError at 2:6: Division by zero\nWarning at 2:10: Division by zero [division_by_zero]\n\n");
+    let case = run_test(Significance::new(), Path::new(&path)).unwrap();
+    assert_eq!(case.expectations, Expectations::File(dir.join("error.expected")));
+    assert!(case.passed(), "{:?}", case.mismatches());
+
+    // Embedded expectations take precedence over the file
+    let (expectations, expected) = read_expectations(Path::new(&path), "x # expect: 2\n").unwrap();
+    assert!(matches!(expectations, Expectations::Embedded(_)));
+    assert_eq!(expected, vec!["2"]);
+}
+
+#[test]
+fn test_failures_are_described() {
+    let dir = scratch_dir("golden-failures");
+    let path = write(&dir, "values.sig", "1 # expect: 2\n3\n");
+    let case = run_test(Significance::new(), Path::new(&path)).unwrap();
+    assert!(!case.passed());
+    assert_eq!(case.mismatches(), vec!["line 1: expected `2`, got `1`", "line 2: unexpected `3`"]);
+
+    let path = write(&dir, "missing.sig", "1\n");
+    let case = run_test(Significance::new(), Path::new(&path)).unwrap();
+    assert_eq!(case.expectations, Expectations::Missing);
+}
+
+#[test]
+fn test_tests_run_with_the_given_interpreter() {
+    let dir = scratch_dir("golden-backend");
+    let path = write(&dir, "interval.sig", "2 +/- 0.5 # expect: [1.5, 2.5]\n");
+    assert!(run_test(Significance::<Interval>::with_backend(), Path::new(&path)).unwrap().passed());
+    assert!(!run_test(Significance::new(), Path::new(&path)).unwrap().passed());
+}
+
+#[test]
+fn test_bless_rewrites_embedded_expectations() {
+    let text = "x # expect: 1\ny # expect: 2\n  # expect: 3\n";
+    let positions = match read_expectations(Path::new("unused.sig"), text).unwrap().0 {
+        Expectations::Embedded(positions) => positions,
+        other => panic!("{:?}", other),
+    };
+    let lines = |lines: &[&str]| lines.iter().map(|line| line.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(rewrite_expectations(text, &positions, &lines(&["4", "5", "6"])), "x # expect: 4\ny # expect: 5\n  # expect: 6\n");
+    assert_eq!(rewrite_expectations(text, &positions, &lines(&["4"])), "x # expect: 4\ny\n");
+    assert_eq!(rewrite_expectations(text, &positions, &lines(&["4", "5", "6", "7"])), "x # expect: 4\ny # expect: 5\n  # expect: 6\n# expect: 7\n");
+}
+
+#[test]
+fn test_bless() {
+    let dir = scratch_dir("golden-bless");
+    let path = write(&dir, "new.sig", "1\n2 +/- 0.1\n");
+    bless(&run_test(Significance::new(), Path::new(&path)).unwrap()).unwrap();
+    assert_eq!(fs::read_to_string(dir.join("new.expected")).unwrap(), "1\n2 +/- 0.1\n");
+    assert!(run_test(Significance::new(), Path::new(&path)).unwrap().passed());
+
+    let path = write(&dir, "embedded.sig", "1 # expect: 3\n");
+    bless(&run_test(Significance::new(), Path::new(&path)).unwrap()).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1 # expect: 1\n");
+}
+
+#[test]
+fn test_examples() {
+    for example in ["docs/example_1.sig", "docs/example_2.sig"] {
+        let case = run_test(Significance::new(), Path::new(example)).unwrap();
+        assert_ne!(case.expectations, Expectations::Missing);
+        assert!(case.passed(), "{}: {:?}", example, case.mismatches());
+    }
+}
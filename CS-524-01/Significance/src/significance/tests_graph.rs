@@ -0,0 +1,112 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use crate::significance::ast_parser::Expression;
+use crate::significance::diagnostic::Severity;
+use crate::significance::graph::{Graph, NodeKind};
+use crate::significance::language_parser::Significance;
+
+const PROGRAM: &str = "{x : real}\n{y : real}\n{z : real}\n\nx := 1 +/- 0.3\ny := 2 +/- 0.4\nz := x + y\nz * sqrt(4)\n";
+
+/// Runs a program and builds its graph
+fn graph(source: &str) -> (Graph, Significance) {
+    let mut interpreter = Significance::new();
+    let (program, _, diagnostics) = interpreter.check("main.sig", source, None);
+    assert!(diagnostics.iter().all(|d| d.severity == Severity::Warning), "{:?}", diagnostics);
+    (Graph::from_program(&program), interpreter)
+}
+
+fn index(graph: &Graph, label: &str) -> usize {
+    graph.nodes.iter().position(|node| node.label() == label).unwrap()
+}
+
+#[test]
+fn test_nodes_and_edges() {
+    let (graph, _) = graph(PROGRAM);
+    let labels: Vec<String> = graph.nodes.iter().map(|node| node.label()).collect();
+    assert_eq!(labels, vec!["x", "y", "z", "1 +/- 0.3", "2 +/- 0.4", "+", "*", "sqrt", "4"]);
+    assert_eq!(graph.nodes[0].kind, NodeKind::Variable("x".to_string()));
+    assert_eq!(graph.nodes[5].kind, NodeKind::Operator("+"));
+    assert_eq!(graph.nodes[7].kind, NodeKind::Call("sqrt".to_string()));
+    assert_eq!(graph.nodes[8].kind, NodeKind::Literal);
+
+    // Assignments feed their variables, and variables feed the expressions using them
+    assert_eq!(graph.edges, vec![(3, 0), (4, 1), (0, 5), (1, 5), (5, 2), (2, 6), (8, 7), (7, 6)]);
+    let outputs: Vec<usize> = (0..graph.nodes.len()).filter(|&i| graph.nodes[i].output).collect();
+    assert_eq!(outputs, vec![index(&graph, "*")]);
+    assert!(matches!(graph.result(), Some(Expression::Binary { .. })));
+}
+
+#[test]
+fn test_to_dot() {
+    let (graph, _) = graph("{x : real}\nx := -(1 +/- 0.5)\nx\n");
+    assert_eq!(graph.to_dot(), "digraph program {\n    \
+        rankdir=LR;\n    \
+        n0 [label=\"x\", shape=box, peripheries=2];\n    \
+        n1 [label=\"-\", shape=circle];\n    \
+        n2 [label=\"1 +/- 0.5\", shape=plaintext];\n    \
+        n2 -> n1;\n    \
+        n1 -> n0;\n\
+        }\n");
+}
+
+#[test]
+fn test_comparisons_and_logical_operators() {
+    let (mut graph, mut interpreter) = graph("{x : real}\nx := 2 +/- 0.1\n!(x > 1) || x <= 3\n");
+    graph.annotate_values(&mut interpreter);
+    let labels: Vec<String> = graph.nodes.iter().map(|node| node.label()).collect();
+    assert_eq!(labels, vec!["x", "2 +/- 0.1", "||", "!", ">", "1", "<=", "3"]);
+    assert_eq!(graph.nodes[index(&graph, "||")].value.as_deref(), Some("1"));
+    assert!(graph.to_dot().contains("n2 [label=\"||\\n= 1\", shape=circle, peripheries=2];"), "{}", graph.to_dot());
+}
+
+#[test]
+fn test_values() {
+    let (mut graph, mut interpreter) = graph(PROGRAM);
+    graph.annotate_values(&mut interpreter);
+    let value = |label: &str| graph.nodes[index(&graph, label)].value.clone().unwrap();
+    assert_eq!(value("x"), "1 +/- 0.3");
+    assert_eq!(value("+"), "3 +/- 0.5");
+    assert_eq!(value("sqrt"), "2");
+    assert_eq!(value("*"), "6 +/- 1");
+    assert!(graph.to_dot().contains("n5 [label=\"+\\n= 3 +/- 0.5\", shape=circle];"), "{}", graph.to_dot());
+
+    // Literals are not labelled with their value twice
+    assert!(graph.to_dot().contains("n8 [label=\"4\", shape=plaintext];"), "{}", graph.to_dot());
+}
+
+#[test]
+fn test_variance_shares() {
+    let (mut graph, interpreter) = graph(PROGRAM);
+    graph.annotate_shares(&interpreter);
+    let share = |label: &str| graph.nodes[index(&graph, label)].share;
+    assert!((share("x").unwrap() - 36.0).abs() < 1e-6);
+    assert!((share("y").unwrap() - 64.0).abs() < 1e-6);
+    assert_eq!(share("z"), None);
+    assert_eq!(share("1 +/- 0.3"), None);
+    assert!(graph.to_dot().contains("n1 [label=\"y\\n64.0% of variance\", shape=box];"), "{}", graph.to_dot());
+
+    // Literals and `+/-` inside definitions are sources of their own
+    let (mut graph, interpreter) = self::graph("{a : real}\na := (2 +/- 0.1) * 3 +/- (0.2 * 2)\na\n");
+    graph.annotate_shares(&interpreter);
+    let share = |label: &str| graph.nodes[index(&graph, label)].share.unwrap();
+    assert!((share("2 +/- 0.1") - 100.0 * 0.09 / 0.73).abs() < 1e-6);
+    assert!((share("+/-") - 100.0 * 0.64 / 0.73).abs() < 1e-6);
+}
+
+#[test]
+fn test_final_result_without_expression_statements() {
+    let (mut graph, interpreter) = graph("{x : real}\n{y : real}\nx := 1 +/- 0.1\ny := x * 2\n");
+    assert!(matches!(graph.result(), Some(Expression::Variable { name, .. }) if name == "y"));
+    graph.annotate_shares(&interpreter);
+    assert_eq!(graph.nodes[index(&graph, "x")].share, Some(100.0));
+
+    let (mut graph, interpreter) = self::graph("{x : real}\n");
+    assert!(graph.result().is_none());
+    graph.annotate_shares(&interpreter);
+    assert!(graph.nodes.iter().all(|node| node.share.is_none()));
+}
@@ -0,0 +1,194 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use crate::significance::interval::Interval;
+use crate::significance::numbers::Number;
+use crate::significance::executor::Executor;
+use crate::significance::propagation::PropagationOrder;
+use crate::significance::standard_lib::{std_lib_call, StdFunction, STD_LIB};
+use crate::significance::tokenizer::{Position, Tokenizer};
+use crate::significance::ast_parser::{AstParser, Statement};
+
+fn interval(lo: f64, hi: f64) -> Interval {
+    Interval::with_bounds(lo, hi)
+}
+
+/// Asserts that `actual` encloses `[lo, hi]` and is at most a few ulps wider.
+fn assert_encloses(actual: Interval, lo: f64, hi: f64) {
+    assert!(actual.lower() <= lo && actual.upper() >= hi, "{} does not enclose [{}, {}]", actual, lo, hi);
+    let slack = 4.0 * f64::EPSILON * lo.abs().max(hi.abs()).max(1.0);
+    assert!(lo - actual.lower() <= slack && actual.upper() - hi <= slack, "{} is much wider than [{}, {}]", actual, lo, hi);
+}
+
+fn evaluate(source: &str) -> Interval {
+    let tokens = Tokenizer::new(source).tokenize().unwrap();
+    let program = AstParser::new().parse_program(tokens).unwrap();
+    let mut executor = Executor::<Interval>::with_backend();
+    let mut result = Interval::new(f64::NAN);
+    for statement in &program.statements {
+        match statement {
+            Statement::Expression(expression) => {
+                result = executor.evaluate_expression(expression);
+            },
+            _ => executor.execute_statement(statement),
+        }
+    }
+    result
+}
+
+#[test]
+fn test_interval_from_uncertainty() {
+    let x = Interval::with_error(12.3, 0.5);
+    assert_encloses(x, 11.8, 12.8);
+    assert!((x.value() - 12.3).abs() < 1e-12);
+    assert!((x.error() - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn test_interval_exact_operations_stay_exact() {
+    let result = Interval::new(2.0) + Interval::new(3.0) * Interval::new(4.0);
+    assert_eq!(result, Interval::new(14.0));
+    assert_eq!(result.error(), 0.0);
+}
+
+#[test]
+fn test_interval_inexact_operations_round_outward() {
+    let third = Interval::new(1.0) / Interval::new(3.0);
+    assert!(third.lower() < third.upper());
+    assert!(third.lower() * 3.0 <= 1.0 && third.upper() * 3.0 >= 1.0);
+
+    let sum = Interval::new(0.1) + Interval::new(0.2);
+    assert!(sum.contains(0.1 + 0.2));
+    assert!(sum.lower() < sum.upper());
+}
+
+#[test]
+fn test_interval_arithmetic() {
+    let x = interval(1.0, 2.0);
+    let y = interval(-3.0, 4.0);
+    assert_encloses(x + y, -2.0, 6.0);
+    assert_encloses(x - y, -3.0, 5.0);
+    assert_encloses(x * y, -6.0, 8.0);
+    assert_encloses(y / x, -3.0, 4.0);
+    assert_encloses(-x, -2.0, -1.0);
+}
+
+#[test]
+fn test_interval_division_by_interval_containing_zero() {
+    let result = Interval::new(1.0) / interval(-1.0, 1.0);
+    assert_eq!(result.lower(), f64::NEG_INFINITY);
+    assert_eq!(result.upper(), f64::INFINITY);
+}
+
+#[test]
+fn test_interval_even_power_of_interval_containing_zero() {
+    let result = interval(-2.0, 1.0).power(Interval::new(2.0));
+    assert_encloses(result, 0.0, 4.0);
+
+    let cubed = interval(-2.0, 1.0).power(Interval::new(3.0));
+    assert_encloses(cubed, -8.0, 1.0);
+}
+
+#[test]
+fn test_interval_root_and_fractional_power() {
+    assert_encloses(interval(4.0, 9.0).root(Interval::new(2.0)), 2.0, 3.0);
+    assert_encloses(interval(8.0, 27.0).root(Interval::new(3.0)), 2.0, 3.0);
+    assert!(interval(-1.0, 1.0).power(Interval::new(0.5)).is_empty());
+}
+
+#[test]
+fn test_interval_plus_minus_adds_linearly() {
+    let result = Interval::new(10.0).plus_minus(Interval::new(3.0)).plus_minus(Interval::new(4.0));
+    assert_encloses(result, 3.0, 17.0);
+}
+
+#[test]
+fn test_interval_remainder() {
+    assert_encloses(interval(5.0, 6.0) % Interval::new(4.0), 1.0, 2.0);
+    assert_encloses(interval(3.0, 5.0) % Interval::new(4.0), 0.0, 4.0);
+    assert_encloses(interval(-6.0, -5.0) % Interval::new(4.0), -2.0, -1.0);
+}
+
+#[test]
+fn test_interval_sin_includes_extrema() {
+    let pos = Position { line: 1, column: 1 };
+    let result = std_lib_call("sin", &[interval(0.0, 3.0)], &pos);
+    assert_eq!(result.upper(), 1.0);
+    assert!(result.lower() <= 0.0);
+
+    let cos = std_lib_call("cos", &[interval(-1.0, 1.0)], &pos);
+    assert_eq!(cos.upper(), 1.0);
+    assert!(cos.lower() <= 1.0_f64.cos());
+}
+
+#[test]
+fn test_interval_tan_across_pole_is_unbounded() {
+    let pos = Position { line: 1, column: 1 };
+    let result = std_lib_call("tan", &[interval(1.0, 2.0)], &pos);
+    assert_eq!(result.lower(), f64::NEG_INFINITY);
+    assert_eq!(result.upper(), f64::INFINITY);
+}
+
+#[test]
+fn test_interval_domain_is_clipped() {
+    let pos = Position { line: 1, column: 1 };
+    assert_encloses(std_lib_call("sqrt", &[interval(-1.0, 4.0)], &pos), 0.0, 2.0);
+    assert!(std_lib_call("ln", &[interval(-2.0, -1.0)], &pos).is_empty());
+}
+
+#[test]
+fn test_interval_every_std_function_encloses_samples() {
+    let pos = Position { line: 1, column: 1 };
+    let x = interval(0.25, 0.75);
+    let y = interval(0.5, 1.5);
+    for function in STD_LIB {
+        let args: Vec<Interval> = [x, y][..function.arity].to_vec();
+        let result = std_lib_call(function.name, &args, &pos);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let sample = [0.25 + 0.5 * t, 1.5 - t];
+            let value = (function.eval)(&sample[..function.arity]);
+            assert!(result.contains(value), "{}({:?}) = {} not in {}", function.name, &sample[..function.arity], value, result);
+        }
+    }
+}
+
+#[test]
+fn test_interval_function_without_enclosure_falls_back() {
+    let cube = StdFunction { name: "cube", arity: 1, eval: |x| x[0] * x[0] * x[0] };
+    let order = PropagationOrder::First;
+    assert_encloses(Interval::std_function(&cube, &[Interval::new(2.0)], order), 8.0, 8.0);
+
+    let wide = Interval::std_function(&cube, &[interval(1.0, 2.0)], order);
+    assert!(wide.lower() == f64::NEG_INFINITY && wide.upper() == f64::INFINITY, "{}", wide);
+}
+
+#[test]
+fn test_interval_backend_runs_program() {
+    let result = evaluate("{x : real}\n{y : real}\nx := 12.3 +/- 0.5\ny := 2.0 +/- 0.1\nx * y\n");
+    assert_encloses(result, 11.8 * 1.9, 12.8 * 2.1);
+}
+
+#[test]
+fn test_interval_backend_asymmetric_literal() {
+    let result = evaluate("10(+1/-2)");
+    assert_encloses(result, 8.0, 11.0);
+}
+
+#[test]
+fn test_interval_decimal_literals_are_rounded_outward() {
+    // Neither 0.1 nor 0.3 is a double: the nearest double to 0.3 is just below it
+    let sum = evaluate("0.1 + 0.2");
+    assert!(sum.lower() < 0.3 && sum.upper() > 0.3, "{} does not enclose 0.3", sum);
+    assert_encloses(sum, 0.3, 0.3);
+
+    let literal = evaluate("0.1 +/- 0.1");
+    assert!(literal.lower() < 0.0 && literal.upper() > 0.2);
+
+    // Integers are exact and stay points
+    assert_eq!(evaluate("2 + 3"), Interval::new(5.0));
+}
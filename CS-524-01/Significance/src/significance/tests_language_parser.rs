@@ -0,0 +1,181 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use std::fs;
+
+use crate::significance::language_parser::Significance;
+use crate::significance::numbers::Number;
+use crate::significance::tests_support::{scratch_dir, write};
+
+const CALIBRATION: &str = "{offset : real}\n{gain : real}\n{pending : real}\noffset := 0.12 +/- 0.01\ngain := 1.5\noffset * 1000\n";
+
+#[test]
+fn test_import_under_namespace() {
+    let dir = scratch_dir("namespace");
+    write(&dir, "lib/calibration.sig", CALIBRATION);
+    let main = write(&dir, "main.sig", "import \"lib/calibration.sig\"\nimport \"lib/calibration.sig\" as cal\n");
+
+    let mut sig = Significance::new();
+    sig.run_file(&main).unwrap();
+
+    let offset = sig.get_var("calibration.offset").unwrap();
+    assert_eq!(offset.get_value().value(), 0.12);
+    assert_eq!(offset.get_value().error(), 0.01);
+    assert!(offset.get_definition().is_none());
+    assert!(sig.get_var("cal.gain").is_some());
+    assert!(sig.get_var("offset").is_none());
+    assert!(sig.get_var("calibration.pending").is_none());
+    assert!(sig.parse_repl("1 +/- cal.gain").unwrap().is_empty());
+}
+
+#[test]
+fn test_import_selected_names() {
+    let dir = scratch_dir("selected");
+    write(&dir, "lib/calibration.sig", CALIBRATION);
+    let main = write(&dir, "main.sig", "import \"lib/calibration.sig\" (gain)\n{x : real}\nx := 2 * gain\n");
+
+    let mut sig = Significance::new();
+    sig.run_file(&main).unwrap();
+    assert_eq!(sig.get_var("x").unwrap().get_value().value(), 3.0);
+    assert!(sig.get_var("offset").is_none());
+
+    let main = write(&dir, "missing.sig", "import \"lib/calibration.sig\" (pending)\n");
+    let error = Significance::new().run_file(&main).unwrap_err();
+    assert_eq!(error, format!(
+        "error: Cannot import 'lib/calibration.sig': no assigned variable 'pending'\n --> {}:1:1\n  |\n1 | import \"lib/calibration.sig\" (pending)\n  | {}",
+        main, "^".repeat(38)
+    ));
+}
+
+#[test]
+fn test_imports_resolve_relative_to_importing_file() {
+    let dir = scratch_dir("relative");
+    write(&dir, "lib/base.sig", "{scale : real}\nscale := 4\n");
+    write(&dir, "lib/derived.sig", "import \"base.sig\" (scale)\n{area : real}\narea := scale ** 2\n");
+    let main = write(&dir, "main.sig", "import \"lib/derived.sig\" (area)\n");
+
+    let mut sig = Significance::new();
+    sig.run_file(&main).unwrap();
+    assert_eq!(sig.get_var("area").unwrap().get_value().value(), 16.0);
+}
+
+#[test]
+fn test_import_cycle_is_reported() {
+    let dir = scratch_dir("cycle");
+    write(&dir, "a.sig", "import \"b.sig\"\n");
+    write(&dir, "b.sig", "\nimport \"a.sig\"\n");
+
+    let error = Significance::new().run_file(dir.join("a.sig").to_str().unwrap()).unwrap_err();
+    let b = dir.join("b.sig");
+    assert!(error.starts_with("error: Cannot import 'a.sig': import cycle"), "{}", error);
+    assert!(error.contains(&format!("a.sig\n --> {}:2:1\n", b.display())), "{}", error);
+}
+
+#[test]
+fn test_imported_errors_carry_file() {
+    let dir = scratch_dir("errors");
+    write(&dir, "lib/broken.sig", "{y : real}\ny := z\n");
+    let main = write(&dir, "main.sig", "import \"lib/broken.sig\" as broken\n");
+
+    let error = Significance::new().run_file(&main).unwrap_err();
+    assert_eq!(error, format!(
+        "error: Variable 'z' not declared\n --> {}:2:6\n  |\n2 | y := z\n  |      ^",
+        dir.join("lib/broken.sig").display()
+    ));
+}
+
+#[test]
+fn test_import_conflicts_are_reported() {
+    let dir = scratch_dir("conflict");
+    write(&dir, "lib/calibration.sig", CALIBRATION);
+    let main = write(&dir, "main.sig", "{gain : real}\nimport \"lib/calibration.sig\" (gain)\n");
+
+    let error = Significance::new().run_file(&main).unwrap_err();
+    assert!(error.starts_with(&format!("error: Variable 'gain' already declared\n --> {}:2:1\n", main)), "{}", error);
+}
+
+#[test]
+fn test_runtime_errors_are_reported() {
+    let dir = scratch_dir("runtime");
+    let main = write(&dir, "main.sig", "{x : real}\nx := 1 / 0\n");
+
+    let error = Significance::new().run_file(&main).unwrap_err();
+    assert!(error.starts_with(&format!("error: Division by zero\n --> {}:2:", main)), "{}", error);
+}
+
+#[test]
+fn test_import_shadows_constant() {
+    let dir = scratch_dir("shadow");
+    write(&dir, "lib/solution.sig", "{c : real}\nc := 0.25 +/- 0.01\n");
+    let main = write(&dir, "main.sig", "import \"lib/solution.sig\" (c)\n{n : real}\nn := c * 2\n");
+
+    let mut sig = Significance::new();
+    sig.run_file(&main).unwrap();
+    assert_eq!(sig.get_var("n").unwrap().get_value().value(), 0.5);
+    assert!(sig.warnings().iter().all(|warning| !warning.contains("one_letter_constant")));
+}
+
+#[test]
+fn test_ast_output_is_opt_in() {
+    let dir = scratch_dir("ast");
+    let main = write(&dir, "main.sig", "{x : real}\nx := 2\n");
+    let output = dir.join("out/ast.json");
+
+    Significance::new().run_file(&main).unwrap();
+    assert!(!output.exists());
+
+    let mut sig = Significance::new();
+    sig.set_ast_output(Some(&output));
+    let error = sig.run_file(&main).unwrap_err();
+    assert!(error.starts_with("error: Failed to write AST to file"), "{}", error);
+
+    fs::create_dir_all(dir.join("out")).unwrap();
+    sig.run_file(&main).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&output).unwrap()).unwrap();
+    assert_eq!(json["statements"][0]["VarDeclaration"]["name"], "x");
+}
+
+#[test]
+fn test_syntax_and_semantic_errors_reported_together() {
+    let dir = scratch_dir("recovery");
+    let main = write(&dir, "main.sig", "{x : real}\nx := * 2\n{y real}\nz := 1\n");
+
+    let error = Significance::new().run_file(&main).unwrap_err();
+    let headlines: Vec<&str> = error.lines().filter(|line| line.starts_with("error:")).collect();
+    assert_eq!(headlines.len(), 3, "{}", error);
+    assert!(error.contains(&format!("{}:2:6", main)), "{}", error);
+    assert!(error.contains(&format!("{}:3:4", main)), "{}", error);
+    assert_eq!(headlines[2], "error: Variable 'z' not declared");
+}
+
+#[test]
+fn test_lexical_errors_do_not_stop_recovery() {
+    let dir = scratch_dir("lexical");
+    let main = write(&dir, "main.sig", "{x : real}\ny := @\nz := (1 +\nq := undefinedvar\n");
+
+    let error = Significance::new().run_file(&main).unwrap_err();
+    let headlines: Vec<&str> = error.lines().filter(|line| line.starts_with("error:")).collect();
+    assert_eq!(headlines, vec![
+        "error: Unexpected character '@'",
+        "error: Expected expression",
+        "error: Variable 'q' not declared",
+        "error: Variable 'undefinedvar' not declared",
+    ], "{}", error);
+}
+
+#[test]
+fn test_failed_assertions_are_reported() {
+    let dir = scratch_dir("assert");
+    let main = write(&dir, "main.sig", "{g : real}\ng := 1.52 +/- 0.01\nassert_close(g, 1.5, 0.05)\nassert_consistent(g, 1.5, 1)\n");
+
+    let error = Significance::new().run_file(&main).unwrap_err();
+    assert!(error.starts_with(&format!("error: Assertion failed: 1.52 +/- 0.01 and 1.5 differ by 2.00 standard uncertainties, more than 1\n --> {}:4:1\n", main)), "{}", error);
+
+    let mut sig = Significance::new();
+    let output = sig.parse_repl("assert(0)").unwrap();
+    assert!(output[0].starts_with("error: Assertion failed: argument is 0\n --> <repl>:1:1"), "{}", output[0]);
+}
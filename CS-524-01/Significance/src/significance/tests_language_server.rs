@@ -0,0 +1,290 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use std::io::Cursor;
+
+use serde_json::{json, Value};
+
+use crate::significance::language_server::{serve, read_message, write_message};
+use crate::significance::tests_support::{scratch_dir, write};
+
+const URI: &str = "untitled:calibration.sig";
+
+const SOURCE: &str = "{gain : real}\n{reading : real}\ngain := 1.5 +/- 0.1\nreading := gain * sqrt(4)\nreading\n";
+
+fn request(id: u64, method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+fn notification(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+fn open(uri: &str, text: &str) -> Value {
+    notification("textDocument/didOpen", json!({ "textDocument": { "uri": uri, "languageId": "significance", "version": 1, "text": text } }))
+}
+
+fn at(id: u64, method: &str, line: u64, character: u64) -> Value {
+    request(id, method, json!({ "textDocument": { "uri": URI }, "position": { "line": line, "character": character } }))
+}
+
+/// Frames the messages, runs a server over them and decodes everything it wrote
+fn run(messages: &[Value]) -> Vec<Value> {
+    let mut input = Vec::new();
+    for message in messages {
+        write_message(&mut input, message).unwrap();
+    }
+
+    let mut output = Vec::new();
+    serve(Cursor::new(input), &mut output).unwrap();
+
+    let mut reader = Cursor::new(output);
+    let mut replies = Vec::new();
+    while let Some(reply) = read_message(&mut reader).unwrap() {
+        replies.push(reply.unwrap());
+    }
+    replies
+}
+
+/// Finds the response to the request with the given id
+fn response(replies: &[Value], id: u64) -> &Value {
+    replies.iter().find(|reply| reply["id"] == id).unwrap_or_else(|| panic!("no response to request {}", id))
+}
+
+/// Collects the diagnostics published for a URI, in order
+fn published<'a>(replies: &'a [Value], uri: &str) -> Vec<&'a Value> {
+    replies.iter()
+        .filter(|reply| reply["method"] == "textDocument/publishDiagnostics" && reply["params"]["uri"] == uri)
+        .map(|reply| &reply["params"]["diagnostics"])
+        .collect()
+}
+
+fn range(start: (u64, u64), end: (u64, u64)) -> Value {
+    json!({ "start": { "line": start.0, "character": start.1 }, "end": { "line": end.0, "character": end.1 } })
+}
+
+#[test]
+fn test_initialize_and_exit() {
+    let replies = run(&[
+        request(1, "initialize", json!({ "capabilities": {} })),
+        notification("initialized", json!({})),
+        request(2, "shutdown", Value::Null),
+        notification("exit", Value::Null),
+        request(3, "shutdown", Value::Null),
+    ]);
+
+    assert_eq!(replies.len(), 2);
+    let capabilities = &response(&replies, 1)["result"]["capabilities"];
+    assert_eq!(capabilities["textDocumentSync"], 1);
+    assert_eq!(capabilities["hoverProvider"], true);
+    assert_eq!(response(&replies, 2)["result"], Value::Null);
+}
+
+#[test]
+fn test_unknown_request() {
+    let replies = run(&[request(1, "workspace/symbol", json!({ "query": "" }))]);
+    assert_eq!(response(&replies, 1)["error"]["code"], -32601);
+}
+
+#[test]
+fn test_diagnostics_on_open_and_change() {
+    let replies = run(&[
+        open(URI, "{x : real}\nx := 2 * (y + 1)\nx := *\n"),
+        notification("textDocument/didChange", json!({
+            "textDocument": { "uri": URI, "version": 2 },
+            "contentChanges": [{ "text": "{x : real}\nx := 2\nx\n" }],
+        })),
+    ]);
+
+    let diagnostics = published(&replies, URI);
+    assert_eq!(diagnostics.len(), 2);
+
+    let first = diagnostics[0].as_array().unwrap();
+    assert_eq!(first.len(), 2);
+    assert_eq!(first[0]["message"], "Expected expression");
+    assert_eq!(first[0]["range"], range((2, 5), (2, 6)));
+    assert_eq!(first[1]["message"], "Variable 'y' not declared");
+    assert_eq!(first[1]["range"], range((1, 10), (1, 11)));
+    assert_eq!(first[1]["severity"], 1);
+
+    assert_eq!(diagnostics[1], &json!([]));
+}
+
+#[test]
+fn test_runtime_errors_are_published() {
+    let replies = run(&[open(URI, "{x : real}\nx := 1 / 0\n")]);
+    let diagnostics = published(&replies, URI);
+    assert_eq!(diagnostics[0][0]["message"], "Division by zero");
+}
+
+#[test]
+fn test_lint_warnings_are_published() {
+    let replies = run(&[open(URI, "{x : real}\n{y : real}\nx := 12.3456 +/- 0.5\nx\n")]);
+    let diagnostics = published(&replies, URI);
+    let diagnostics = diagnostics[0].as_array().unwrap();
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0]["message"], "Variable 'y' is declared but never assigned [unassigned_variable]");
+    assert_eq!(diagnostics[0]["range"], range((1, 0), (1, 10)));
+    assert_eq!(diagnostics[0]["severity"], 2);
+    assert_eq!(diagnostics[1]["message"], "'12.3456' has more digits than its uncertainty justifies [excess_precision]");
+}
+
+#[test]
+fn test_hover_shows_type_and_value() {
+    let replies = run(&[
+        open(URI, SOURCE),
+        at(1, "textDocument/hover", 3, 12),
+        at(2, "textDocument/hover", 3, 19),
+        at(3, "textDocument/hover", 4, 0),
+        at(4, "textDocument/hover", 3, 9),
+    ]);
+
+    let hover = &response(&replies, 1)["result"];
+    assert_eq!(hover["contents"]["value"], "```\n{gain : real}\n```\n\n= `1.5 +/- 0.1`");
+    assert_eq!(hover["range"], range((3, 11), (3, 15)));
+
+    let hover = &response(&replies, 2)["result"];
+    assert_eq!(hover["contents"]["value"], "```\nsqrt(x) : real function\n```");
+
+    let value = response(&replies, 3)["result"]["contents"]["value"].as_str().unwrap().to_string();
+    assert!(value.starts_with("```\n{reading : real}\n```\n\n= `3 +/- 0.2"), "{}", value);
+
+    // Whitespace between tokens
+    assert_eq!(response(&replies, 4)["result"], Value::Null);
+}
+
+#[test]
+fn test_hover_on_constant() {
+    let replies = run(&[open(URI, "2 * pi\n"), at(1, "textDocument/hover", 0, 5)]);
+    let value = response(&replies, 1)["result"]["contents"]["value"].as_str().unwrap().to_string();
+    assert!(value.contains("Built-in constant: ratio of a circle's circumference to its diameter"), "{}", value);
+}
+
+#[test]
+fn test_definition() {
+    let replies = run(&[
+        open(URI, SOURCE),
+        at(1, "textDocument/definition", 3, 14),
+        at(2, "textDocument/definition", 3, 19),
+    ]);
+
+    assert_eq!(response(&replies, 1)["result"], json!({ "uri": URI, "range": range((0, 1), (0, 5)) }));
+    assert_eq!(response(&replies, 2)["result"], Value::Null);
+}
+
+#[test]
+fn test_references() {
+    let replies = run(&[
+        open(URI, SOURCE),
+        at(1, "textDocument/references", 2, 0),
+        request(2, "textDocument/references", json!({
+            "textDocument": { "uri": URI },
+            "position": { "line": 2, "character": 0 },
+            "context": { "includeDeclaration": false },
+        })),
+    ]);
+
+    let ranges: Vec<&Value> = response(&replies, 1)["result"].as_array().unwrap().iter().map(|location| &location["range"]).collect();
+    assert_eq!(ranges, vec![&range((0, 1), (0, 5)), &range((2, 0), (2, 4)), &range((3, 11), (3, 15))]);
+
+    assert_eq!(response(&replies, 2)["result"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_completion() {
+    let replies = run(&[
+        open(URI, SOURCE),
+        request(1, "textDocument/completion", json!({ "textDocument": { "uri": URI }, "position": { "line": 4, "character": 0 } })),
+    ]);
+
+    let items = response(&replies, 1)["result"].as_array().unwrap();
+    let item = |label: &str| items.iter().find(|item| item["label"] == label).unwrap_or_else(|| panic!("no item {}", label));
+    assert_eq!(item("gain")["kind"], 6);
+    assert_eq!(item("sqrt")["kind"], 3);
+    assert_eq!(item("atan2")["detail"], "function of 2 argument(s)");
+    assert_eq!(item("budget")["kind"], 3);
+    assert_eq!(item("c")["kind"], 21);
+}
+
+#[test]
+fn test_imported_file_diagnostics() {
+    let dir = scratch_dir("lsp");
+    let broken = write(&dir, "lib/broken.sig", "{y : real}\ny := z\n");
+    let uri = format!("file://{}", dir.join("main.sig").display());
+    let imported = format!("file://{}", broken);
+
+    let replies = run(&[
+        open(&uri, "import \"lib/broken.sig\" as broken\n"),
+        notification("textDocument/didChange", json!({
+            "textDocument": { "uri": uri, "version": 2 },
+            "contentChanges": [{ "text": "1 + 1\n" }],
+        })),
+    ]);
+
+    let diagnostics = published(&replies, &imported);
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0][0]["message"], "Variable 'z' not declared");
+    assert_eq!(diagnostics[0][0]["range"], range((1, 5), (1, 6)));
+    assert_eq!(diagnostics[1], &json!([]));
+    assert_eq!(published(&replies, &uri)[0], &json!([]));
+}
+
+#[test]
+fn test_wrong_arity_does_not_stop_server() {
+    let replies = run(&[
+        open(URI, "{x : real}\nx := sin(1, 2)\n"),
+        request(1, "shutdown", Value::Null),
+    ]);
+
+    let diagnostics = published(&replies, URI);
+    assert_eq!(diagnostics[0][0]["message"], "Function 'sin' takes 1 argument(s) but was given 2");
+    assert_eq!(diagnostics[0][0]["range"], range((1, 5), (1, 8)));
+    assert_eq!(response(&replies, 1)["result"], Value::Null);
+}
+
+#[test]
+fn test_lexical_errors_do_not_hide_others() {
+    let replies = run(&[open(URI, "{x : real}\ny := @\nz := (1 +\nq := undefinedvar\n")]);
+
+    let diagnostics = published(&replies, URI);
+    let messages: Vec<&Value> = diagnostics[0].as_array().unwrap().iter().map(|d| &d["message"]).collect();
+    assert_eq!(messages, vec![
+        "Unexpected character '@'",
+        "Expected expression",
+        "Variable 'q' not declared",
+        "Variable 'undefinedvar' not declared",
+    ]);
+}
+
+#[test]
+fn test_warnings_are_published() {
+    let replies = run(&[open(URI, "{y : real}\ny := 2 * c\ny\n")]);
+
+    let diagnostics = published(&replies, URI);
+    assert_eq!(diagnostics[0].as_array().unwrap().len(), 1);
+    assert_eq!(diagnostics[0][0]["severity"], 2);
+    assert_eq!(diagnostics[0][0]["range"], range((1, 9), (1, 10)));
+}
+
+#[test]
+fn test_malformed_json_gets_parse_error() {
+    let mut input = b"Content-Length: 8\r\n\r\n{\"id\": 1".to_vec();
+    write_message(&mut input, &request(2, "shutdown", Value::Null)).unwrap();
+
+    let mut output = Vec::new();
+    serve(Cursor::new(input), &mut output).unwrap();
+
+    let mut reader = Cursor::new(output);
+    let mut replies = Vec::new();
+    while let Some(reply) = read_message(&mut reader).unwrap() {
+        replies.push(reply.unwrap());
+    }
+    assert_eq!(replies.len(), 2);
+    assert_eq!(replies[0]["id"], Value::Null);
+    assert_eq!(replies[0]["error"]["code"], -32700);
+    assert_eq!(response(&replies, 2)["result"], Value::Null);
+}
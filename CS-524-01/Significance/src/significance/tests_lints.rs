@@ -0,0 +1,196 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use crate::significance::semantic_analyzer::{SemanticAnalyzer, SemanticWarning};
+use crate::significance::tokenizer::Tokenizer;
+use crate::significance::ast_parser::AstParser;
+use crate::significance::language_parser::Significance;
+use crate::significance::diagnostic::{Diagnostic, Severity};
+use crate::significance::numbers::Number;
+use crate::significance::lints::{Lint, LintLevel, LintConfig, parse_lint_names, LINTS};
+
+/// Tokenizes, parses and analyzes a source string, returning the lint warnings
+fn lint(source: &str) -> Vec<SemanticWarning> {
+    let tokens = Tokenizer::new(source).tokenize().unwrap();
+    let program = AstParser::new().parse_program(tokens).unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.import_standard_library().import_constants_library();
+    analyzer.analyze_program(&program);
+    analyzer.get_warnings().clone()
+}
+
+fn lints(source: &str) -> Vec<Lint> {
+    lint(source).iter().map(|warning| warning.lint).collect()
+}
+
+/// Checks a source text with an interpreter, returning its diagnostics
+fn check(sig: &mut Significance, source: &str) -> Vec<Diagnostic> {
+    sig.check("main.sig", source, None).2
+}
+
+#[test]
+fn test_unassigned_and_unused_variables() {
+    let warnings = lint("{x : real}\n{y : real}\n{z : real}\ny := 2\nz := 3\nz * 2\n");
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].lint, Lint::UnassignedVariable);
+    assert_eq!(warnings[0].message, "Variable 'x' is declared but never assigned");
+    assert_eq!((warnings[0].span.start.column, warnings[0].span.end.column), (1, 11));
+    assert_eq!(warnings[1].lint, Lint::UnusedVariable);
+    assert_eq!(warnings[1].message, "Variable 'y' is assigned but never used");
+    assert_eq!(warnings[1].span.start.line, 2);
+}
+
+#[test]
+fn test_unused_variables_not_checked_after_errors() {
+    assert!(lints("{x : real}\nx := 2 * w\n").is_empty());
+}
+
+#[test]
+fn test_large_uncertainty() {
+    assert_eq!(lints("1 +/- 2"), vec![Lint::LargeUncertainty]);
+    assert_eq!(lints("12 +/- 120%"), vec![Lint::LargeUncertainty]);
+    assert_eq!(lints("1(+0.5/-1.5)"), vec![Lint::LargeUncertainty]);
+    assert!(lints("0 +/- 0.1").is_empty());
+    assert!(lints("2 +/- 2").is_empty());
+}
+
+#[test]
+fn test_nonpositive_uncertainty() {
+    let warnings = lint("{x : real}\nx := 12.3 +/- (-0.5)\nx +/- 0\n2 +/- 0%\n");
+    let found: Vec<(Lint, &str)> = warnings.iter().map(|warning| (warning.lint, warning.message.as_str())).collect();
+    assert_eq!(found, vec![
+        (Lint::NonPositiveUncertainty, "Negative uncertainty -0.5 is used as 0.5"),
+        (Lint::NonPositiveUncertainty, "Zero uncertainty has no effect"),
+        (Lint::NonPositiveUncertainty, "Zero uncertainty has no effect"),
+    ]);
+    assert_eq!((warnings[1].span.start.line, warnings[1].span.start.column), (3, 7));
+    assert!(lints("12.3 +/- 0.5 +/- nominal(pi)").is_empty());
+}
+
+#[test]
+fn test_division_by_zero() {
+    let warnings = lint("{n : real}\nn := 2 * 3 - 6\n1 / n\n5 % (2 - 2)\n1 / (0 +/- 0.1)\n1 / sin(0)\n");
+    let found: Vec<(&str, usize)> = warnings.iter().map(|warning| (warning.message.as_str(), warning.span.start.line)).collect();
+    assert_eq!(found, vec![("Division by zero", 3), ("Modulus by zero", 4)]);
+}
+
+#[test]
+fn test_constant_value() {
+    let tokens = Tokenizer::new("{n : real}\nn := -(2 ** 3) / 8 + pi * 0\n").tokenize().unwrap();
+    let program = AstParser::new().parse_program(tokens).unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.import_constants_library();
+    analyzer.analyze_program(&program);
+
+    let tokens = Tokenizer::new("n + 1").tokenize().unwrap();
+    let program = AstParser::new().parse_program(tokens).unwrap();
+    let expression = match &program.statements[0] {
+        crate::significance::ast_parser::Statement::Expression(expression) => expression,
+        _ => panic!("Expected an expression statement"),
+    };
+    assert_eq!(analyzer.constant_value(expression), Some(0.0));
+}
+
+#[test]
+fn test_excess_precision() {
+    let warnings = lint("12.3456 +/- 0.5");
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].lint, Lint::ExcessPrecision);
+    assert_eq!(warnings[0].message, "'12.3456' has more digits than its uncertainty justifies");
+
+    assert_eq!(lints("1.23456e3 +/- 2"), vec![Lint::ExcessPrecision]);
+    assert_eq!(lints("12.3456 +/- 1%"), vec![Lint::ExcessPrecision]);
+    assert!(lints("12.35 +/- 0.5").is_empty());
+    assert!(lints("12.3 +/- 0.1").is_empty());
+    assert!(lints("12000 +/- 500").is_empty());
+    assert!(lints("12.3456(5)").is_empty());
+    assert!(lints("12.3456").is_empty());
+}
+
+#[test]
+fn test_lint_names() {
+    for lint in LINTS {
+        assert_eq!(Lint::from_name(lint.name()), Some(lint));
+    }
+    assert_eq!(parse_lint_names("unused_variable, excess_precision"), Ok(vec![Lint::UnusedVariable, Lint::ExcessPrecision]));
+    assert_eq!(parse_lint_names("all").unwrap().len(), LINTS.len());
+    assert_eq!(parse_lint_names("unused"), Err("Unknown lint 'unused'".to_string()));
+}
+
+#[test]
+fn test_directives() {
+    let tokens = Tokenizer::new("# lint: allow(excess_precision) deny(all)\n# lint:warn(unused_variable)\n# a comment\n").tokenize().unwrap();
+    let mut config = LintConfig::new();
+    assert!(config.apply_directives(&tokens).is_empty());
+    assert_eq!(config.level(Lint::ExcessPrecision), LintLevel::Deny);
+    assert_eq!(config.level(Lint::UnusedVariable), LintLevel::Warn);
+    assert_eq!(config.level(Lint::DivisionByZero), LintLevel::Deny);
+
+    let tokens = Tokenizer::new("x # lint: forbid(all)\n# lint: allow(nothing)\n# lint: allow(all\n").tokenize().unwrap();
+    let warnings = LintConfig::new().apply_directives(&tokens);
+    let messages: Vec<&str> = warnings.iter().map(|warning| warning.message.as_str()).collect();
+    assert_eq!(messages, vec![
+        "Unknown lint level 'forbid' (expected allow, warn or deny)",
+        "Unknown lint 'nothing'",
+        "Malformed lint directive: missing ')'",
+    ]);
+    assert!(warnings.iter().all(|warning| warning.severity == Severity::Warning));
+    assert_eq!(warnings[0].span.start.column, 3);
+}
+
+#[test]
+fn test_warnings_are_reported_after_errors() {
+    let mut sig = Significance::new();
+    let diagnostics = check(&mut sig, "{x : real}\nx := 1 / 0\n");
+    let found: Vec<(Severity, &str)> = diagnostics.iter().map(|d| (d.severity, d.message.as_str())).collect();
+    assert_eq!(found, vec![
+        (Severity::Error, "Division by zero"),
+        (Severity::Warning, "Variable 'x' is assigned but never used [unused_variable]"),
+        (Severity::Warning, "Division by zero [division_by_zero]"),
+    ]);
+}
+
+#[test]
+fn test_denied_lint_stops_the_program() {
+    let mut sig = Significance::new();
+    sig.set_lint_level(Lint::UnusedVariable, LintLevel::Deny);
+    let diagnostics = check(&mut sig, "{x : real}\nx := 2\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].is_error());
+    assert!(sig.get_var("x").is_none_or(|var| var.get_value().value() != 2.0));
+
+    let mut sig = Significance::new();
+    sig.set_lint_level(Lint::UnusedVariable, LintLevel::Deny);
+    let diagnostics = check(&mut sig, "# lint: allow(unused_variable)\n{x : real}\nx := 2\n");
+    assert!(diagnostics.is_empty());
+    assert_eq!(sig.get_var("x").unwrap().get_value().value(), 2.0);
+}
+
+#[test]
+fn test_allowed_lints_are_not_reported() {
+    let mut sig = Significance::new();
+    for lint in LINTS {
+        sig.set_lint_level(lint, LintLevel::Allow);
+    }
+    assert!(check(&mut sig, "{x : real}\n{y : real}\ny := 1 +/- 2\n").is_empty());
+}
+
+#[test]
+fn test_repl_lints() {
+    let mut sig = Significance::new();
+    sig.parse_repl("1.23456 +/- 0.5").unwrap();
+    let warnings = sig.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].starts_with("warning: '1.23456' has more digits"), "{}", warnings[0]);
+
+    // Directives entered in the REPL apply to later lines, and unused variables are not checked
+    assert!(sig.parse_repl("# lint: deny(excess_precision)").unwrap().is_empty());
+    assert!(sig.parse_repl("{x : real}").unwrap().is_empty());
+    let output = sig.parse_repl("x := 1.23456 +/- 0.5").unwrap();
+    assert!(output[0].starts_with("error: '1.23456' has more digits"), "{}", output[0]);
+    assert!(!sig.get_symbol("x").unwrap().is_used());
+}
@@ -149,4 +149,70 @@ fn test_division_large_uncertainty() {
     let actual = x / y;
     let expected = Real::with_error(2.0, 1.414213562);
     assert_real(&actual, expected);
-}
\ No newline at end of file
+}
+#[test]
+fn test_plus_minus_combines_in_quadrature() {
+    let x = Real::with_error(12.3, 0.3);
+    let dx = Real::new(0.4);
+    let actual = x.plus_minus(dx);
+    let expected = Real::with_error(12.3, 0.5);
+    assert_real(&actual, expected);
+}
+
+#[test]
+fn test_plus_minus_ignores_operand_error() {
+    let x = Real::new(1.0);
+    let dx = Real::with_error(-0.2, 0.1);
+    let actual = x.plus_minus(dx);
+    let expected = Real::with_error(1.0, 0.2);
+    assert_real(&actual, expected);
+}
+
+#[test]
+fn test_asymmetric_equal_bounds_is_symmetric() {
+    let actual = Real::with_asymmetric_error(12.3, 0.4, 0.4);
+    assert!(!actual.is_asymmetric());
+    assert_eq!(actual, Real::with_error(12.3, 0.4));
+}
+
+#[test]
+fn test_asymmetric_display() {
+    let x = Real::with_asymmetric_error(12.3, 0.4, 0.2);
+    assert_eq!(x.to_string(), "12.3(+0.4/-0.2)");
+    assert_eq!(x.error(), 0.4);
+}
+
+#[test]
+fn test_asymmetric_addition() {
+    let x = Real::with_asymmetric_error(12.3, 0.4, 0.2);
+    let y = Real::with_error(2.6, 0.3);
+    let actual = x + y;
+    assert!((actual.value() - 14.9).abs() < 1e-9);
+    assert!((actual.upper_error() - 0.5).abs() < 1e-6);
+    assert!((actual.lower_error() - 0.13f64.sqrt()).abs() < 1e-6);
+}
+
+#[test]
+fn test_asymmetric_subtraction_swaps_bounds() {
+    // Subtracting x moves the result down by x's upper error
+    let x = Real::with_asymmetric_error(2.0, 0.4, 0.1);
+    let actual = Real::new(10.0) - x;
+    assert!((actual.upper_error() - 0.1).abs() < 1e-6);
+    assert!((actual.lower_error() - 0.4).abs() < 1e-6);
+}
+
+#[test]
+fn test_asymmetric_negation() {
+    let x = Real::with_asymmetric_error(2.0, 0.4, 0.1);
+    let actual = -x;
+    assert_eq!(actual, Real::with_asymmetric_error(-2.0, 0.1, 0.4));
+}
+
+#[test]
+fn test_asymmetric_multiplication() {
+    let x = Real::with_asymmetric_error(2.0, 0.4, 0.1);
+    let actual = x * Real::new(-3.0);
+    assert!((actual.value() + 6.0).abs() < 1e-9);
+    assert!((actual.upper_error() - 0.3).abs() < 1e-6);
+    assert!((actual.lower_error() - 1.2).abs() < 1e-6);
+}
@@ -0,0 +1,151 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+#![allow(clippy::approx_constant)]
+
+use crate::significance::ast_parser::{AstParser, Expression, Statement, UnaryOp};
+use crate::significance::interval::Interval;
+use crate::significance::language_parser::Significance;
+use crate::significance::numbers::Number;
+use crate::significance::optimizer::{fold_expression, fold_program};
+use crate::significance::tokenizer::Tokenizer;
+
+/// Parses a single expression statement
+fn parse(source: &str) -> Expression {
+    let tokens = Tokenizer::new(source).tokenize().unwrap();
+    match AstParser::new().parse_program(tokens).unwrap().statements.remove(0) {
+        Statement::Expression(expression) => expression,
+        other => panic!("Expected an expression statement, got {:?}", other),
+    }
+}
+
+/// Folds a single expression statement
+fn fold(source: &str) -> Expression {
+    fold_expression(&parse(source))
+}
+
+fn literal(expression: &Expression) -> Option<(f64, f64)> {
+    match expression {
+        Expression::NumberWithUncertainty { value, error, .. } => Some((*value, *error)),
+        _ => None,
+    }
+}
+
+/// Runs a program with and without folding, returning both outputs
+fn outputs(source: &str) -> (Vec<String>, Vec<String>) {
+    let run = |folding: bool| {
+        let mut sig = Significance::new();
+        sig.set_constant_folding(folding);
+        let (mut output, diagnostics) = sig.capture("main.sig", source, None);
+        output.extend(diagnostics.iter().map(|diagnostic| diagnostic.to_string()));
+        output
+    };
+    (run(true), run(false))
+}
+
+#[test]
+fn test_literal_subtrees_are_folded() {
+    assert_eq!(literal(&fold("2.0 * 3.14159 / 180")), Some((2.0 * 3.14159 / 180.0, 0.0)));
+    assert_eq!(literal(&fold("(2 ** 3) // 3")), Some((2.0, 0.0)));
+    assert_eq!(literal(&fold("-(2 * 3)")), Some((-6.0, 0.0)));
+    assert_eq!(literal(&fold("sqrt(16) + nominal(2)")), Some((6.0, 0.0)));
+    assert_eq!(literal(&fold("atan2(0, 1)")), Some((0.0, 0.0)));
+}
+
+#[test]
+fn test_folded_literal_keeps_position() {
+    let expression = parse("1 + (2 * 3)");
+    let folded = fold_expression(&expression);
+    assert_eq!(folded.position(), expression.position());
+    assert_eq!(folded.span(), expression.span());
+
+    match (parse("x * (2 * 3)"), fold("x * (2 * 3)")) {
+        (Expression::Binary { right: original, .. }, Expression::Binary { right, .. }) => {
+            assert_eq!(literal(&right), Some((6.0, 0.0)));
+            assert_eq!((right.position(), right.span()), (original.position(), original.span()));
+            assert_eq!((right.span().start.column, right.span().end.column), (5, 12));
+        },
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn test_unary_plus_is_removed() {
+    assert!(matches!(fold("+x"), Expression::Variable { pos, .. } if pos.column == 2));
+    assert!(matches!(fold("-(+x)"), Expression::Unary { op: UnaryOp::Minus, operand, .. } if matches!(*operand, Expression::Variable { .. })));
+    assert_eq!(literal(&fold("+(+2)")), Some((2.0, 0.0)));
+}
+
+#[test]
+fn test_uncertain_values_are_not_folded() {
+    // Uncertain literals are sources, and variables may be correlated with themselves
+    assert!(matches!(fold("(2 +/- 0.1) * 3"), Expression::Binary { .. }));
+    assert!(matches!(fold("-(2 +/- 0.1)"), Expression::Unary { .. }));
+    assert!(matches!(fold("x - x"), Expression::Binary { .. }));
+    assert!(matches!(fold("x * 0"), Expression::Binary { .. }));
+    assert!(matches!(fold("pi * 2"), Expression::Binary { .. }));
+    assert!(matches!(fold("nominal(2 +/- 0.5)"), Expression::FunctionCall { .. }));
+    assert!(matches!(fold("1 +/- (0.1 * 2)"), Expression::Binary { right, .. } if literal(&right).is_some()));
+    assert!(matches!(fold("budget(1 + 2)"), Expression::FunctionCall { args, .. } if literal(&args[0]).is_some()));
+    assert!(matches!(fold("assert(1)"), Expression::FunctionCall { .. }));
+}
+
+#[test]
+fn test_errors_are_not_folded_away() {
+    assert!(matches!(fold("1 / (2 - 2)"), Expression::Binary { right, .. } if literal(&right) == Some((0.0, 0.0))));
+    assert!(matches!(fold("sqrt(-1)"), Expression::FunctionCall { .. }));
+
+    let (folded, unfolded) = outputs("{x : real}\nx := 1 / (2 - 2)\nx\n");
+    assert_eq!(folded, unfolded);
+    assert!(folded.contains(&"Error at 2:6: Division by zero".to_string()), "{:?}", folded);
+}
+
+#[test]
+fn test_comparisons_and_logical_operators_are_folded() {
+    assert_eq!(literal(&fold("2 * 3 > 5")), Some((1.0, 0.0)));
+    assert_eq!(literal(&fold("!(1 == 1) || 2 <= 1")), Some((0.0, 0.0)));
+    assert_eq!(literal(&fold("!0")), Some((1.0, 0.0)));
+    assert!(matches!(fold("0 && 1 / 0"), Expression::Binary { .. }));
+    assert!(matches!(fold("!(2 +/- 0.1)"), Expression::Unary { op: UnaryOp::Not, .. }));
+
+    let (folded, unfolded) = outputs("{x : real}\nx := 2 +/- 0.1\n0 && 1 / 0\n!(x > 1) || 3 >= 2\nassert(1 < 2 && x != 0)\n");
+    assert_eq!(folded, unfolded);
+    assert!(!folded.iter().any(|line| line.starts_with("Error")), "{:?}", folded);
+}
+
+#[test]
+fn test_folding_does_not_change_results() {
+    let programs = [
+        "{x : real}\n{y : real}\nx := 12.3 +/- 0.5\ny := x * (2.0 * 3.14159 / 180) + 1 ** 3\ny\nbudget(y)\nx - x\n",
+        "{a : real}\na := sin(2 * 0.5) +/- 0.1\n(a + +2) * -(3 // 2)\nassert_close(a, 0.84, 0.01)\n",
+        "-(2 +/- 0.1) * (4 % 3) + 2 ** 0.5\n",
+    ];
+    for source in programs {
+        let (folded, unfolded) = outputs(source);
+        assert_eq!(folded, unfolded, "{}", source);
+    }
+}
+
+#[test]
+fn test_folding_is_toggleable() {
+    let mut sig = Significance::new();
+    assert!(sig.is_constant_folding());
+    sig.set_constant_folding(false);
+    assert!(!sig.is_constant_folding());
+    assert!(!Significance::<Interval>::with_backend().is_constant_folding());
+
+    let tokens = Tokenizer::new("{x : real}\nx := +(1 + 1)\nx\n").tokenize().unwrap();
+    let program = AstParser::new().parse_program(tokens).unwrap();
+    match &fold_program(&program).statements[1] {
+        Statement::Assignment { value, .. } => assert_eq!(literal(value), Some((2.0, 0.0))),
+        other => panic!("{:?}", other),
+    }
+
+    sig.parse_repl("{y : real}").unwrap();
+    sig.parse_repl("y := +(2 * 3)").unwrap();
+    assert_eq!(sig.get_var("y").unwrap().get_value().value(), 6.0);
+}
@@ -0,0 +1,177 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use crate::significance::numbers::{Number, Real, assert_real};
+use crate::significance::propagation::{propagate, propagate_with_order, gradient, partial_derivative, second_partial_derivative, PropagationOrder};
+use crate::significance::standard_lib::{std_lib_call, std_lib_call_with_order, lookup_std_function, resolve_builtin, Builtin, STD_LIB};
+use crate::significance::tokenizer::Position;
+
+fn dummy_pos() -> Position {
+    Position { line: 1, column: 1 }
+}
+
+#[test]
+fn test_partial_derivative_polynomial() {
+    let f = |x: &[f64]| x[0].powi(3);
+    let actual = partial_derivative(&f, &[2.0], 0);
+    assert!((actual - 12.0).abs() < 1e-6, "expected 12, got {}", actual);
+}
+
+#[test]
+fn test_gradient_two_arguments() {
+    let f = |x: &[f64]| x[0] * x[1] * x[1];
+    let actual = gradient(&f, &[3.0, 2.0]);
+    assert!((actual[0] - 4.0).abs() < 1e-6);
+    assert!((actual[1] - 12.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_partial_derivative_at_domain_edge() {
+    // sqrt is undefined just below zero, so a one-sided difference must be used
+    let f = |x: &[f64]| x[0].sqrt();
+    let actual = partial_derivative(&f, &[1e-20], 0);
+    assert!(actual.is_finite() && actual > 0.0);
+}
+
+#[test]
+fn test_propagate_exact_arguments() {
+    let actual = propagate(|x: &[f64]| x[0].exp(), &[Real::new(1.0)]);
+    let expected = Real::new(std::f64::consts::E);
+    assert_real(&actual, expected);
+}
+
+#[test]
+fn test_propagate_matches_sine_formula() {
+    let x = Real::with_error(2.1, 0.1);
+    let actual = propagate(|v: &[f64]| v[0].sin(), &[x]);
+    let expected = Real::with_error(2.1f64.sin(), 2.1f64.cos().abs() * 0.1);
+    assert_real(&actual, expected);
+}
+
+#[test]
+fn test_propagate_matches_multiplication() {
+    let a = Real::with_error(5.0, 0.2);
+    let b = Real::with_error(2.3, 0.05);
+    let actual = propagate(|v: &[f64]| v[0] * v[1], &[a.clone(), b.clone()]);
+    let expected = a * b;
+    assert_real(&actual, expected);
+}
+
+#[test]
+fn test_std_lib_sqrt_matches_root() {
+    let x = Real::with_error(16.0, 0.4);
+    let expected = x.root(Real::new(2.0));
+    let actual = std_lib_call("sqrt", &[x], &dummy_pos());
+    assert_real(&actual, expected);
+}
+
+#[test]
+fn test_std_lib_ln() {
+    let x = Real::with_error(4.0, 0.2);
+    let actual = std_lib_call("ln", &[x], &dummy_pos());
+    let expected = Real::with_error(4.0f64.ln(), 0.05);
+    assert_real(&actual, expected);
+}
+
+#[test]
+fn test_std_lib_hypot() {
+    let x = Real::with_error(3.0, 0.3);
+    let y = Real::with_error(4.0, 0.4);
+    let actual = std_lib_call("hypot", &[x, y], &dummy_pos());
+    // ∂/∂x = 3/5, ∂/∂y = 4/5 → σ = sqrt(0.18² + 0.32²)
+    let expected = Real::with_error(5.0, (0.18f64.powi(2) + 0.32f64.powi(2)).sqrt());
+    assert_real(&actual, expected);
+}
+
+#[test]
+fn test_lookup_std_function() {
+    assert_eq!(lookup_std_function("atan2").unwrap().arity, 2);
+    assert!(lookup_std_function("not_a_function").is_none());
+}
+
+#[test]
+#[should_panic(expected = "takes 1 argument")]
+fn test_std_lib_wrong_arity() {
+    std_lib_call("sin", &[Real::new(1.0), Real::new(2.0)], &dummy_pos());
+}
+
+#[test]
+fn test_resolve_builtin() {
+    assert_eq!(resolve_builtin("budget", 1, &dummy_pos()), Builtin::Budget);
+    assert_eq!(resolve_builtin("nominal", 1, &dummy_pos()), Builtin::Nominal);
+    assert_eq!(resolve_builtin("assert_close", 3, &dummy_pos()), Builtin::Assertion("assert_close"));
+    let Builtin::Std(function) = resolve_builtin("atan2", 2, &dummy_pos()) else { panic!("expected a std lib function") };
+    assert_eq!(STD_LIB[function].name, "atan2");
+}
+
+#[test]
+#[should_panic(expected = "takes 1 argument")]
+fn test_resolve_builtin_wrong_arity() {
+    resolve_builtin("budget", 2, &dummy_pos());
+}
+
+#[test]
+fn test_second_partial_derivative() {
+    let f = |x: &[f64]| x[0] * x[0] * x[1];
+    let d_xx = second_partial_derivative(&f, &[3.0, 2.0], 0, 0);
+    let d_xy = second_partial_derivative(&f, &[3.0, 2.0], 0, 1);
+    assert!((d_xx - 4.0).abs() < 1e-5, "expected 4, got {}", d_xx);
+    assert!((d_xy - 6.0).abs() < 1e-5, "expected 6, got {}", d_xy);
+}
+
+#[test]
+fn test_second_order_equals_first_order_for_linear() {
+    let args = [Real::with_error(5.5, 0.2), Real::with_error(3.2, 0.05)];
+    let first = propagate(|v: &[f64]| v[0] - 2.0 * v[1], &args);
+    let second = propagate_with_order(|v: &[f64]| v[0] - 2.0 * v[1], &args, PropagationOrder::Second);
+    assert_real(&second, first);
+}
+
+#[test]
+fn test_second_order_square_at_zero() {
+    // x ~ N(0, 1): x² is χ² with one degree of freedom, mean 1 and variance 2
+    let x = Real::with_error(0.0, 1.0);
+    let first = propagate(|v: &[f64]| v[0] * v[0], std::slice::from_ref(&x));
+    let second = propagate_with_order(|v: &[f64]| v[0] * v[0], &[x], PropagationOrder::Second);
+    assert_real(&first, Real::new(0.0));
+    assert_real(&second, Real::with_error(1.0, 2.0f64.sqrt()));
+}
+
+#[test]
+fn test_second_order_product_of_zero_means() {
+    // Var(ab) = μa²σb² + μb²σa² + σa²σb² exactly for independent Gaussians
+    let a = Real::with_error(0.0, 1.0);
+    let b = Real::with_error(0.0, 2.0);
+    let actual = propagate_with_order(|v: &[f64]| v[0] * v[1], &[a, b], PropagationOrder::Second);
+    assert_real(&actual, Real::with_error(0.0, 2.0));
+}
+
+#[test]
+fn test_second_order_sine_at_stationary_point() {
+    // For x ~ N(π/2, σ²): E[sin x] = e^(-σ²/2), Var[sin x] = ½(1 + e^(-2σ²)) - e^(-σ²)
+    let sigma: f64 = 0.1;
+    let x = Real::with_error(std::f64::consts::FRAC_PI_2, sigma);
+    let exact_mean = (-sigma * sigma / 2.0).exp();
+    let exact_sigma = (0.5 * (1.0 + (-2.0 * sigma * sigma).exp()) - (-sigma * sigma).exp()).sqrt();
+
+    let first = std_lib_call("sin", std::slice::from_ref(&x), &dummy_pos());
+    let second = std_lib_call_with_order("sin", &[x], PropagationOrder::Second, &dummy_pos());
+
+    assert!(first.error() < 1e-9, "first order misses the curvature entirely");
+    assert!((second.value() - exact_mean).abs() < 1e-4);
+    assert!((second.error() - exact_sigma).abs() / exact_sigma < 0.02);
+}
+
+#[test]
+fn test_std_lib_asymmetric_decreasing_function() {
+    // cos is decreasing on (0, π), so the upper error of x becomes the lower error of cos(x)
+    let x = Real::with_asymmetric_error(1.0, 0.02, 0.01);
+    let actual = std_lib_call("cos", &[x], &dummy_pos());
+    let slope = 1.0f64.sin();
+    assert!((actual.upper_error() - slope * 0.01).abs() < 1e-8);
+    assert!((actual.lower_error() - slope * 0.02).abs() < 1e-8);
+}
@@ -0,0 +1,83 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use crate::significance::semantic_analyzer::{SemanticAnalyzer, SemanticError};
+use crate::significance::tokenizer::Tokenizer;
+use crate::significance::ast_parser::AstParser;
+
+/// Tokenizes, parses and analyzes a source string, returning the semantic errors
+fn analyze(source: &str) -> Vec<SemanticError> {
+    let tokens = Tokenizer::new(source).tokenize().unwrap();
+    let program = AstParser::new().parse_program(tokens).unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.import_standard_library();
+    analyzer.analyze_program(&program);
+    analyzer.get_errors().clone()
+}
+
+#[test]
+fn test_undeclared_variable() {
+    let errors = analyze("x := 5");
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], SemanticError::VariableNotDeclared(..)));
+}
+
+#[test]
+fn test_errors_point_at_identifier() {
+    let errors = analyze("{y : real}\ny := 2 * (x + sqrt(w))");
+    assert_eq!(errors.len(), 2);
+    match (&errors[0], &errors[1]) {
+        (SemanticError::VariableNotDeclared(x, x_pos), SemanticError::VariableNotDeclared(w, w_pos)) => {
+            assert_eq!((x.as_str(), x_pos.line, x_pos.column), ("x", 2, 11));
+            assert_eq!((w.as_str(), w_pos.line, w_pos.column), ("w", 2, 20));
+        }
+        _ => panic!("Expected two VariableNotDeclared errors"),
+    }
+}
+
+#[test]
+fn test_plus_minus_exact_operand() {
+    let errors = analyze("{x : real}\n{dx : real}\ndx := 0.2 * 3\nx := 12.3 +/- (dx / 2)\nx +/- sqrt(dx)");
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test_plus_minus_uncertain_operand() {
+    let errors = analyze("{dx : real}\ndx := 0.5 +/- 0.1\n12.3 +/- dx");
+    assert_eq!(errors.len(), 1);
+    match &errors[0] {
+        SemanticError::UncertainErrorOperand(pos) => assert_eq!((pos.line, pos.column), (3, 6)),
+        other => panic!("Expected UncertainErrorOperand, got {}", other),
+    }
+}
+
+#[test]
+fn test_plus_minus_nominal_operand() {
+    let errors = analyze("{dx : real}\ndx := 0.5 +/- 0.1\n12.3 +/- nominal(dx)");
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test_built_in_argument_count() {
+    let errors = analyze("atan2(1)\nassert_close(2, 1)\nassert(2)\nassert_consistent(2, 1, 2, 3)");
+    let messages: Vec<String> = errors.iter().map(|error| error.message()).collect();
+    assert_eq!(messages, vec![
+        "Function 'atan2' takes 2 argument(s) but was given 1",
+        "Function 'assert_close' takes 3 argument(s) but was given 2",
+        "Function 'assert_consistent' takes 3 argument(s) but was given 4",
+    ]);
+    assert_eq!((errors[1].position().line, errors[1].position().column), (2, 1));
+}
+
+#[test]
+fn test_comparisons_are_exact() {
+    // A comparison of uncertain values is an exact 1 or 0, so it may follow `+/-`
+    let errors = analyze("{dx : real}\ndx := 0.5 +/- 0.1\n12.3 +/- (dx > 0.2)\n12.3 +/- (!dx)\n12.3 +/- (dx + 1)");
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], SemanticError::UncertainErrorOperand(..)));
+    assert_eq!(errors[0].position().line, 5);
+}
@@ -0,0 +1,206 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use std::collections::HashMap;
+
+use crate::significance::ast_parser::{AstParser, BinaryOp, Expression, Statement};
+use crate::significance::language_parser::Significance;
+use crate::significance::numbers::Number;
+use crate::significance::propagation::partial_derivative;
+use crate::significance::standard_lib::STD_LIB;
+use crate::significance::symbolic::{Formula, Symbolic};
+use crate::significance::tokenizer::Tokenizer;
+
+fn symbol(name: &str) -> Symbolic {
+    Symbolic::Symbol(name.to_string())
+}
+
+/// Runs a program, and parses a single expression statement to analyze against its variables
+fn analyze(program: &str, expression: &str) -> (Significance, Expression) {
+    let mut interpreter = Significance::new();
+    interpreter.check("main.sig", program, None);
+    let tokens = Tokenizer::new(expression).tokenize().unwrap();
+    match AstParser::new().parse_program(tokens).unwrap().statements.remove(0) {
+        Statement::Expression(expression) => (interpreter, expression),
+        other => panic!("Expected an expression statement, got {:?}", other),
+    }
+}
+
+fn formula(program: &str, expression: &str) -> Formula {
+    let (interpreter, expression) = analyze(program, expression);
+    interpreter.formula(&expression)
+}
+
+#[test]
+fn test_simplification() {
+    let (x, y) = (symbol("x"), symbol("y"));
+    assert_eq!(x.clone() * Symbolic::Number(0.0), Symbolic::Number(0.0));
+    assert_eq!(Symbolic::Number(1.0) * x.clone() + Symbolic::Number(0.0), x);
+    assert_eq!((x.clone() * x.clone()).to_text(), "x**2");
+    assert_eq!((x.clone() + x.clone()).to_text(), "2 * x");
+    assert_eq!((x.clone() * Symbolic::Number(3.0) * Symbolic::Number(2.0)).to_text(), "6 * x");
+    assert_eq!((x.clone() - x.clone()).to_text(), "0");
+    assert_eq!((x.clone() + -y.clone()).to_text(), "x - y");
+    assert_eq!((-(-x.clone())).to_text(), "x");
+    assert_eq!((-x.clone()).pow(Symbolic::Number(2.0)).to_text(), "x**2");
+    assert_eq!(Symbolic::call("abs", vec![Symbolic::Number(-2.0)]), Symbolic::Number(2.0));
+    assert_eq!(Symbolic::call("sqrt", vec![Symbolic::Number(2.0)]).to_text(), "sqrt(2)");
+}
+
+#[test]
+fn test_rendering() {
+    let (x, y) = (symbol("x"), symbol("q1"));
+    let expression = (x.clone() + y.clone()) * x.clone().pow(-(y.clone())) / (x.clone() - Symbolic::Number(1.0));
+    assert_eq!(expression.to_text(), "(x + q1) * x**-q1 / (x - 1)");
+    assert_eq!(expression.to_latex(), "\\frac{\\left(x + q_{1}\\right) \\cdot x^{-q_{1}}}{x - 1}");
+
+    let expression = Symbolic::call("sin", vec![x.clone()]).pow(Symbolic::Number(2.0)) * Symbolic::Uncertainty("m_e".to_string());
+    assert_eq!(expression.to_text(), "sin(x)**2 * σ_m_e");
+    assert_eq!(expression.to_latex(), "\\sin\\left(x\\right)^{2} \\cdot \\sigma_{m_{e}}");
+
+    // Unary minus binds tighter than `**` in Significance
+    let expression = -(x.clone().pow(Symbolic::Number(2.0))) + Symbolic::Number(-2.0).pow(x);
+    assert_eq!(expression.to_text(), "-(x**2) + (-2)**x");
+    assert_eq!(expression.to_latex(), "-x^{2} + \\left(-2\\right)^{x}");
+}
+
+#[test]
+fn test_derivative_rules_match_finite_differences() {
+    let (a, b) = (symbol("a"), symbol("b"));
+    let values = HashMap::from([("a".to_string(), 0.3), ("b".to_string(), 0.7)]);
+    for function in STD_LIB {
+        let args: Vec<Symbolic> = [a.clone(), b.clone()][..function.arity].to_vec();
+        let call = Symbolic::call(function.name, args);
+        for (index, name) in ["a", "b"][..function.arity].iter().enumerate() {
+            let symbolic = call.derivative(name).evaluate(&values);
+            let numeric = partial_derivative(&function.eval, &[0.3, 0.7][..function.arity], index);
+            assert!((symbolic - numeric).abs() < 1e-6, "d{}/d{}: {} != {}", function.name, name, symbolic, numeric);
+        }
+    }
+
+    // The operators, including a power with an uncertain exponent and a modulus
+    let expression = (a.clone() * b.clone() - a.clone() / b.clone()).pow(b.clone()) + (a.clone() + Symbolic::Number(2.0)).modulo(b.clone());
+    let f = |v: &[f64]| (v[0] * v[1] - v[0] / v[1]).powf(v[1]) + (v[0] + 2.0) % v[1];
+    for (index, name) in ["a", "b"].iter().enumerate() {
+        let symbolic = expression.derivative(name).evaluate(&HashMap::from([("a".to_string(), 2.0), ("b".to_string(), 3.0)]));
+        assert!((symbolic - partial_derivative(&f, &[2.0, 3.0], index)).abs() < 1e-6, "d/d{}", name);
+    }
+}
+
+#[test]
+fn test_formula_follows_definitions() {
+    let program = "{x : real}\n{y : real}\n{w : real}\nx := 12.3 +/- 0.5\ny := 2.6 +/- 0.2\nw := x*x + y**2\n";
+    let formula = formula(program, "w");
+    assert_eq!(formula.name, "w");
+    assert_eq!(formula.to_text(), vec![
+        "w = x**2 + y**2",
+        "∂w/∂x = 2 * x = 24.6",
+        "∂w/∂y = 2 * y = 5.2",
+        format!("σ_w = sqrt((2 * x * σ_x)**2 + (2 * y * σ_y)**2) = {}", formula.error()).as_str(),
+    ]);
+    assert_eq!(formula.to_latex(), vec![
+        "w = x^{2} + y^{2}",
+        "\\frac{\\partial w}{\\partial x} = 2 x",
+        "\\frac{\\partial w}{\\partial y} = 2 y",
+        "\\sigma_{w} = \\sqrt{\\left(2 x \\cdot \\sigma_{x}\\right)^{2} + \\left(2 y \\cdot \\sigma_{y}\\right)^{2}}",
+    ]);
+
+    // x * x is one correlated input, as in the error budget
+    let (interpreter, expression) = analyze(program, "w");
+    let budget = interpreter.error_budget(&expression);
+    assert!((formula.value() - budget.result.value()).abs() < 1e-9);
+    assert!((formula.error() - budget.result.error()).abs() < 1e-6);
+}
+
+#[test]
+fn test_literal_and_plus_minus_inputs() {
+    // Exact variables and constants stay symbols, and the uncertainty of `nominal` is dropped
+    let program = "{k : real}\n{a : real}\nk := 3\na := (2 +/- 0.1) * k + (1 +/- nominal(0.4 +/- 0.5))\n";
+    let formula = formula(program, "a * pi");
+    assert_eq!(formula.name, "f");
+    assert_eq!(formula.expression.to_text(), "(q1 * k + 1 + q2) * pi");
+    let inputs: Vec<(&str, &str, f64, f64)> = formula.inputs.iter()
+        .map(|input| (input.name.as_str(), input.source.as_str(), input.value, input.error))
+        .collect();
+    assert_eq!(inputs, vec![("q1", "a: literal at 4:7", 2.0, 0.1), ("q2", "a: +/- at 4:27", 0.0, 0.4)]);
+    assert_eq!(formula.partials[0].to_text(), "k * pi");
+    assert_eq!(formula.partials[1].to_text(), "pi");
+
+    // A single input's uncertainty needs no square root
+    let formula = self::formula("{x : real}\nx := 1 +/- 0.1\n", "-3 * x + 2");
+    assert_eq!(formula.sigma().to_text(), "3 * σ_x");
+    assert_eq!(self::formula("", "3").sigma(), Symbolic::Number(0.0));
+}
+
+#[test]
+fn test_formula_function() {
+    let mut sig = Significance::new();
+    let (output, diagnostics) = sig.capture("main.sig", "{x : real}\nx := 12.3 +/- 0.5\nformula(x * 2)\n", None);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert_eq!(output, vec![
+        "Uncertainty formula for f",
+        "  f = 2 * x",
+        "  ∂f/∂x = 2",
+        "  σ_f = 2 * σ_x = 1",
+        "  evaluates to 24.6 +/- 1 (propagated operator by operator)",
+        "  inputs:",
+        "    x = 12.3 +/- 0.5",
+        "  LaTeX:",
+        "    f = 2 x",
+        "    \\frac{\\partial f}{\\partial x} = 2",
+        "    \\sigma_{f} = 2 \\sigma_{x}",
+        "24.6 +/- 1",
+    ]);
+}
+
+#[test]
+fn test_repeated_variable_shows_both_uncertainties() {
+    // σ_f treats x as one correlated input; the value returned is propagated operator by operator
+    let mut sig = Significance::new();
+    let (output, _) = sig.capture("main.sig", "{x : real}\nx := 1 +/- 0.1\nformula(x - x)\n", None);
+    assert_eq!(output[1..5], [
+        "  f = 0",
+        "  ∂f/∂x = 0",
+        "  σ_f = 0",
+        "  evaluates to 0 +/- 0.14142135623730953 (propagated operator by operator)",
+    ]);
+    assert_eq!(output.last().unwrap(), "0 +/- 0.14142135623730953");
+
+    let program = "{x : real}\n{y : real}\nx := 2 +/- 0.1\ny := 3 +/- 0.2\n";
+    let formula = formula(program, "x*y/sqrt(x) + x**2");
+    assert!((formula.error() - 0.5797).abs() < 1e-4, "{}", formula.error());
+    let (mut interpreter, expression) = analyze(program, "x*y/sqrt(x) + x**2");
+    let result = interpreter.evaluate(&expression);
+    assert!((result.error() - 0.5443).abs() < 1e-4, "{}", result.error());
+    assert!(formula.report(&result).contains(&format!("evaluates to {} (propagated", result)));
+}
+
+#[test]
+fn test_formula_does_not_depend_on_folding() {
+    let source = "{x : real}\n{z : real}\nx := 2 +/- 0.1\nz := 6 * (x * sin(3)) + 2 * 0.5\nformula(z)\nformula(z * (1 + 1))\n";
+    let run = |folding: bool| {
+        let mut sig = Significance::new();
+        sig.set_constant_folding(folding);
+        sig.capture("main.sig", source, None)
+    };
+    let (folded, unfolded) = (run(true), run(false));
+    assert!(folded.1.is_empty(), "{:?}", folded.1);
+    assert_eq!(folded.0, unfolded.0);
+    assert_eq!(folded.0[1], "  z = 6 * (x * sin(3)) + 1");
+    assert!(folded.0.contains(&"  f = 2 * (6 * (x * sin(3)) + 1)".to_string()), "{:?}", folded.0);
+}
+
+#[test]
+fn test_comparisons_have_no_uncertainty() {
+    let formula = formula("{x : real}\nx := 2 +/- 0.1\n", "x > 1 && !(x == 3)");
+    assert_eq!(formula.expression.to_text(), "(x > 1) && !(x == 3)");
+    assert_eq!(formula.expression.to_latex(), "\\left(x > 1\\right) \\land \\lnot \\left(x = 3\\right)");
+    assert_eq!(formula.partials[0], Symbolic::Number(0.0));
+    assert_eq!(formula.value(), 1.0);
+    assert_eq!(formula.error(), 0.0);
+    assert_eq!(Symbolic::compare(BinaryOp::Less, Symbolic::Number(1.0), Symbolic::Number(2.0)), Symbolic::Number(1.0));
+}
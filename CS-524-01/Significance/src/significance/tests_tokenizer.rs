@@ -5,7 +5,7 @@
 // Final Project
 //------------------
 
-use crate::significance::tokenizer::{Token, TokenWithPos, Tokenizer};
+use crate::significance::tokenizer::{Token, TokenWithPos, Tokenizer, RelativeUnit};
 
 const FLOAT_EPSILON: f64 = 1e-10;
 
@@ -127,3 +127,218 @@ w"#;
         Token::EOF,
     ]);
 }
+
+#[test]
+fn test_tokenize_relative_uncertainty() {
+    let mut tokenizer = Tokenizer::new("12.3 +/- 2%\n5.0 +/- 3ppm\n1.0 +/- 40ppb");
+    let tokens = tokenizer.tokenize().unwrap();
+
+    assert_tokens(&tokens, &[
+        Token::Number(12.3), Token::PlusMinus, Token::RelativeNumber(2.0, RelativeUnit::Percent),
+        Token::Newline,
+        Token::Number(5.0), Token::PlusMinus, Token::RelativeNumber(3.0, RelativeUnit::PartsPerMillion),
+        Token::Newline,
+        Token::Number(1.0), Token::PlusMinus, Token::RelativeNumber(40.0, RelativeUnit::PartsPerBillion),
+        Token::EOF,
+    ]);
+}
+
+#[test]
+fn test_tokenize_relative_uncertainty_after_space() {
+    let mut tokenizer = Tokenizer::new("12.3 +/- 2 %\n5.0 +/- 3 ppm\n7 % 3");
+    let tokens = tokenizer.tokenize().unwrap();
+
+    assert_tokens(&tokens, &[
+        Token::Number(12.3), Token::PlusMinus, Token::RelativeNumber(2.0, RelativeUnit::Percent),
+        Token::Newline,
+        Token::Number(5.0), Token::PlusMinus, Token::RelativeNumber(3.0, RelativeUnit::PartsPerMillion),
+        Token::Newline,
+        Token::Number(7.0), Token::Modulus, Token::Number(3.0),
+        Token::EOF,
+    ]);
+}
+
+#[test]
+fn test_tokenize_percent_is_modulus_outside_uncertainty() {
+    let mut tokenizer = Tokenizer::new("7%3");
+    let tokens = tokenizer.tokenize().unwrap();
+
+    assert_tokens(&tokens, &[
+        Token::Number(7.0), Token::Modulus, Token::Number(3.0),
+        Token::EOF,
+    ]);
+}
+
+#[test]
+fn test_tokenize_unicode_plus_minus() {
+    let mut tokenizer = Tokenizer::new("x := 12.3 ± 0.5 * y");
+    let tokens = tokenizer.tokenize().unwrap();
+
+    assert_tokens(&tokens, &[
+        Token::Identifier("x".to_string()),
+        Token::Assign,
+        Token::Number(12.3), Token::PlusMinus, Token::Number(0.5),
+        Token::Multiply,
+        Token::Identifier("y".to_string()),
+        Token::EOF,
+    ]);
+
+    // `±` is a single column even though it is two bytes in UTF-8
+    let columns: Vec<usize> = tokens.iter().map(|t| t.position.column).collect();
+    assert_eq!(columns, vec![1, 3, 6, 11, 13, 17, 19, 20]);
+}
+
+#[test]
+fn test_tokenize_unicode_relative_uncertainty() {
+    let mut tokenizer = Tokenizer::new("12.3±2%");
+    let tokens = tokenizer.tokenize().unwrap();
+
+    assert_tokens(&tokens, &[
+        Token::Number(12.3), Token::PlusMinus, Token::RelativeNumber(2.0, RelativeUnit::Percent),
+        Token::EOF,
+    ]);
+}
+
+#[test]
+fn test_tokenize_parenthetical_uncertainty() {
+    let mut tokenizer = Tokenizer::new("12.3(5)\n6.02214076(12)e23\n1234(56)\n1.5(3)E-2 * 2");
+    let tokens = tokenizer.tokenize().unwrap();
+
+    assert_tokens(&tokens, &[
+        Token::Number(12.3), Token::PlusMinus, Token::Number(0.5),
+        Token::Newline,
+        Token::Number(6.02214076e23), Token::PlusMinus, Token::Number(0.00000012e23),
+        Token::Newline,
+        Token::Number(1234.0), Token::PlusMinus, Token::Number(56.0),
+        Token::Newline,
+        Token::Number(1.5e-2), Token::PlusMinus, Token::Number(0.3e-2),
+        Token::Multiply, Token::Number(2.0),
+        Token::EOF,
+    ]);
+
+    // `+/-` sits at the opening parenthesis and the uncertainty at its first digit
+    let positions: Vec<(usize, usize)> = tokens[4..7].iter().map(|t| (t.position.line, t.position.column)).collect();
+    assert_eq!(positions, vec![(2, 1), (2, 11), (2, 12)]);
+}
+
+#[test]
+fn test_tokenize_number_before_parenthesized_expression() {
+    // Only digits directly inside the parentheses form an uncertainty
+    let mut tokenizer = Tokenizer::new("2(x)\n2 (3)");
+    let tokens = tokenizer.tokenize().unwrap();
+
+    assert_tokens(&tokens, &[
+        Token::Number(2.0), Token::LeftParen, Token::Identifier("x".to_string()), Token::RightParen,
+        Token::Newline,
+        Token::Number(2.0), Token::LeftParen, Token::Number(3.0), Token::RightParen,
+        Token::EOF,
+    ]);
+}
+
+#[test]
+fn test_tokenize_unexpected_character_position() {
+    let mut tokenizer = Tokenizer::new("±1 @");
+    let error = tokenizer.tokenize().unwrap_err();
+    assert_eq!(error.to_string(), "Error at 1:4: Unexpected character '@'");
+}
+
+#[test]
+fn test_tokenize_asymmetric_uncertainty() {
+    let mut tokenizer = Tokenizer::new("12.3(+0.4/-0.2)\n12.3(+1e-3/-2e-3)\n6.02e23(+1e21/-2e21)");
+    let tokens = tokenizer.tokenize().unwrap();
+
+    assert_tokens(&tokens, &[
+        Token::Number(12.3), Token::AsymmetricError(0.4, 0.2),
+        Token::Newline,
+        Token::Number(12.3), Token::AsymmetricError(1e-3, 2e-3),
+        Token::Newline,
+        Token::Number(6.02e23), Token::AsymmetricError(1e21, 2e21),
+        Token::EOF,
+    ]);
+}
+
+#[test]
+fn test_tokenize_asymmetric_lookalikes_stay_arithmetic() {
+    // Without the parentheses directly after a number, `+a/-b` keeps its arithmetic meaning
+    let mut tokenizer = Tokenizer::new("1+2/-3\n10 +4/-2\nx(+0.4/-0.2)");
+    let tokens = tokenizer.tokenize().unwrap();
+
+    assert_tokens(&tokens, &[
+        Token::Number(1.0), Token::Plus, Token::Number(2.0), Token::Divide, Token::Minus, Token::Number(3.0),
+        Token::Newline,
+        Token::Number(10.0), Token::Plus, Token::Number(4.0), Token::Divide, Token::Minus, Token::Number(2.0),
+        Token::Newline,
+        Token::Identifier("x".to_string()), Token::LeftParen, Token::Plus, Token::Number(0.4), Token::Divide,
+        Token::Minus, Token::Number(0.2), Token::RightParen,
+        Token::EOF,
+    ]);
+}
+
+#[test]
+fn test_tokenize_keeps_number_literal_text() {
+    let mut tokenizer = Tokenizer::new("6.02214076e23 + x * 0.10");
+    let tokens = tokenizer.tokenize().unwrap();
+
+    let literals: Vec<Option<&str>> = tokens.iter().map(|t| t.literal.as_deref()).collect();
+    assert_eq!(literals, vec![Some("6.02214076e23"), None, None, None, Some("0.10"), None]);
+}
+
+#[test]
+fn test_tokenize_import() {
+    let mut tokenizer = Tokenizer::new("import \"lib/calib.sig\" as cal\ncal.offset * 2.5");
+    let tokens = tokenizer.tokenize().unwrap();
+
+    assert_tokens(&tokens, &[
+        Token::Import, Token::StringLiteral("lib/calib.sig".to_string()), Token::As, Token::Identifier("cal".to_string()),
+        Token::Newline,
+        Token::Identifier("cal.offset".to_string()), Token::Multiply, Token::Number(2.5),
+        Token::EOF,
+    ]);
+}
+
+#[test]
+fn test_tokenize_unterminated_string() {
+    let mut tokenizer = Tokenizer::new("import \"calib.sig\nx");
+    let error = tokenizer.tokenize().unwrap_err();
+    assert_eq!(error.to_string(), "Error at 1:8: Unterminated string");
+}
+
+#[test]
+fn test_tokenize_recovers_at_next_line() {
+    let mut tokenizer = Tokenizer::new("x := @ 1\ny := 2a\nz := \"open\nw := 3");
+    let (tokens, errors) = tokenizer.tokenize_recovering();
+
+    let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    assert_eq!(messages, vec![
+        "Error at 1:6: Unexpected character '@'",
+        "Error at 2:6: Unexpected character 'a' immediately after number",
+        "Error at 3:6: Unterminated string",
+    ]);
+    // The lines with errors are left empty, so the parser sees only the last statement
+    assert_tokens(&tokens, &[
+        Token::Newline, Token::Newline, Token::Newline,
+        Token::Identifier("w".to_string()), Token::Assign, Token::Number(3.0),
+        Token::EOF,
+    ]);
+}
+
+#[test]
+fn test_tokenize_comparison_and_logical_operators() {
+    let mut tokenizer = Tokenizer::new("a<b <= c>d>=e == f != g && !h || i");
+    let tokens = tokenizer.tokenize().unwrap();
+    let id = |name: &str| Token::Identifier(name.to_string());
+    assert_tokens(&tokens, &[
+        id("a"), Token::Less, id("b"), Token::LessEqual, id("c"), Token::Greater, id("d"),
+        Token::GreaterEqual, id("e"), Token::Equal, id("f"), Token::NotEqual, id("g"),
+        Token::And, Token::Not, id("h"), Token::Or, id("i"),
+        Token::EOF,
+    ]);
+
+    let (_, errors) = Tokenizer::new("x = 1\ny := a & b\nz := a | b").tokenize_recovering();
+    let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    assert_eq!(messages, vec![
+        "Error at 1:3: Unexpected character '='; use ':=' to assign or '==' to compare",
+        "Error at 2:8: Unexpected character '&'; use '&&' for logical and",
+        "Error at 3:8: Unexpected character '|'; use '||' for logical or",
+    ]);
+}
@@ -0,0 +1,96 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+use crate::significance::*;
+
+/// The executor's test cases below run against the VM under the executor's name.
+type Executor<N = Real> = Vm<N>;
+
+#[allow(clippy::duplicate_mod, clippy::approx_constant, clippy::needless_borrow)]
+#[path = "tests_executor.rs"]
+mod tests_executor;
+
+use crate::significance::executor::Executor as TreeExecutor;
+
+/// Tokenizes and parses a source string
+fn parse(source: &str) -> Program {
+    let tokens = Tokenizer::new(source).tokenize().unwrap();
+    AstParser::new().parse_program(tokens).unwrap()
+}
+
+#[test]
+fn test_variables_are_resolved_to_slots() {
+    let mut vm = Vm::new();
+    let chunk: Chunk<Real> = vm.compile(&parse("{x : real}\nx := 2\nx * x\n"));
+    let slot = match chunk.code[0] {
+        Instruction::Declare(slot) => slot,
+        ref other => panic!("Expected a declaration, got {:?}", other),
+    };
+    let loads: Vec<usize> = chunk.code.iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::Load(slot, _) => Some(*slot),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(loads, vec![slot, slot]);
+    assert_eq!(chunk.constants.len(), 1);
+    assert_eq!(chunk.code.last(), Some(&Instruction::Print));
+}
+
+#[test]
+fn test_chunk_runs_again_after_reset() {
+    let mut vm = Vm::new();
+    vm.set_quiet(true);
+    let chunk = vm.compile(&parse("{x : real}\nx := 3 +/- 0.1\n"));
+    for _ in 0..3 {
+        vm.reset();
+        assert!(vm.get_var("x").is_none());
+        vm.run(&chunk);
+        assert_real(vm.get_var("x").unwrap().get_value(), Real::with_error(3.0, 0.1));
+    }
+    assert!(vm.get_var("pi").is_some());
+    assert!(vm.get_errors().is_empty());
+}
+
+#[test]
+fn test_vm_matches_executor() {
+    let programs = [
+        "{x : real}\n{y : real}\nx := 12.3 +/- 0.5\ny := 2.6 +/- 0.2\nx + y\nx*x + y**2\nx % y\n",
+        "sin(2.1 +/- 0.1)\n-(nominal(pi) / 4) ** 2\nhypot(1 +/- 0.5, 2 +/- 0.1)\n",
+        "{x : real}\nx := 1 / 0\n1 / x\nassert(x)\nbudget(x + 1 +/- 0.1)\n",
+        "{x : real}\nx := 2 +/- 0.1\nx > 1 && !(x == 3)\n0 && 1 / 0\n1 || 1 / 0\nx <= 1 || x != 2\n",
+    ];
+    for order in [PropagationOrder::First, PropagationOrder::Second] {
+        for source in programs {
+            let program = parse(source);
+            let mut executor = TreeExecutor::new();
+            let mut vm = Vm::new();
+            executor.set_propagation_order(order);
+            vm.set_propagation_order(order);
+            executor.set_capture(true);
+            vm.set_capture(true);
+            executor.execute_program(&program);
+            vm.execute_program(&program);
+            assert_eq!(vm.take_output(), executor.take_output(), "{}", source);
+            assert_eq!(format!("{:?}", vm.get_errors()), format!("{:?}", executor.get_errors()), "{}", source);
+        }
+    }
+}
+
+#[test]
+fn test_logical_operators_jump_past_their_right_operand() {
+    let mut vm = Vm::new();
+    let chunk: Chunk<Real> = vm.compile(&parse("0 && 1 / 0\n"));
+    let (index, target) = chunk.code.iter().enumerate()
+        .find_map(|(index, instruction)| match instruction {
+            Instruction::ShortCircuit { op: BinaryOp::And, target } => Some((index, *target)),
+            _ => None,
+        })
+        .expect("Expected a short circuit");
+    assert!(matches!(chunk.code[target - 1], Instruction::Binary(BinaryOp::And, _)));
+    assert!(chunk.code[index..target].iter().any(|instruction| matches!(instruction, Instruction::Binary(BinaryOp::Div, _))));
+}
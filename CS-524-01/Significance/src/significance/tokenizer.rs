@@ -11,6 +11,9 @@
 //! The tokenizer handles numeric literals (including scientific notation and uncertainty syntax),
 //! identifiers, operators, keywords, comments, and position tracking for error reporting.
 
+use std::collections::VecDeque;
+use crate::significance::source_map::{FileId, Span};
+use crate::significance::diagnostic::{Diagnostic, Stage};
 use std::fmt;
 use serde::{Serialize, Deserialize};
 
@@ -24,7 +27,7 @@ use serde::{Serialize, Deserialize};
 ///
 /// - **Literals**: Numbers and identifiers
 /// - **Keywords**: Language keywords like `real`
-/// - **Operators**: Arithmetic and special operators
+/// - **Operators**: Arithmetic, comparison, logical and special operators
 /// - **Delimiters**: Parentheses, braces, commas
 /// - **Special**: Comments, newlines, EOF
 #[derive(Debug, Clone, PartialEq)]
@@ -34,18 +37,48 @@ pub enum Token {
     ///
     /// Examples: `42`, `3.14`, `1.5e-3`, `6.022e23`
     Number(f64),
+
+    /// Relative uncertainty literal: a number immediately followed by `%`, `ppm` or `ppb`
+    ///
+    /// The `%` form is only recognized for the operand of `+/-`, where it cannot mean modulus.
+    /// Examples: `2%`, `3ppm`, `0.5ppb` (as in `12.3 +/- 2%`)
+    RelativeNumber(f64, RelativeUnit),
+
+    /// Asymmetric uncertainty: `(+upper/-lower)`, written directly after a number
+    ///
+    /// A parenthesis cannot otherwise follow a numeric literal, so this form never
+    /// collides with arithmetic such as `1+2/-3`.
+    /// Example: `(+0.4/-0.2)` (as in `12.3(+0.4/-0.2)`)
+    AsymmetricError(f64, f64),
     
     /// Identifier: variable or function name
     ///
     /// Must start with letter or underscore, can contain letters, digits, underscores.
-    /// Examples: `x`, `my_var`, `temp2`, `_internal`
+    /// A name imported under a namespace is qualified with a dot.
+    /// Examples: `x`, `my_var`, `temp2`, `_internal`, `calib.offset`
     Identifier(String),
+
+    /// String literal: `"text"`, without the quotes
+    ///
+    /// Only used for the path of an `import`.
+    /// Example: `"calibration.sig"`
+    StringLiteral(String),
     
     // Keywords/Types
     /// The `real` keyword used in type declarations
     ///
     /// Example: `{x : real}`
     Real,
+
+    /// The `import` keyword
+    ///
+    /// Example: `import "calibration.sig"`
+    Import,
+
+    /// The `as` keyword, naming the namespace of an import
+    ///
+    /// Example: `import "calibration.sig" as cal`
+    As,
     
     // Operators
     /// Addition operator: `+`
@@ -78,6 +111,39 @@ pub enum Token {
     /// Used to specify measurement uncertainty.
     /// Example: `12.3 +/- 0.5`
     PlusMinus,
+
+    /// Less-than operator: `<`
+    Less,
+
+    /// Less-than-or-equal operator: `<=`
+    LessEqual,
+
+    /// Greater-than operator: `>`
+    Greater,
+
+    /// Greater-than-or-equal operator: `>=`
+    GreaterEqual,
+
+    /// Equality operator: `==`
+    ///
+    /// Example: `n == 3`
+    Equal,
+
+    /// Inequality operator: `!=`
+    NotEqual,
+
+    /// Logical and operator: `&&`
+    ///
+    /// Example: `x > 0 && x < 1`
+    And,
+
+    /// Logical or operator: `||`
+    Or,
+
+    /// Logical not operator: `!`
+    ///
+    /// Example: `!(x > 0)`
+    Not,
     
     // Assignment and Declaration
     /// Assignment operator: `:=`
@@ -131,8 +197,13 @@ impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Token::Number(n) => write!(f, "NUMBER({})", n),
+            Token::RelativeNumber(n, unit) => write!(f, "RELATIVE({}{})", n, unit),
+            Token::AsymmetricError(upper, lower) => write!(f, "ASYMMETRIC(+{}/-{})", upper, lower),
             Token::Identifier(s) => write!(f, "ID({})", s),
+            Token::StringLiteral(s) => write!(f, "STRING({})", s),
             Token::Real => write!(f, "REAL"),
+            Token::Import => write!(f, "IMPORT"),
+            Token::As => write!(f, "AS"),
             Token::Plus => write!(f, "+"),
             Token::Minus => write!(f, "-"),
             Token::Multiply => write!(f, "*"),
@@ -141,6 +212,15 @@ impl fmt::Display for Token {
             Token::Power => write!(f, "**"),
             Token::Root => write!(f, "//"),
             Token::PlusMinus => write!(f, "+/-"),
+            Token::Less => write!(f, "<"),
+            Token::LessEqual => write!(f, "<="),
+            Token::Greater => write!(f, ">"),
+            Token::GreaterEqual => write!(f, ">="),
+            Token::Equal => write!(f, "=="),
+            Token::NotEqual => write!(f, "!="),
+            Token::And => write!(f, "&&"),
+            Token::Or => write!(f, "||"),
+            Token::Not => write!(f, "!"),
             Token::Assign => write!(f, ":="),
             Token::Colon => write!(f, ":"),
             Token::LeftParen => write!(f, "("),
@@ -155,6 +235,48 @@ impl fmt::Display for Token {
     }
 }
 
+/// Unit of a relative uncertainty literal.
+///
+/// Relative uncertainties are written as a number with a unit suffix and are
+/// converted to an absolute error by multiplying with the magnitude of the value.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RelativeUnit {
+    /// Percent: `%` (1e-2)
+    Percent,
+
+    /// Parts per million: `ppm` (1e-6)
+    PartsPerMillion,
+
+    /// Parts per billion: `ppb` (1e-9)
+    PartsPerBillion,
+}
+
+impl RelativeUnit {
+    /// Returns the multiplier that converts an amount in this unit to a fraction.
+    pub fn factor(&self) -> f64 {
+        match self {
+            RelativeUnit::Percent => 1e-2,
+            RelativeUnit::PartsPerMillion => 1e-6,
+            RelativeUnit::PartsPerBillion => 1e-9,
+        }
+    }
+
+    /// Returns the source suffix for this unit.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            RelativeUnit::Percent => "%",
+            RelativeUnit::PartsPerMillion => "ppm",
+            RelativeUnit::PartsPerBillion => "ppb",
+        }
+    }
+}
+
+impl fmt::Display for RelativeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
 /// Source code position for error reporting and debugging.
 ///
 /// Tracks the line and column number of tokens in the source text.
@@ -188,6 +310,20 @@ pub struct TokenWithPos {
     
     /// Where this token appears in the source
     pub position: Position,
+
+    /// Exact source text of a number's value (e.g. `6.02214076e23`), kept so that
+    /// arbitrary-precision backends are not limited to the `f64` in the token
+    pub literal: Option<String>,
+
+    /// Source text the token was scanned from, including its file
+    pub span: Span,
+}
+
+impl TokenWithPos {
+    /// Creates a token with no literal text and an empty span at `position`.
+    pub fn new(token: Token, position: Position) -> Self {
+        Self { token, position, literal: None, span: Span::new(FileId::default(), position, position) }
+    }
 }
 
 /// Lexical analyzer (tokenizer) for the Significance language.
@@ -195,7 +331,9 @@ pub struct TokenWithPos {
 /// The tokenizer converts raw source text into a stream of tokens that can be
 /// consumed by the parser. It handles:
 /// - Numeric literals with scientific notation
-/// - Multi-character operators (`**`, `//`, `+/-`, `:=`)
+/// - Multi-character operators (`**`, `//`, `+/-`, `:=`, `<=`, `>=`, `==`, `!=`, `&&`, `||`) and `±`
+/// - Parenthetical uncertainty (`12.3(5)`), lexed as `12.3 +/- 0.5`
+/// - Asymmetric uncertainty (`12.3(+0.4/-0.2)`)
 /// - Identifiers and keywords
 /// - Comments (ignored by parser but preserved in token stream)
 /// - Position tracking for error reporting
@@ -206,6 +344,7 @@ pub struct TokenWithPos {
 /// single-character lookahead for disambiguation (e.g., distinguishing `*` from `**`).
 /// Whitespace (spaces, tabs, carriage returns) is skipped automatically, while
 /// newlines are preserved as tokens since they're significant for statement separation.
+/// Columns count characters rather than bytes, so `±` occupies a single column.
 ///
 /// # Example
 ///
@@ -227,6 +366,21 @@ pub struct Tokenizer {
     
     /// Current column number (1-indexed)
     column: usize,
+
+    /// Whether the most recently produced token was `+/-`.
+    ///
+    /// A `%` directly after the following number then marks a relative uncertainty
+    /// rather than the modulus operator.
+    after_plus_minus: bool,
+
+    /// Tokens already scanned but not yet returned.
+    ///
+    /// Parenthetical uncertainty (`12.3(5)`) is scanned as one literal but produces
+    /// three tokens; the `+/-` and the uncertainty wait here.
+    pending: VecDeque<TokenWithPos>,
+
+    /// Source text being tokenized, recorded in every token's span
+    file: FileId,
 }
 
 impl Tokenizer {
@@ -245,11 +399,26 @@ impl Tokenizer {
     /// let tokenizer = Tokenizer::new("x := 5");
     /// ```
     pub fn new(input: &str) -> Self {
+        Self::with_file(input, FileId::default())
+    }
+
+    /// Creates a tokenizer for a source text registered in a `SourceMap`.
+    ///
+    /// Every token's span, and every error, refers to `file`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Source code to tokenize
+    /// * `file` - Id of the source text in the interpreter's `SourceMap`
+    pub fn with_file(input: &str, file: FileId) -> Self {
         Self {
             input: input.chars().collect(),
             current: 0,
             line: 1,
             column: 1,
+            after_plus_minus: false,
+            pending: VecDeque::new(),
+            file,
         }
     }
     
@@ -265,78 +434,137 @@ impl Tokenizer {
     ///
     /// # Returns
     ///
-    /// * `Ok(TokenWithPos)` - Successfully recognized token with position
-    /// * `Err(String)` - Error message for unexpected or invalid characters
+    /// * `Ok(TokenWithPos)` - Successfully recognized token with position and span
+    /// * `Err(Diagnostic)` - Unexpected or invalid characters, located in the source
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - An unexpected character is encountered
     /// - A number cannot be parsed as `f64`
-    pub fn next_token(&mut self) -> Result<TokenWithPos, String> {
+    pub fn next_token(&mut self) -> Result<TokenWithPos, Diagnostic> {
+        let mut token = self.scan_token()?;
+        token.span.file = self.file;
+        self.after_plus_minus = token.token == Token::PlusMinus;
+        Ok(token)
+    }
+
+    /// Scans the next token; see `next_token`.
+    ///
+    /// The token's span runs from its first character to the current position.
+    fn scan_token(&mut self) -> Result<TokenWithPos, Diagnostic> {
+        if let Some(token) = self.pending.pop_front() {
+            return Ok(token);
+        }
+
         self.skip_whitespace();
         
         if self.is_at_end() {
-            return Ok(TokenWithPos {
-                token: Token::EOF,
-                position: self.current_position(),
-            });
+            return Ok(TokenWithPos::new(Token::EOF, self.current_position()));
         }
         
         let start_pos = self.current_position();
         let ch = self.advance();
         
-        match ch {
+        let token = match ch {
             // Single character tokens with potential multi-character lookahead
             '+' => {
                 if self.match_char('/') && self.match_char('-') {
-                    Ok(TokenWithPos { token: Token::PlusMinus, position: start_pos })
+                    Ok(TokenWithPos::new(Token::PlusMinus, start_pos))
                 } else {
-                    Ok(TokenWithPos { token: Token::Plus, position: start_pos })
+                    Ok(TokenWithPos::new(Token::Plus, start_pos))
                 }
             },
-            '-' => Ok(TokenWithPos { token: Token::Minus, position: start_pos }),
+            '±' => Ok(TokenWithPos::new(Token::PlusMinus, start_pos)),
+            '-' => Ok(TokenWithPos::new(Token::Minus, start_pos)),
             '*' => {
                 if self.match_char('*') {
-                    Ok(TokenWithPos { token: Token::Power, position: start_pos })
+                    Ok(TokenWithPos::new(Token::Power, start_pos))
                 } else {
-                    Ok(TokenWithPos { token: Token::Multiply, position: start_pos })
+                    Ok(TokenWithPos::new(Token::Multiply, start_pos))
                 }
             },
             '/' => {
                 if self.match_char('/') {
-                    Ok(TokenWithPos { token: Token::Root, position: start_pos })
+                    Ok(TokenWithPos::new(Token::Root, start_pos))
+                } else {
+                    Ok(TokenWithPos::new(Token::Divide, start_pos))
+                }
+            },
+            '%' => Ok(TokenWithPos::new(Token::Modulus, start_pos)),
+            '<' => {
+                if self.match_char('=') {
+                    Ok(TokenWithPos::new(Token::LessEqual, start_pos))
+                } else {
+                    Ok(TokenWithPos::new(Token::Less, start_pos))
+                }
+            },
+            '>' => {
+                if self.match_char('=') {
+                    Ok(TokenWithPos::new(Token::GreaterEqual, start_pos))
+                } else {
+                    Ok(TokenWithPos::new(Token::Greater, start_pos))
+                }
+            },
+            '!' => {
+                if self.match_char('=') {
+                    Ok(TokenWithPos::new(Token::NotEqual, start_pos))
+                } else {
+                    Ok(TokenWithPos::new(Token::Not, start_pos))
+                }
+            },
+            '=' => {
+                if self.match_char('=') {
+                    Ok(TokenWithPos::new(Token::Equal, start_pos))
+                } else {
+                    Err("Unexpected character '='; use ':=' to assign or '==' to compare".to_string())
+                }
+            },
+            '&' => {
+                if self.match_char('&') {
+                    Ok(TokenWithPos::new(Token::And, start_pos))
                 } else {
-                    Ok(TokenWithPos { token: Token::Divide, position: start_pos })
+                    Err("Unexpected character '&'; use '&&' for logical and".to_string())
                 }
             },
-            '%' => Ok(TokenWithPos { token: Token::Modulus, position: start_pos }),
-            '(' => Ok(TokenWithPos { token: Token::LeftParen, position: start_pos }),
-            ')' => Ok(TokenWithPos { token: Token::RightParen, position: start_pos }),
-            '{' => Ok(TokenWithPos { token: Token::LeftBrace, position: start_pos }),
-            '}' => Ok(TokenWithPos { token: Token::RightBrace, position: start_pos }),
-            ',' => Ok(TokenWithPos { token: Token::Comma, position: start_pos }),
+            '|' => {
+                if self.match_char('|') {
+                    Ok(TokenWithPos::new(Token::Or, start_pos))
+                } else {
+                    Err("Unexpected character '|'; use '||' for logical or".to_string())
+                }
+            },
+            '(' => Ok(TokenWithPos::new(Token::LeftParen, start_pos)),
+            ')' => Ok(TokenWithPos::new(Token::RightParen, start_pos)),
+            '{' => Ok(TokenWithPos::new(Token::LeftBrace, start_pos)),
+            '}' => Ok(TokenWithPos::new(Token::RightBrace, start_pos)),
+            ',' => Ok(TokenWithPos::new(Token::Comma, start_pos)),
             ':' => {
                 if self.match_char('=') {
-                    Ok(TokenWithPos { token: Token::Assign, position: start_pos })
+                    Ok(TokenWithPos::new(Token::Assign, start_pos))
                 } else {
-                    Ok(TokenWithPos { token: Token::Colon, position: start_pos })
+                    Ok(TokenWithPos::new(Token::Colon, start_pos))
                 }
             },
             '#' => {
                 let comment_text = self.read_comment();
-                Ok(TokenWithPos { token: Token::Comment(comment_text), position: start_pos })
+                Ok(TokenWithPos::new(Token::Comment(comment_text), start_pos))
             },
+            '"' => self.read_string()
+                .map(|text| TokenWithPos::new(Token::StringLiteral(text), start_pos)),
             '\n' => {
                 self.line += 1;
                 self.column = 1;
-                Ok(TokenWithPos { token: Token::Newline, position: start_pos })
+                Ok(TokenWithPos::new(Token::Newline, start_pos))
             },
             
             // Numbers (including scientific notation)
-            '0'..='9' => {
-                let number = self.read_number(ch)?;
-                Ok(TokenWithPos { token: Token::Number(number), position: start_pos })
+            '0'..='9' => match self.read_number(ch) {
+                Ok((number, literal)) => match self.read_relative_unit() {
+                    Some(unit) => Ok(TokenWithPos::new(Token::RelativeNumber(number, unit), start_pos)),
+                    None => Ok(TokenWithPos { literal: Some(literal), ..TokenWithPos::new(Token::Number(number), start_pos) }),
+                },
+                Err(message) => Err(message),
             },
             
             // Identifiers and keywords
@@ -344,12 +572,20 @@ impl Tokenizer {
                 let identifier = self.read_identifier(ch);
                 let token = match identifier.as_str() {
                     "real" => Token::Real,
+                    "import" => Token::Import,
+                    "as" => Token::As,
                     _ => Token::Identifier(identifier),
                 };
-                Ok(TokenWithPos { token, position: start_pos })
+                Ok(TokenWithPos::new(token, start_pos))
             },
             
-            _ => Err(format!("Unexpected character '{}' at {}:{}", ch, self.line, self.column)),
+            _ => Err(format!("Unexpected character '{}'", ch)),
+        };
+
+        let span = Span::new(self.file, start_pos, self.current_position());
+        match token {
+            Ok(token) => Ok(TokenWithPos { span, ..token }),
+            Err(message) => Err(Diagnostic::new(Stage::Tokenizer, message, span)),
         }
     }
     
@@ -362,7 +598,7 @@ impl Tokenizer {
     /// # Returns
     ///
     /// * `Ok(Vec<TokenWithPos>)` - Complete token stream including EOF
-    /// * `Err(String)` - Error message if tokenization fails
+    /// * `Err(Diagnostic)` - The first error, if tokenization fails
     ///
     /// # Errors
     ///
@@ -375,19 +611,62 @@ impl Tokenizer {
     /// let tokens = tokenizer.tokenize()?;
     /// assert!(matches!(tokens.last().unwrap().token, Token::EOF));
     /// ```
-    pub fn tokenize(&mut self) -> Result<Vec<TokenWithPos>, String> {
+    pub fn tokenize(&mut self) -> Result<Vec<TokenWithPos>, Diagnostic> {
+        let (tokens, errors) = self.tokenize_recovering();
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(tokens),
+        }
+    }
+
+    /// Tokenizes the entire input, recovering from lexical errors.
+    ///
+    /// When a token cannot be read, the error is recorded, the tokens already read
+    /// on that line are dropped and the rest of the line is skipped. The newline
+    /// itself is kept, so the parser sees an empty line and its own recovery carries
+    /// on with the next statement. Every lexical error in the input is reported in
+    /// one pass.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<TokenWithPos>` - The tokens of every line without errors, including EOF
+    /// * `Vec<Diagnostic>` - Every lexical error, in source order
+    pub fn tokenize_recovering(&mut self) -> (Vec<TokenWithPos>, Vec<Diagnostic>) {
         let mut tokens = Vec::new();
+        let mut errors = Vec::new();
+        let mut line_start = 0;
         
         loop {
-            let token = self.next_token()?;
-            let is_eof = matches!(token.token, Token::EOF);
-            tokens.push(token);
-            if is_eof {
-                break;
+            match self.next_token() {
+                Ok(token) => {
+                    let is_eof = matches!(token.token, Token::EOF);
+                    let is_newline = matches!(token.token, Token::Newline);
+                    tokens.push(token);
+                    if is_eof {
+                        break;
+                    }
+                    if is_newline {
+                        line_start = tokens.len();
+                    }
+                },
+                Err(error) => {
+                    errors.push(error);
+                    tokens.truncate(line_start);
+                    self.skip_line();
+                },
             }
         }
         
-        Ok(tokens)
+        (tokens, errors)
+    }
+
+    /// Skips the rest of the current line after a lexical error, leaving the newline.
+    fn skip_line(&mut self) {
+        self.pending.clear();
+        self.after_plus_minus = false;
+        while !self.is_at_end() && self.peek() != '\n' {
+            self.advance();
+        }
     }
     
     /// Skips whitespace characters (space, tab, carriage return).
@@ -500,15 +779,46 @@ impl Tokenizer {
 
     }
 
+    /// Reads a string literal up to the closing `"`.
+    ///
+    /// Assumes the opening `"` has already been consumed. Strings may not span lines
+    /// and have no escape sequences.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The text between the quotes
+    /// * `Err(String)` - The line or input ended before the closing quote
+    fn read_string(&mut self) -> Result<String, String> {
+
+        let mut text = String::new();
+        while !self.is_at_end() && self.peek() != '"' && self.peek() != '\n' {
+            text.push(self.advance());
+        }
+
+        if !self.match_char('"') {
+            return Err("Unterminated string".to_string());
+        }
+        Ok(text)
+    }
+
     /// Reads a complete numeric literal.
     ///
     /// Handles integers, floating-point numbers, and scientific notation.
     /// Supports formats like: `42`, `3.14`, `1.5e-3`, `6.022e+23`
     ///
+    /// A relative unit suffix (`%`, `ppm`, `ppb`) is left unconsumed for the caller.
+    ///
+    /// Parenthetical uncertainty (`12.3(5)`, `6.02214076(12)e23`) is also read here: the
+    /// digits in parentheses give the uncertainty in the last digits of the mantissa.
+    /// The returned value is the number itself, and the `+/-` and uncertainty tokens
+    /// are queued in `pending`. An asymmetric uncertainty after the number
+    /// (`12.3(+0.4/-0.2)`) is queued in the same way.
+    ///
     /// # Grammar
     ///
     /// ```text
-    /// number := integer_part ('.' integer_part)? (('e'|'E') ('+'|'-')? integer_part)?
+    /// number := integer_part ('.' integer_part)? ('(' integer_part ')')? (('e'|'E') ('+'|'-')? integer_part)?
+    ///           ('(+' unsigned_number '/-' unsigned_number ')')?
     /// ```
     ///
     /// # Arguments
@@ -517,36 +827,177 @@ impl Tokenizer {
     ///
     /// # Returns
     ///
-    /// * `Ok(f64)` - Successfully parsed number
+    /// * `Ok((f64, String))` - Successfully parsed number, and its exact text (without
+    ///   any parenthetical uncertainty)
     /// * `Err(String)` - Invalid number format
     ///
     /// # Errors
     ///
     /// Returns an error if the collected string cannot be parsed as `f64`.
-    fn read_number(&mut self, first_char: char) -> Result<f64, String> { 
+    fn read_number(&mut self, first_char: char) -> Result<(f64, String), String> { 
 
         let mut number_text = String::new();
         number_text.push(first_char);
             
         self.read_integer_part(&mut number_text);
         self.read_decimal_part(&mut number_text);
+        let parenthetical = self.read_parenthetical_uncertainty();
+        let mantissa_len = number_text.len();
         self.read_exponent_part(&mut number_text);
 
+        if let Some((open_pos, digits_pos, digits)) = parenthetical {
+            // Scale the uncertainty digits to the last place of the mantissa (and the exponent)
+            let decimals = number_text[..mantissa_len].split('.').nth(1).map_or(0, |d| d.len()) as i32;
+            let exponent = match &number_text[mantissa_len..] {
+                "" => 0,
+                text => text[1..].parse::<i32>()
+                    .map_err(|_| format!("Could not parse '{}' as floating point number", number_text))?,
+            };
+            let error_text = format!("{}e{}", digits, exponent - decimals);
+            let error = error_text.parse::<f64>()
+                .map_err(|_| format!("Could not parse '{}' as floating point number", error_text))?;
+            self.pending.push_back(TokenWithPos::new(Token::PlusMinus, open_pos));
+            self.pending.push_back(TokenWithPos::new(Token::Number(error), digits_pos));
+        } else if let Some((open_pos, upper, lower)) = self.read_asymmetric_error() {
+            self.pending.push_back(TokenWithPos::new(Token::AsymmetricError(upper, lower), open_pos));
+        }
+
         let c = self.peek();
 
+        if self.peek_relative_unit().is_some() {
+            return Self::parse_number_text(number_text);
+        }
+
         match c {
         'a'..='z' | 'A'..='Z' | '_' => {
-            return Err(format!(
-                "Unexpected character '{}' immediately after number at {}:{}", 
-                c, self.line, self.column
-            ));
+            return Err(format!("Unexpected character '{}' immediately after number", c));
         },
         _ => {} // All other characters are valid terminators, this prevents identifiers from appearing after numbers without a space
 }
 
-        number_text.parse::<f64>()
-            .map_err(|_| format!("Could not parse '{}' as floating point number", number_text))
+        Self::parse_number_text(number_text)
+
+    }
+
+    /// Parses the text of a number, returning the value along with the text.
+    fn parse_number_text(number_text: String) -> Result<(f64, String), String> {
+        match number_text.parse::<f64>() {
+            Ok(number) => Ok((number, number_text)),
+            Err(_) => Err(format!("Could not parse '{}' as floating point number", number_text)),
+        }
+    }
+
+    /// Checks whether a relative unit suffix starts at the current position.
+    ///
+    /// `ppm` and `ppb` are recognized anywhere directly after a number (a letter there
+    /// is otherwise an error). `%` is only recognized after the operand of `+/-`, so
+    /// that `7%3` keeps meaning modulus. After the operand of `+/-` the suffix may also
+    /// be separated from the number by spaces or tabs (`12.3 +/- 2 %`). A suffix must
+    /// not be followed by further identifier characters.
+    ///
+    /// # Returns
+    ///
+    /// The unit and the length of its suffix (including any skipped whitespace), or `None`
+    fn peek_relative_unit(&self) -> Option<(RelativeUnit, usize)> {
+        let mut rest = &self.input[self.current..];
+        let mut skipped = 0;
+        if self.after_plus_minus {
+            while let [' ' | '\t', tail @ ..] = rest {
+                rest = tail;
+                skipped += 1;
+            }
+        }
+        let (unit, len) = match rest {
+            ['%', ..] if self.after_plus_minus => (RelativeUnit::Percent, 1),
+            ['p', 'p', 'm', ..] => (RelativeUnit::PartsPerMillion, 3),
+            ['p', 'p', 'b', ..] => (RelativeUnit::PartsPerBillion, 3),
+            _ => return None,
+        };
+        match rest.get(len) {
+            Some(c) if c.is_ascii_alphanumeric() || *c == '_' => None,
+            _ => Some((unit, skipped + len)),
+        }
+    }
+
+    /// Consumes a relative unit suffix if one starts at the current position.
+    ///
+    /// # Returns
+    ///
+    /// The consumed unit, or `None` if the number has no suffix
+    fn read_relative_unit(&mut self) -> Option<RelativeUnit> {
+        let (unit, len) = self.peek_relative_unit()?;
+        for _ in 0..len {
+            self.advance();
+        }
+        Some(unit)
+    }
+
+    /// Reads an asymmetric uncertainty such as the `(+0.4/-0.2)` in `12.3(+0.4/-0.2)` (if present).
+    ///
+    /// Only a parenthesis directly after the number, containing exactly `+upper/-lower`
+    /// without spaces, is consumed; anything else is left for the caller.
+    ///
+    /// # Returns
+    ///
+    /// The position of the `(` and the upper and lower errors, or `None`
+    fn read_asymmetric_error(&mut self) -> Option<(Position, f64, f64)> {
+        if !matches!(self.input[self.current..], ['(', '+', c, ..] if c.is_ascii_digit()) {
+            return None;
+        }
+
+        let (saved_current, saved_column) = (self.current, self.column);
+        let open_pos = self.current_position();
+        self.advance();
+        self.advance();
+        let upper = self.read_unsigned_number();
+        let lower = if self.match_char('/') && self.match_char('-') && self.peek().is_ascii_digit() {
+            self.read_unsigned_number()
+        } else {
+            None
+        };
 
+        match (upper, lower) {
+            (Some(upper), Some(lower)) if self.match_char(')') => Some((open_pos, upper, lower)),
+            _ => {
+                self.current = saved_current;
+                self.column = saved_column;
+                None
+            }
+        }
+    }
+
+    /// Reads a plain number (digits, decimal part and exponent) starting at the current position.
+    fn read_unsigned_number(&mut self) -> Option<f64> {
+        let mut number_text = String::new();
+        self.read_integer_part(&mut number_text);
+        self.read_decimal_part(&mut number_text);
+        self.read_exponent_part(&mut number_text);
+        number_text.parse::<f64>().ok()
+    }
+
+    /// Reads parenthetical uncertainty digits such as the `(5)` in `12.3(5)` (if present).
+    ///
+    /// Only a parenthesis directly after the digits, containing nothing but digits, is
+    /// consumed; anything else is left for the caller.
+    ///
+    /// # Returns
+    ///
+    /// The positions of the `(` and of the first digit, and the digits themselves
+    fn read_parenthetical_uncertainty(&mut self) -> Option<(Position, Position, String)> {
+        let rest = &self.input[self.current..];
+        let digit_count = rest.iter().skip(1).take_while(|c| c.is_ascii_digit()).count();
+        if rest.first() != Some(&'(') || digit_count == 0 || rest.get(digit_count + 1) != Some(&')') {
+            return None;
+        }
+
+        let open_pos = self.current_position();
+        self.advance();
+        let digits_pos = self.current_position();
+        let mut digits = String::new();
+        self.read_integer_part(&mut digits);
+        self.advance();
+
+        Some((open_pos, digits_pos, digits))
     }
 
     /// Reads the decimal portion of a number (if present).
@@ -617,7 +1068,8 @@ impl Tokenizer {
     /// # Grammar
     ///
     /// ```text
-    /// identifier := (letter | '_') (letter | digit | '_')*
+    /// identifier := name ('.' name)*
+    /// name       := (letter | '_') (letter | digit | '_')*
     /// ```
     ///
     /// # Arguments
@@ -638,6 +1090,11 @@ impl Tokenizer {
                     let c = self.advance();
                     identifier_text.push(c);
                 },
+                // Qualified name of an imported variable: `namespace.name`
+                '.' if self.input.get(self.current + 1).is_some_and(|c| c.is_ascii_alphabetic() || *c == '_') => {
+                    let c = self.advance();
+                    identifier_text.push(c);
+                },
                 _ =>{
                     break;
                 }
@@ -0,0 +1,343 @@
+//------------------
+// (Tripp) Milton Lamb
+// Fall 2025, Nov 29 2025
+// CS-524: Programming Languages
+// Final Project
+//------------------
+
+//! Stack VM that runs compiled bytecode (`bytecode.rs`).
+//!
+//! `Vm` is a drop-in replacement for the tree-walking `Executor`: it has the same
+//! methods and gives identical results (the executor's test cases are run against
+//! both). Instead of a `HashMap` of variables it keeps a vector indexed by the slots
+//! the compiler resolved, and instead of recursing through the `Expression` tree it
+//! runs a flat list of instructions over a value stack.
+//!
+//! The VM is a library-only entry point: `Significance` and the command-line tools
+//! always run programs with the executor, so the VM is reached only by constructing a
+//! `Vm` directly. A program compiled once with `compile` can be run many times with
+//! `run`, which is where the VM pays off, e.g. for Monte Carlo workloads:
+//!
+//! ```ignore
+//! let mut vm = Vm::new();
+//! let chunk = vm.compile(&program);
+//! for _ in 0..trials {
+//!     vm.reset();
+//!     vm.run(&chunk);
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::{Number, Real};
+use crate::significance::ast_parser::{is_true, BinaryOp, Expression, Program, Statement, VarType};
+use crate::significance::budget::{error_budget, ErrorBudget};
+use crate::significance::bytecode::{Chunk, Compiler, Instruction};
+use crate::significance::constants::CONSTANTS;
+use crate::significance::symbolic::{formula, Formula};
+use crate::significance::executor::{Executor, RunTimeError, VarRunTime};
+use crate::significance::propagation::PropagationOrder;
+use crate::significance::standard_lib::{assertion_call, STD_LIB};
+use crate::significance::tokenizer::Position;
+
+/// Bytecode virtual machine for the Significance language.
+///
+/// See the module documentation. Like the executor, it is generic over the `Number`
+/// it computes with, and collects runtime errors without stopping.
+pub struct Vm<N = Real> {
+    /// Resolves variable names to slots when compiling
+    compiler: Compiler,
+
+    /// Variable of every slot, `None` while the variable is not declared
+    vars: Vec<Option<VarRunTime<N>>>,
+
+    /// Operands of the instructions being run
+    stack: Vec<N>,
+
+    /// Accumulated runtime errors encountered during execution
+    errors: Vec<RunTimeError>,
+
+    /// Taylor order used to propagate uncertainty through operators and functions
+    propagation_order: PropagationOrder,
+
+    /// Whether printing is suppressed (expression results and `budget` reports)
+    quiet: bool,
+
+    /// Lines that would have been printed, kept instead while output is captured
+    output: Option<Vec<String>>,
+}
+
+impl Default for Vm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Vm {
+    /// Creates a new VM computing with `Real`, with no variables or errors.
+    pub fn new() -> Self {
+        Self::with_backend()
+    }
+}
+
+impl<N: Number> Vm<N> {
+    /// Creates a new VM that computes with the numeric backend `N`.
+    pub fn with_backend() -> Self {
+        let mut vm = Self {
+            compiler: Compiler::new(),
+            vars: Vec::new(),
+            stack: Vec::new(),
+            errors: Vec::new(),
+            propagation_order: PropagationOrder::First,
+            quiet: false,
+            output: None,
+        };
+        vm.reset();
+        vm
+    }
+
+    /// Selects the order of uncertainty propagation (see `Executor::set_propagation_order`).
+    pub fn set_propagation_order(&mut self, order: PropagationOrder) {
+        self.propagation_order = order;
+    }
+
+    /// Returns the order of uncertainty propagation currently in use.
+    pub fn get_propagation_order(&self) -> PropagationOrder {
+        self.propagation_order
+    }
+
+    /// Suppresses (or restores) printing of expression results and `budget` reports.
+    pub fn set_quiet(&mut self, quiet: bool) {
+        self.quiet = quiet;
+    }
+
+    /// Returns whether printing is suppressed.
+    pub fn is_quiet(&self) -> bool {
+        self.quiet
+    }
+
+    /// Starts (or stops) keeping the lines the program prints instead of printing them.
+    pub fn set_capture(&mut self, capture: bool) {
+        self.output = capture.then(Vec::new);
+    }
+
+    /// Returns the lines kept since output capture started (or was last taken).
+    pub fn take_output(&mut self) -> Vec<String> {
+        self.output.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    /// Removes every variable except the built-in constants. Errors are kept.
+    pub fn reset(&mut self) {
+        self.vars.iter_mut().for_each(|var| *var = None);
+        for constant in CONSTANTS {
+            let slot = self.compiler.slot(constant.name);
+            self.vars.resize(self.compiler.slot_count(), None);
+            self.vars[slot] = Some(VarRunTime::new(constant.to_number(), None));
+        }
+    }
+
+    pub fn clear_errors(&mut self) {
+        self.errors.clear();
+    }
+
+    /// Returns all runtime errors accumulated during execution.
+    pub fn get_errors(&self) -> Vec<RunTimeError> {
+        self.errors.clone()
+    }
+
+    /// Retrieves the current value of a variable by name.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(VarRunTime)` - Variable found with its current value
+    /// * `None` - Variable does not exist
+    pub fn get_var(&self, name: &str) -> Option<VarRunTime<N>> {
+        let slot = self.compiler.lookup(name)?;
+        self.vars.get(slot)?.clone()
+    }
+
+    /// Adds the variables of an imported source file, dropping their definitions.
+    pub fn import_variables(&mut self, variables: HashMap<String, VarRunTime<N>>) {
+        for (name, var) in variables {
+            let slot = self.compiler.slot(&name);
+            self.vars.resize(self.compiler.slot_count(), None);
+            self.vars[slot] = Some(VarRunTime::new(var.get_value().clone(), None));
+        }
+    }
+
+    /// Declares and initializes a new variable to zero.
+    pub fn declare_variable(&mut self, name: &str, _var_type: &VarType, _: &Position) {
+        let slot = self.compiler.slot(name);
+        self.vars.resize(self.compiler.slot_count(), None);
+        self.vars[slot] = Some(VarRunTime::new(N::new(0.0), None));
+    }
+
+    /// Compiles a program to bytecode, resolving its variables to this VM's slots.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a function doesn't exist or is called with the wrong number of
+    /// arguments. Both are expected to be caught by semantic analysis.
+    pub fn compile(&mut self, program: &Program) -> Chunk<N> {
+        self.compiler.compile_program(program)
+    }
+
+    /// Compiles and runs a complete program.
+    pub fn execute_program(&mut self, program: &Program) {
+        let chunk = self.compile(program);
+        self.run(&chunk);
+    }
+
+    /// Compiles and runs a single statement.
+    pub fn execute_statement(&mut self, statement: &Statement) {
+        let chunk = self.compiler.compile_statement(statement);
+        self.run(&chunk);
+    }
+
+    /// Evaluates an expression and returns its computed value.
+    pub fn evaluate_expression(&mut self, expression: &Expression) -> N {
+        let chunk = self.compiler.compile_expression(expression);
+        self.run(&chunk);
+        self.pop()
+    }
+
+    /// Evaluates a single expression with a specific propagation order, restoring the
+    /// configured order afterward.
+    pub fn evaluate_expression_with_order(&mut self, expression: &Expression, order: PropagationOrder) -> N {
+        let previous = std::mem::replace(&mut self.propagation_order, order);
+        let value = self.evaluate_expression(expression);
+        self.propagation_order = previous;
+        value
+    }
+
+    /// Computes the error budget of an expression against the current variables.
+    pub fn error_budget(&self, expression: &Expression) -> ErrorBudget {
+        error_budget(expression, &self.variables())
+    }
+
+    /// Derives the symbolic uncertainty formula of an expression against the current variables.
+    pub fn formula(&self, expression: &Expression) -> Formula {
+        formula(expression, &self.variables())
+    }
+
+    /// Runs compiled bytecode.
+    ///
+    /// The chunk must have been compiled by this VM, since its slots are this VM's.
+    /// A chunk can be run any number of times.
+    pub fn run(&mut self, chunk: &Chunk<N>) {
+        self.vars.resize(self.compiler.slot_count(), None);
+
+        let mut next = 0;
+        while let Some(instruction) = chunk.code.get(next) {
+            next += 1;
+            match instruction {
+                Instruction::Constant(index) => self.stack.push(chunk.constants[*index].clone()),
+                Instruction::Load(slot, pos) => {
+                    let value = match &self.vars[*slot] {
+                        Some(var) => var.get_value().clone(),
+                        None => {
+                            self.errors.push(RunTimeError::UndefinedVariable(self.compiler.name(*slot).to_string(), *pos));
+                            N::new(f64::NAN)
+                        },
+                    };
+                    self.stack.push(value);
+                },
+                Instruction::Declare(slot) => self.vars[*slot] = Some(VarRunTime::new(N::new(0.0), None)),
+                Instruction::Store { slot, definition, pos } => {
+                    let value = self.pop();
+                    match &mut self.vars[*slot] {
+                        Some(var) => *var = VarRunTime::new(value, Some(Rc::clone(&chunk.expressions[*definition]))),
+                        None => self.errors.push(RunTimeError::UndefinedVariable(self.compiler.name(*slot).to_string(), *pos)),
+                    }
+                },
+                Instruction::Binary(op, pos) => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    if op == &BinaryOp::Div && right.value() == 0.0 && right.error() == 0.0 {
+                        self.errors.push(RunTimeError::DivisionByZero(*pos));
+                    }
+                    let result = match self.propagation_order {
+                        PropagationOrder::Second => Executor::apply_binary_second_order(left, op, right),
+                        PropagationOrder::First => Executor::apply_binary(left, op, right),
+                    };
+                    self.stack.push(result);
+                },
+                Instruction::Negate => {
+                    let value = self.pop();
+                    self.stack.push(-value);
+                },
+                Instruction::Not => {
+                    let value = self.pop();
+                    self.stack.push(N::new(if is_true(value.value()) { 0.0 } else { 1.0 }));
+                },
+                Instruction::ShortCircuit { op, target } => {
+                    let left = is_true(self.stack.last().expect("bytecode pops only values it pushed").value());
+                    if left == (op == &BinaryOp::Or) {
+                        self.pop();
+                        self.stack.push(N::new(if left { 1.0 } else { 0.0 }));
+                        next = *target;
+                    }
+                },
+                Instruction::Call { function, argc } => {
+                    let args = self.stack.split_off(self.stack.len() - argc);
+                    self.stack.push(N::std_function(&STD_LIB[*function], &args, self.propagation_order));
+                },
+                Instruction::Nominal => {
+                    let value = self.pop();
+                    self.stack.push(N::new(value.value()));
+                },
+                Instruction::Budget(index) => {
+                    if !self.quiet {
+                        let budget = self.error_budget(&chunk.expressions[*index]).to_string();
+                        self.print(&budget);
+                    }
+                },
+                Instruction::Formula(index) => {
+                    if !self.quiet {
+                        let result = self.stack.last().expect("bytecode pops only values it pushed");
+                        let report = self.formula(&chunk.expressions[*index]).report(result);
+                        self.print(&report);
+                    }
+                },
+                Instruction::Assert { function, argc, span } => {
+                    let args = self.stack.split_off(self.stack.len() - argc);
+                    let value = assertion_call(function, &args, &span.start).unwrap_or_else(|message| {
+                        self.errors.push(RunTimeError::AssertionFailed(message, *span));
+                        args[0].clone()
+                    });
+                    self.stack.push(value);
+                },
+                Instruction::Print => {
+                    let value = self.pop();
+                    self.print(&value.to_string());
+                },
+                Instruction::Pop => {
+                    self.pop();
+                },
+            }
+        }
+    }
+
+    fn pop(&mut self) -> N {
+        self.stack.pop().expect("bytecode pops only values it pushed")
+    }
+
+    /// Prints a result, or keeps its lines if output is captured.
+    fn print(&mut self, text: &str) {
+        if self.quiet {
+            return;
+        }
+        match &mut self.output {
+            Some(output) => output.extend(text.lines().map(str::to_string)),
+            None => println!("{}", text),
+        }
+    }
+
+    /// Returns the declared variables by name, as error budgets look them up.
+    fn variables(&self) -> HashMap<String, VarRunTime<N>> {
+        self.vars.iter().enumerate()
+            .filter_map(|(slot, var)| Some((self.compiler.name(slot).to_string(), var.clone()?)))
+            .collect()
+    }
+}